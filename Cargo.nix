@@ -24,7 +24,7 @@ args@{
   ignoreLockHash,
 }:
 let
-  nixifiedLockHash = "503cfb587f06527fb63770cd6a1b55b6453281253b874bdab8b94b1ec53916bf";
+  nixifiedLockHash = "163df3a6f3f6e2e2bac521674c5577c2d4cd69a9ac3b0f8bb402d6a51cef127f";
   workspaceSrc = if args.workspaceSrc == null then ./. else args.workspaceSrc;
   currentLockHash = builtins.hashFile "sha256" (workspaceSrc + /Cargo.lock);
   lockHashIgnored = if ignoreLockHash
@@ -61,6 +61,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8e60d3430d3a69478ad0993f19238d2df97c507009a52b3c10addcd7f6bcb916"; };
     features = builtins.concatLists [
+      [ "perf-literal" ]
       [ "std" ]
     ];
     dependencies = {
@@ -74,11 +75,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "dc890384c8602f339876ded803c97ad529f3842aba97f6392b3dba0dd171769b"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      equator = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".equator."0.4.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "equator" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".equator."0.4.2" { inherit profileName; }).out;
     };
   });
   
@@ -167,11 +168,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "dde20b3d026af13f561bdd0f15edf01fc734f0dafcedbaf42bba506a9517f223"; };
     features = builtins.concatLists [
-      [ "derive" ]
-      [ "derive_arbitrary" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "derive")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "derive_arbitrary")
     ];
     dependencies = {
-      derive_arbitrary = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".derive_arbitrary."1.4.1" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "derive_arbitrary" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".derive_arbitrary."1.4.1" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -181,9 +182,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0ae92a5119aa49cdbcf6b9f893fe4e1d98b04ccbf82ee0584ad948a44a734dea"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
@@ -192,10 +193,196 @@ in
     version = "0.7.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7c02d123df017efcdfbd739ef81735b36c5ba83ec3c59c80a9d7ecc718f92e50"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".ascii."1.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "ascii";
+    version = "1.1.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "d92bec98840b8f03a5ff5413de5293bfcd8bf96467cf5452609f939ec6f5de16"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "std")
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-broadcast."0.7.2" = overridableMkRustCrate (profileName: rec {
+    name = "async-broadcast";
+    version = "0.7.2";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "435a87a52755b8f27fcf321ac4f04b2802e337c8c4872923137471ec39c37532"; };
+    dependencies = {
+      event_listener = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".event-listener."5.4.2" { inherit profileName; }).out;
+      event_listener_strategy = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".event-listener-strategy."0.5.4" { inherit profileName; }).out;
+      futures_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      pin_project_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-channel."2.5.0" = overridableMkRustCrate (profileName: rec {
+    name = "async-channel";
+    version = "2.5.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "924ed96dd52d1b75e9c1a3e6275715fd320f5f9439fb5a4a11fa51f4221158d2"; };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
     ];
+    dependencies = {
+      concurrent_queue = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".concurrent-queue."2.5.0" { inherit profileName; }).out;
+      event_listener_strategy = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".event-listener-strategy."0.5.4" { inherit profileName; }).out;
+      futures_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      pin_project_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-executor."1.14.0" = overridableMkRustCrate (profileName: rec {
+    name = "async-executor";
+    version = "1.14.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "c96bf972d85afc50bf5ab8fe2d54d1586b4e0b46c97c50a0c9e71e2f7bcd812a"; };
+    dependencies = {
+      async_task = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-task."4.7.1" { inherit profileName; }).out;
+      concurrent_queue = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".concurrent-queue."2.5.0" { inherit profileName; }).out;
+      fastrand = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".fastrand."2.3.0" { inherit profileName; }).out;
+      futures_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-lite."2.6.1" { inherit profileName; }).out;
+      pin_project_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+      slab = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".slab."0.4.10" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-fs."2.2.0" = overridableMkRustCrate (profileName: rec {
+    name = "async-fs";
+    version = "2.2.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "8034a681df4aed8b8edbd7fbe472401ecf009251c8b40556b304567052e294c5"; };
+    dependencies = {
+      async_lock = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-lock."3.4.2" { inherit profileName; }).out;
+      blocking = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".blocking."1.7.0" { inherit profileName; }).out;
+      futures_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-lite."2.6.1" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-io."2.6.0" = overridableMkRustCrate (profileName: rec {
+    name = "async-io";
+    version = "2.6.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "456b8a8feb6f42d237746d4b3e9a178494627745c3c56c6ea55d92ba50d026fc"; };
+    dependencies = {
+      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      concurrent_queue = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".concurrent-queue."2.5.0" { inherit profileName; }).out;
+      futures_io = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-io."0.3.31" { inherit profileName; }).out;
+      futures_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-lite."2.6.1" { inherit profileName; }).out;
+      parking = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".parking."2.2.1" { inherit profileName; }).out;
+      polling = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".polling."3.11.0" { inherit profileName; }).out;
+      rustix = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustix."1.0.7" { inherit profileName; }).out;
+      slab = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".slab."0.4.10" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.61.2" { inherit profileName; }).out;
+    };
+    buildDependencies = {
+      autocfg = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".autocfg."1.4.0" { profileName = "__noProfile"; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-lock."3.4.2" = overridableMkRustCrate (profileName: rec {
+    name = "async-lock";
+    version = "3.4.2";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "290f7f2596bd5b78a9fec8088ccd89180d7f9f55b94b0576823bbbdc72ee8311"; };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "std" ]
+    ];
+    dependencies = {
+      event_listener = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".event-listener."5.4.2" { inherit profileName; }).out;
+      event_listener_strategy = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".event-listener-strategy."0.5.4" { inherit profileName; }).out;
+      pin_project_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-process."2.5.0" = overridableMkRustCrate (profileName: rec {
+    name = "async-process";
+    version = "2.5.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "fc50921ec0055cdd8a16de48773bfeec5c972598674347252c0399676be7da75"; };
+    dependencies = {
+      ${ if hostPlatform.isWindows || hostPlatform.parsed.kernel.name == "linux" then "async_channel" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-channel."2.5.0" { inherit profileName; }).out;
+      async_io = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-io."2.6.0" { inherit profileName; }).out;
+      ${ if hostPlatform.isUnix then "async_lock" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-lock."3.4.2" { inherit profileName; }).out;
+      ${ if hostPlatform.isUnix then "async_signal" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-signal."0.2.14" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows || hostPlatform.parsed.kernel.name == "linux" then "async_task" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-task."4.7.1" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "blocking" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".blocking."1.7.0" { inherit profileName; }).out;
+      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      event_listener = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".event-listener."5.4.2" { inherit profileName; }).out;
+      futures_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-lite."2.6.1" { inherit profileName; }).out;
+      ${ if hostPlatform.isUnix then "rustix" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustix."1.0.7" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-recursion."1.2.0" = overridableMkRustCrate (profileName: rec {
+    name = "async-recursion";
+    version = "1.2.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "4f8abc12baad266b1c8cec146854c195b5864b4221d4b2ca7296a7ae82d9e451"; };
+    dependencies = {
+      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."3.0.9" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-signal."0.2.14" = overridableMkRustCrate (profileName: rec {
+    name = "async-signal";
+    version = "0.2.14";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "52b5aaafa020cf5053a01f2a60e8ff5dccf550f0f77ec54a4e47285ac2bab485"; };
+    dependencies = {
+      ${ if hostPlatform.isUnix then "async_io" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-io."2.6.0" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "async_lock" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-lock."3.4.2" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "atomic_waker" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".atomic-waker."1.1.2" { inherit profileName; }).out;
+      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      futures_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      ${ if hostPlatform.isUnix then "futures_io" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-io."0.3.31" { inherit profileName; }).out;
+      ${ if hostPlatform.isUnix then "rustix" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustix."1.0.7" { inherit profileName; }).out;
+      ${ if hostPlatform.isUnix then "signal_hook_registry" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".signal-hook-registry."1.4.8" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "slab" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".slab."0.4.10" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.61.2" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-task."4.7.1" = overridableMkRustCrate (profileName: rec {
+    name = "async-task";
+    version = "4.7.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "8b75356056920673b02621b35afd0f7dda9306d03c79a30f5c56c44cf256e3de"; };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "std" ]
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".async-trait."0.1.92" = overridableMkRustCrate (profileName: rec {
+    name = "async-trait";
+    version = "0.1.92";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "82f6aeea286b8eb4dd3431a1be1b59d290ace00f5bfd8e2a159bc2a05e2c1667"; };
+    dependencies = {
+      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."3.0.9" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".atomic-waker."1.1.2" = overridableMkRustCrate (profileName: rec {
+    name = "atomic-waker";
+    version = "1.1.2";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "1505bd5d3d116872e7271a6d4e16d81d0c8570876c8de68093a09ac269d8aac0"; };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".autocfg."1.4.0" = overridableMkRustCrate (profileName: rec {
@@ -211,22 +398,22 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4f3efb2ca85bc610acfa917b5aaa36f3fcbebed5b3182d7f877b02531c4b80c8"; };
     features = builtins.concatLists [
-      [ "create" ]
-      [ "default" ]
-      [ "diff" ]
-      [ "estimate" ]
-      [ "nom" ]
-      [ "num-rational" ]
-      [ "parse" ]
-      [ "v_frame" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "create")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "diff")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "estimate")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "nom")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "num-rational")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "parse")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "v_frame")
     ];
     dependencies = {
-      anyhow = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".anyhow."1.0.98" { inherit profileName; }).out;
-      arrayvec = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arrayvec."0.7.6" { inherit profileName; }).out;
-      log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
-      nom = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nom."7.1.3" { inherit profileName; }).out;
-      num_rational = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-rational."0.4.2" { inherit profileName; }).out;
-      v_frame = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".v_frame."0.3.9" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "anyhow" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".anyhow."1.0.98" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "arrayvec" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arrayvec."0.7.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "nom" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nom."7.1.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_rational" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-rational."0.4.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "v_frame" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".v_frame."0.3.9" { inherit profileName; }).out;
     };
   });
   
@@ -236,7 +423,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "98922d6a4cfbcb08820c69d8eeccc05bb1f29bfa06b4f5b1dbfe9a868bd7608e"; };
     dependencies = {
-      arrayvec = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arrayvec."0.7.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "arrayvec" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arrayvec."0.7.6" { inherit profileName; }).out;
     };
   });
   
@@ -252,35 +439,48 @@ in
     ];
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".base64."0.23.1" = overridableMkRustCrate (profileName: rec {
+    name = "base64";
+    version = "0.23.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "ac07cdecf99051d9a5238b80f35af32cdeba5b336e55d957b318b50137e18da5"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "simd-unsafe")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "std")
+    ];
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".bindgen."0.65.1" = overridableMkRustCrate (profileName: rec {
     name = "bindgen";
     version = "0.65.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cfdf7b466f9a4903edc73f95d6d2bcd5baf8ae620638762244d3f60143643cc5"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "log" ]
-      [ "logging" ]
-      [ "runtime" ]
-      [ "which" ]
-      [ "which-rustfmt" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "log")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "logging")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "runtime")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "which")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "which-rustfmt")
     ];
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
-      cexpr = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cexpr."0.6.0" { inherit profileName; }).out;
-      clang_sys = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".clang-sys."1.8.1" { inherit profileName; }).out;
-      lazy_static = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazy_static."1.5.0" { inherit profileName; }).out;
-      lazycell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazycell."1.3.0" { inherit profileName; }).out;
-      log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
-      peeking_take_while = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".peeking_take_while."0.1.2" { inherit profileName; }).out;
-      prettyplease = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prettyplease."0.2.34" { inherit profileName; }).out;
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      regex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".regex."1.11.1" { inherit profileName; }).out;
-      rustc_hash = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustc-hash."1.1.0" { inherit profileName; }).out;
-      shlex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".shlex."1.3.0" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
-      which = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".which."4.4.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "cexpr" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cexpr."0.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "clang_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".clang-sys."1.8.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "lazy_static" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazy_static."1.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "lazycell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazycell."1.3.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "peeking_take_while" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".peeking_take_while."0.1.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "prettyplease" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prettyplease."0.2.34" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "regex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".regex."1.11.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "rustc_hash" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustc-hash."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "shlex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".shlex."1.3.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "which" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".which."4.4.2" { inherit profileName; }).out;
     };
   });
   
@@ -322,7 +522,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "bef38d45163c2f1dde094a7dfd33ccf595c92905c8f8f4fdc18d06fb1037718a"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
     ];
   });
   
@@ -342,8 +542,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6099cdc01846bc367c4e7dd630dc5966dccf36b652fae7a74e17b640411a91b2"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
   });
   
@@ -354,28 +554,74 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "0d8c1fef690941d3e7788d328517591fecc684c084084702d6ff1641e993699a"; };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".block-buffer."0.10.4" = overridableMkRustCrate (profileName: rec {
+    name = "block-buffer";
+    version = "0.10.4";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "3078c7629b62d3f0439517fa394996acacc5cbc91c5a20d8c658e77abd503a71"; };
+    dependencies = {
+      generic_array = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".generic-array."0.14.7" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".blocking."1.7.0" = overridableMkRustCrate (profileName: rec {
+    name = "blocking";
+    version = "1.7.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "a70e4329df6cb94385eed412ec92375c3cdd8a6e502493d1229b6414e4036dfa"; };
+    dependencies = {
+      async_channel = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-channel."2.5.0" { inherit profileName; }).out;
+      async_task = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-task."4.7.1" { inherit profileName; }).out;
+      futures_io = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-io."0.3.31" { inherit profileName; }).out;
+      futures_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-lite."2.6.1" { inherit profileName; }).out;
+      piper = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".piper."0.2.5" { inherit profileName; }).out;
+    };
+  });
+  
   "unknown".bongo-modulator."0.1.0" = overridableMkRustCrate (profileName: rec {
     name = "bongo-modulator";
     version = "0.1.0";
     registry = "unknown";
     src = fetchCrateLocal workspaceSrc;
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "ai")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "camera")
+      (lib.optional (rootFeatures' ? "bongo-modulator/default") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "http")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "hub")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "otel")
+    ];
     dependencies = {
-      candle_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".candle-core."0.9.1" { inherit profileName; }).out;
-      candle_onnx = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".candle-onnx."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "candle_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".candle-core."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "candle_onnx" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".candle-onnx."0.9.1" { inherit profileName; }).out;
       clap = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".clap."4.5.40" { inherit profileName; }).out;
-      hf_hub = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hf-hub."0.4.3" { inherit profileName; }).out;
-      image = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".image."0.25.6" { inherit profileName; }).out;
-      nokhwa = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa."0.10.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "hf_hub" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hf-hub."0.4.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "image" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".image."0.25.6" { inherit profileName; }).out;
+      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nokhwa" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa."0.10.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry."0.31.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry_otlp" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry-otlp."0.31.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry_sdk" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry_sdk."0.31.0" { inherit profileName; }).out;
+      regex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".regex."1.11.1" { inherit profileName; }).out;
       serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
       serde_json = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_json."1.0.140" { inherit profileName; }).out;
-      sysinfo = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sysinfo."0.35.2" { inherit profileName; }).out;
-      tracing = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.41" { inherit profileName; }).out;
-      tracing_subscriber = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-subscriber."0.3.19" { inherit profileName; }).out;
+      shlex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".shlex."1.3.0" { inherit profileName; }).out;
+      signal_hook = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".signal-hook."0.3.18" { inherit profileName; }).out;
+      ${ if !(hostPlatform.parsed.kernel.name == "linux") then "sysinfo" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sysinfo."0.35.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "tiny_http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tiny_http."0.12.0" { inherit profileName; }).out;
+      tracing = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.44" { inherit profileName; }).out;
+      ${ if hostPlatform.parsed.kernel.name == "linux" then "tracing_journald" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-journald."0.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tracing_opentelemetry" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-opentelemetry."0.32.1" { inherit profileName; }).out;
+      tracing_subscriber = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-subscriber."0.3.23" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "tungstenite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tungstenite."0.24.0" { inherit profileName; }).out;
+      ${ if hostPlatform.parsed.kernel.name == "linux" then "zbus" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zbus."4.4.0" { inherit profileName; }).out;
     };
     devDependencies = {
       proptest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proptest."1.7.0" { inherit profileName; }).out;
-      serial_test = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serial_test."2.0.0" { inherit profileName; }).out;
+      prost = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost."0.12.6" { inherit profileName; }).out;
+      serial_test = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serial_test."3.2.0" { inherit profileName; }).out;
       tempfile = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tempfile."3.20.0" { inherit profileName; }).out;
+      ureq = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ureq."2.12.1" { inherit profileName; }).out;
     };
   });
   
@@ -392,7 +638,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "793db76d6187cd04dff33004d8e6c9cc4e05cd330500379d2394209271b4aeee"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "default")
     ];
   });
   
@@ -402,15 +648,15 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "5c76a5792e44e4abe34d3abf15636779261d45a7450612059293d1d2cfc63422"; };
     features = builtins.concatLists [
-      [ "aarch64_simd" ]
-      [ "align_offset" ]
-      [ "bytemuck_derive" ]
-      [ "derive" ]
-      [ "extern_crate_alloc" ]
-      [ "min_const_generics" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "aarch64_simd")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "align_offset")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "bytemuck_derive")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "derive")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "extern_crate_alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "min_const_generics")
     ];
     dependencies = {
-      bytemuck_derive = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck_derive."1.9.3" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck_derive" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck_derive."1.9.3" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -420,9 +666,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7ecc273b49b3205b83d648f0690daa588925572cc5063745bfe547fe7ec8e1a1"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
@@ -432,8 +678,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1fd0f2584146f6f2ef48085050886acf353beff7305ebd1ae69500e27c67f64b"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
   });
   
@@ -443,8 +689,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8f1fe948ff07f4bd06c30984e69f5b4899c516a3ef74f34df92a2df2ab535495"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
   });
   
@@ -454,8 +700,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d71b6127be86fdcfddb610f7182ac57211d4b18a3e9c82eb2d17662f2227ad6a"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "std")
     ];
   });
   
@@ -465,23 +711,23 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a9f51e2ecf6efe9737af8f993433c839f956d2b6ed4fd2dd4a7c6d8b0fa667ff"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
     ];
     dependencies = {
-      byteorder = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder."1.5.0" { inherit profileName; }).out;
-      gemm = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm."0.17.1" { inherit profileName; }).out;
-      half = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
-      memmap2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".memmap2."0.9.5" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      num_cpus = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num_cpus."1.17.0" { inherit profileName; }).out;
-      rand = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.9.1" { inherit profileName; }).out;
-      rand_distr = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand_distr."0.5.1" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
-      safetensors = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".safetensors."0.4.5" { inherit profileName; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
-      ${ if !(hostPlatform.parsed.cpu.name == "wasm32") then "ug" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ug."0.4.0" { inherit profileName; }).out;
-      yoke = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".yoke."0.7.5" { inherit profileName; }).out;
-      zip = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zip."1.1.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "byteorder" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder."1.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "half" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "memmap2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".memmap2."0.9.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_cpus" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num_cpus."1.17.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rand" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rand_distr" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand_distr."0.5.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "safetensors" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".safetensors."0.4.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && !(hostPlatform.parsed.cpu.name == "wasm32") then "ug" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ug."0.4.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "yoke" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".yoke."0.7.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "zip" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zip."1.1.4" { inherit profileName; }).out;
     };
   });
   
@@ -491,16 +737,16 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c1980d53280c8f9e2c6cbe1785855d7ff8010208b46e21252b978badf13ad69d"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
     ];
     dependencies = {
-      candle = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".candle-core."0.9.1" { inherit profileName; }).out;
-      half = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
-      safetensors = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".safetensors."0.4.5" { inherit profileName; }).out;
-      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "candle" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".candle-core."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "half" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "safetensors" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".safetensors."0.4.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
     };
   });
   
@@ -510,12 +756,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8a8fa227a8176fd9b8fb58d63c908c08ad3af1503ee6fcd058be072a598044d2"; };
     dependencies = {
-      candle = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".candle-core."0.9.1" { inherit profileName; }).out;
-      candle_nn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".candle-nn."0.9.1" { inherit profileName; }).out;
-      prost = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost."0.12.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "candle" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".candle-core."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "candle_nn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".candle-nn."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "prost" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost."0.12.6" { inherit profileName; }).out;
     };
     buildDependencies = {
-      prost_build = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".prost-build."0.12.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "prost_build" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".prost-build."0.12.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -525,12 +771,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d487aa071b5f64da6f19a3e848e3578944b726ee5a4854b82172f02aa876bfdc"; };
     features = builtins.concatLists [
-      [ "parallel" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "parallel")
     ];
     dependencies = {
-      jobserver = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".jobserver."0.1.33" { inherit profileName; }).out;
-      ${ if hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      shlex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".shlex."1.3.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "jobserver" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".jobserver."0.1.33" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "shlex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".shlex."1.3.0" { inherit profileName; }).out;
     };
   });
   
@@ -540,7 +786,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6fac387a98bb7c37292057cffc56d62ecb629900026402633ae9160df93a8766"; };
     dependencies = {
-      nom = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nom."7.1.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nom" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nom."7.1.3" { inherit profileName; }).out;
     };
   });
   
@@ -550,13 +796,13 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d067ad48b8650848b989a59a86c6c36a995d02d2bf778d45c3c5d57bc2718f02"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "target-lexicon" ]
-      [ "targets" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "target-lexicon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "targets")
     ];
     dependencies = {
-      smallvec = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".smallvec."1.15.1" { inherit profileName; }).out;
-      target_lexicon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".target-lexicon."0.12.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "smallvec" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".smallvec."1.15.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "target_lexicon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".target-lexicon."0.12.16" { inherit profileName; }).out;
     };
   });
   
@@ -574,30 +820,44 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "9555578bc9e57714c812a1f84e4fc5b4d21fcb063490c624de019f7464c91268"; };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".cfg_aliases."0.2.2" = overridableMkRustCrate (profileName: rec {
+    name = "cfg_aliases";
+    version = "0.2.2";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "f079e83a288787bcd14a6aea84cee5c87a67c5a3e660c30f557a3d24761b3527"; };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".chunked_transfer."1.5.0" = overridableMkRustCrate (profileName: rec {
+    name = "chunked_transfer";
+    version = "1.5.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "6e4de3bc4ea267985becf712dc6d9eed8b04c953b3fcfb339ebc87acd9804901"; };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".clang-sys."1.8.1" = overridableMkRustCrate (profileName: rec {
     name = "clang-sys";
     version = "1.8.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0b023947811758c97c59bf9d1c188fd619ad4718dcaa767947df1cadb14f39f4"; };
     features = builtins.concatLists [
-      [ "clang_3_5" ]
-      [ "clang_3_6" ]
-      [ "clang_3_7" ]
-      [ "clang_3_8" ]
-      [ "clang_3_9" ]
-      [ "clang_4_0" ]
-      [ "clang_5_0" ]
-      [ "clang_6_0" ]
-      [ "libloading" ]
-      [ "runtime" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "clang_3_5")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "clang_3_6")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "clang_3_7")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "clang_3_8")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "clang_3_9")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "clang_4_0")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "clang_5_0")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "clang_6_0")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "libloading")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "runtime")
     ];
     dependencies = {
-      glob = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".glob."0.3.2" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      libloading = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libloading."0.8.8" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "glob" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".glob."0.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libloading" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libloading."0.8.8" { inherit profileName; }).out;
     };
     buildDependencies = {
-      glob = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".glob."0.3.2" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "glob" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".glob."0.3.2" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -672,13 +932,13 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0c49e86fc36d5704151f5996b7b3795385f50ce09e3be0f47a0cfde869681cf8"; };
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
-      block = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".block."0.1.6" { inherit profileName; }).out;
-      core_foundation = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.7.0" { inherit profileName; }).out;
-      core_graphics = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-graphics."0.19.2" { inherit profileName; }).out;
-      foreign_types = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".foreign-types."0.3.2" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      objc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc."0.2.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "block" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".block."0.1.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_foundation" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_graphics" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-graphics."0.19.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "foreign_types" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".foreign-types."0.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "objc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc."0.2.7" { inherit profileName; }).out;
     };
   });
   
@@ -688,12 +948,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8c6234cbb2e4c785b456c0644748b1ac416dd045799740356f8363dfe00c93f7"; };
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
-      block = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".block."0.1.6" { inherit profileName; }).out;
-      core_foundation = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.9.4" { inherit profileName; }).out;
-      core_graphics_types = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-graphics-types."0.1.3" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      objc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc."0.2.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "block" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".block."0.1.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_foundation" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.9.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_graphics_types" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-graphics-types."0.1.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "objc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc."0.2.7" { inherit profileName; }).out;
     };
   });
   
@@ -711,21 +971,35 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "b05b61dc5112cbb17e4b6cd61790d9845d13888356391624cbe7e41efeac1e75"; };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".concurrent-queue."2.5.0" = overridableMkRustCrate (profileName: rec {
+    name = "concurrent-queue";
+    version = "2.5.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "4ca0197aee26d1ae37445ee532fefce43251d24cc7c166799f4d46817f1d3973"; };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "std" ]
+    ];
+    dependencies = {
+      crossbeam_utils = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.8.21" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".console."0.15.11" = overridableMkRustCrate (profileName: rec {
     name = "console";
     version = "0.15.11";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "054ccb5b10f9f2cbf51eb355ca1d05c2d279ce1804688d0db74b4733a5aeafd8"; };
     features = builtins.concatLists [
-      [ "ansi-parsing" ]
-      [ "unicode-width" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "ansi-parsing")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "unicode-width")
     ];
     dependencies = {
-      ${ if hostPlatform.isWindows then "encode_unicode" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".encode_unicode."1.0.0" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      unicode_width = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".unicode-width."0.2.1" { inherit profileName; }).out;
-      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.59.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.isWindows then "encode_unicode" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".encode_unicode."1.0.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "unicode_width" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".unicode-width."0.2.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.59.0" { inherit profileName; }).out;
     };
   });
   
@@ -735,8 +1009,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "57d24c7a13c43e870e37c1556b74555437870a04514f7685f5b354e090567171"; };
     dependencies = {
-      core_foundation_sys = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation-sys."0.7.0" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_foundation_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation-sys."0.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
   });
   
@@ -746,12 +1020,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "91e195e091a93c46f7102ec7818a2aa394e1e1771c3ab4825963fa03e45afb8f"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "link" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "link")
     ];
     dependencies = {
-      core_foundation_sys = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation-sys."0.8.7" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_foundation_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation-sys."0.8.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
   });
   
@@ -768,7 +1042,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "773648b94d0e5d620f64f280777445740e61fe701025087ec8b57f45c791888b"; };
     features = builtins.concatLists [
-      [ "link" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "link")
     ];
   });
   
@@ -778,13 +1052,13 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b3889374e6ea6ab25dba90bb5d96202f61108058361f6dc72e8b03e6f8bbe923"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
     ];
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
-      core_foundation = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.7.0" { inherit profileName; }).out;
-      foreign_types = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".foreign-types."0.3.2" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_foundation" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "foreign_types" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".foreign-types."0.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
   });
   
@@ -794,13 +1068,13 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "45390e6114f68f718cc7a830514a96f903cccd70d02a8f6d9f643ac4ba45afaf"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "link" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "link")
     ];
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
-      core_foundation = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.9.4" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_foundation" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.9.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
   });
   
@@ -810,12 +1084,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "273bf3fc5bf51fd06a7766a84788c1540b6527130a0bce39e00567d6ab9f31f1"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
     ];
     dependencies = {
-      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; }).out;
-      core_foundation_sys = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation-sys."0.7.0" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_foundation_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation-sys."0.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
   });
   
@@ -825,19 +1099,29 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "34ecad23610ad9757664d644e369246edde1803fcb43ed72876565098a5d3828"; };
     features = builtins.concatLists [
-      [ "core-graphics" ]
-      [ "default" ]
-      [ "display_link" ]
-      [ "metal" ]
-      [ "opengl" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "core-graphics")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "display_link")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "metal")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "opengl")
     ];
     dependencies = {
-      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; }).out;
-      core_foundation_sys = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation-sys."0.7.0" { inherit profileName; }).out;
-      core_graphics = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-graphics."0.19.2" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      metal = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".metal."0.18.0" { inherit profileName; }).out;
-      objc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc."0.2.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_foundation_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation-sys."0.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_graphics" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-graphics."0.19.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "metal" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".metal."0.18.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "objc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc."0.2.7" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".cpufeatures."0.2.17" = overridableMkRustCrate (profileName: rec {
+    name = "cpufeatures";
+    version = "0.2.17";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "59ed5838eebb26a2bb2e58f6d5b5316989ae9d08bab10e0e6d103e656d1b0280"; };
+    dependencies = {
+      ${ if hostPlatform.config == "aarch64-linux-android" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.kernel.name == "linux" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.vendor.name == "apple" || hostPlatform.parsed.cpu.name == "loongarch64" && hostPlatform.parsed.kernel.name == "linux" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
   });
   
@@ -847,11 +1131,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a97769d94ddab943e4510d138150169a2758b5ef3eb191a9ee688de3e23ef7b3"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
     };
   });
   
@@ -861,12 +1145,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9dd111b7b7f7d55b72c0a6ae361660ee5853c9af73f70c3c2ef6858b950e2e51"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      crossbeam_epoch = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-epoch."0.9.18" { inherit profileName; }).out;
-      crossbeam_utils = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.8.21" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "crossbeam_epoch" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-epoch."0.9.18" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "crossbeam_utils" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.8.21" { inherit profileName; }).out;
     };
   });
   
@@ -876,11 +1160,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "5b82ac4a3c2ca9c3460964f020e1402edd5753411d7737aa39c3714ad1b5420e"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      crossbeam_utils = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.8.21" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "crossbeam_utils" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.8.21" { inherit profileName; }).out;
     };
   });
   
@@ -890,8 +1174,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d0a5c400df2834b80a4c3327b3aad3a4c4cd4de0629063962b03235697506a28"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
   });
   
@@ -901,34 +1185,64 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "43da5946c66ffcc7745f48db692ffbb10a83bfe0afd96235c5c2a4fb23994929"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "limit_128" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "limit_128")
     ];
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".dashmap."5.5.3" = overridableMkRustCrate (profileName: rec {
-    name = "dashmap";
-    version = "5.5.3";
+  "registry+https://github.com/rust-lang/crates.io-index".crypto-common."0.1.7" = overridableMkRustCrate (profileName: rec {
+    name = "crypto-common";
+    version = "0.1.7";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "978747c1d849a7d2ee5e8adc0159961c48fb7e5db2f06af6723b80123bb53856"; };
+    src = fetchCratesIo { inherit name version; sha256 = "78c8292055d1c1df0cce5d180393dc8cce0abec0a7102adb6c7b1eef6016d60a"; };
+    features = builtins.concatLists [
+      [ "std" ]
+    ];
     dependencies = {
-      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
-      hashbrown = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hashbrown."0.14.5" { inherit profileName; }).out;
-      lock_api = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lock_api."0.4.13" { inherit profileName; }).out;
-      once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      parking_lot_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".parking_lot_core."0.9.11" { inherit profileName; }).out;
+      generic_array = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".generic-array."0.14.7" { inherit profileName; }).out;
+      typenum = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".typenum."1.20.1" { inherit profileName; }).out;
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".data-encoding."2.11.1" = overridableMkRustCrate (profileName: rec {
+    name = "data-encoding";
+    version = "2.11.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "4583a4551df46e2792f82ceeac45e850d2e2d5debba0b91f102385cda5b11f06"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "std")
+    ];
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".derive_arbitrary."1.4.1" = overridableMkRustCrate (profileName: rec {
     name = "derive_arbitrary";
     version = "1.4.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "30542c1ad912e0e3d22a1935c290e12e8a29d704a420177a31faad4a601a0800"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".digest."0.10.7" = overridableMkRustCrate (profileName: rec {
+    name = "digest";
+    version = "0.10.7";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "9ed9a281f7bc9b7576e61468ba615a66a5c8cfdff42420a70aa82701a3b1e292"; };
+    features = builtins.concatLists [
+      [ "alloc" ]
+      [ "block-buffer" ]
+      [ "core-api" ]
+      [ "default" ]
+      [ "std" ]
+    ];
+    dependencies = {
+      block_buffer = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".block-buffer."0.10.4" { inherit profileName; }).out;
+      crypto_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crypto-common."0.1.7" { inherit profileName; }).out;
     };
   });
   
@@ -938,7 +1252,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c3e8aa94d75141228480295a7d0e7feb620b1a5ad9f12bc40be62411e38cce4e"; };
     dependencies = {
-      dirs_sys = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dirs-sys."0.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "dirs_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dirs-sys."0.5.0" { inherit profileName; }).out;
     };
   });
   
@@ -948,10 +1262,10 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e01a3366d27ee9890022452ee61b2b63a67e6f13f58900b651ff5665f0bb1fab"; };
     dependencies = {
-      ${ if hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      option_ext = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".option-ext."0.2.0" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "redox" then "redox_users" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_users."0.5.0" { inherit profileName; }).out;
-      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.60.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "option_ext" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".option-ext."0.2.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.parsed.kernel.name == "redox" then "redox_users" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_users."0.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.61.2" { inherit profileName; }).out;
     };
   });
   
@@ -980,11 +1294,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "56e53799688f5632f364f8fb387488dd05db9fe45db7011be066fc20e7027f8b"; };
     features = builtins.concatLists [
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
-      reborrow = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".reborrow."0.5.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "reborrow" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".reborrow."0.5.5" { inherit profileName; }).out;
     };
   });
   
@@ -994,11 +1308,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "490bd48eb68fffcfed519b4edbfd82c69cbe741d175b84f0e0cbe8c57cbe0bdd"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
     };
   });
   
@@ -1008,9 +1322,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "48c757948c5ede0e46177b7add2e67155f70e33c07fea8284df6576da70b3719"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
-      [ "use_std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "std")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "use_std")
     ];
   });
   
@@ -1019,6 +1333,17 @@ in
     version = "1.0.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "34aa73646ffb006b8f5147f3dc182bd4bcb190227ce861fc4a4844bf8e3cb2c0"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "std")
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".endi."1.1.1" = overridableMkRustCrate (profileName: rec {
+    name = "endi";
+    version = "1.1.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "66b7e2430c6dff6a955451e2cfc438f09cea1965a9d6f87f7e3b90decc014099"; };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -1031,7 +1356,33 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a1e6a265c649f3f5979b601d26f1d05ada116434c87741c9493cb56218f76cbc"; };
     dependencies = {
-      heck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".heck."0.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "heck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".heck."0.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".enumflags2."0.7.12" = overridableMkRustCrate (profileName: rec {
+    name = "enumflags2";
+    version = "0.7.12";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "1027f7680c853e056ebcec683615fb6fbbc07dbaa13b4d5d9442b146ded4ecef"; };
+    features = builtins.concatLists [
+      [ "serde" ]
+    ];
+    dependencies = {
+      enumflags2_derive = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".enumflags2_derive."0.7.12" { profileName = "__noProfile"; }).out;
+      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".enumflags2_derive."0.7.12" = overridableMkRustCrate (profileName: rec {
+    name = "enumflags2_derive";
+    version = "0.7.12";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "67c78a4d8fdf9953a5c9d458f9efe940fd97a0cab0941c075a813ac594733827"; };
+    dependencies = {
       proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
       quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
       syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
@@ -1044,7 +1395,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4711b213838dfee0117e3be6ac926007d7f433d7bbe33595975d4190cb07e6fc"; };
     dependencies = {
-      equator_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".equator-macro."0.4.2" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "equator_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".equator-macro."0.4.2" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1054,9 +1405,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "44f23cf4b44bfce11a86ace86f8a73ffdec849c9fd00a386a53d278bd9e81fb3"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
@@ -1073,6 +1424,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cea14ef9355e3beab063703aa9dab15afd25f0667c341310c1e5274bb1d0da18"; };
     features = builtins.concatLists [
+      [ "default" ]
       [ "std" ]
     ];
     dependencies = {
@@ -1081,19 +1433,50 @@ in
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".event-listener."5.4.2" = overridableMkRustCrate (profileName: rec {
+    name = "event-listener";
+    version = "5.4.2";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "5a23add41df1562121a9393cb065eab5146a1242410f23a644851e90cfd669d2"; };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "parking" ]
+      [ "std" ]
+    ];
+    dependencies = {
+      parking = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".parking."2.2.1" { inherit profileName; }).out;
+      pin_project_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".event-listener-strategy."0.5.4" = overridableMkRustCrate (profileName: rec {
+    name = "event-listener-strategy";
+    version = "0.5.4";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "8be9f3dfaaffdae2972880079a491a1a8bb7cbed0b8dd7a347f668b4150a3b93"; };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "std" ]
+    ];
+    dependencies = {
+      event_listener = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".event-listener."5.4.2" { inherit profileName; }).out;
+      pin_project_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".exr."1.73.0" = overridableMkRustCrate (profileName: rec {
     name = "exr";
     version = "1.73.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f83197f59927b46c04a183a619b7c29df34e63e63c7869320862268c0ef687e0"; };
     dependencies = {
-      bit_field = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bit_field."0.10.2" { inherit profileName; }).out;
-      half = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
-      lebe = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lebe."0.5.2" { inherit profileName; }).out;
-      miniz_oxide = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".miniz_oxide."0.8.9" { inherit profileName; }).out;
-      rayon_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon-core."1.12.1" { inherit profileName; }).out;
-      smallvec = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".smallvec."1.15.1" { inherit profileName; }).out;
-      zune_inflate = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zune-inflate."0.2.54" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bit_field" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bit_field."0.10.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "half" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "lebe" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lebe."0.5.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "miniz_oxide" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".miniz_oxide."0.8.9" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon-core."1.12.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "smallvec" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".smallvec."1.15.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "zune_inflate" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zune-inflate."0.2.54" { inherit profileName; }).out;
     };
   });
   
@@ -1115,7 +1498,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1e6853b52649d4ac5c0bd02320cddc5ba956bdb407c4b75a2c6b75bf51500f8c"; };
     dependencies = {
-      simd_adler32 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".simd-adler32."0.3.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "simd_adler32" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".simd-adler32."0.3.7" { inherit profileName; }).out;
     };
   });
   
@@ -1132,14 +1515,14 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4a3d7db9596fecd151c5f638c0ee5d5bd487b6e0ea232e5dc96d5250f6f94b1d"; };
     features = builtins.concatLists [
-      [ "any_impl" ]
-      [ "default" ]
-      [ "miniz_oxide" ]
-      [ "rust_backend" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "any_impl")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "miniz_oxide")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rust_backend")
     ];
     dependencies = {
-      crc32fast = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crc32fast."1.4.2" { inherit profileName; }).out;
-      miniz_oxide = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".miniz_oxide."0.8.9" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "crc32fast" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crc32fast."1.4.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "miniz_oxide" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".miniz_oxide."0.8.9" { inherit profileName; }).out;
     };
   });
   
@@ -1149,19 +1532,19 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "da0e4dd2a88388a1f4ccc7c9ce104604dab68d9f408dc34cd45823d5a9069095"; };
     features = builtins.concatLists [
-      [ "async" ]
-      [ "default" ]
-      [ "eventual-fairness" ]
-      [ "futures-core" ]
-      [ "futures-sink" ]
-      [ "nanorand" ]
-      [ "select" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "async")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "eventual-fairness")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "futures-core")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "futures-sink")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "nanorand")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "select")
     ];
     dependencies = {
-      futures_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
-      futures_sink = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-sink."0.3.31" { inherit profileName; }).out;
-      nanorand = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nanorand."0.7.0" { inherit profileName; }).out;
-      spin1 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".spin."0.9.8" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "futures_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "futures_sink" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-sink."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nanorand" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nanorand."0.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "spin1" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".spin."0.9.8" { inherit profileName; }).out;
     };
   });
   
@@ -1182,7 +1565,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f6f339eb8adc052cd2ca78910fda869aefa38d22d5cb648e6485e4d3fc06f3b1"; };
     dependencies = {
-      foreign_types_shared = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".foreign-types-shared."0.1.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "foreign_types_shared" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".foreign-types-shared."0.1.1" { inherit profileName; }).out;
     };
   });
   
@@ -1200,6 +1583,7 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "e13624c2627564efccf4934284bdd98cbaa14e79b0b5a141218e507b3a823456"; };
     features = builtins.concatLists [
       [ "alloc" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
       [ "std" ]
     ];
     dependencies = {
@@ -1236,6 +1620,7 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "2dff15bf788c671c1934e366d07e30c1814a8ef514e1af724a602e8a2fbe1b10"; };
     features = builtins.concatLists [
       [ "alloc" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
       [ "futures-sink" ]
       [ "sink" ]
       [ "std" ]
@@ -1253,6 +1638,7 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "05f29059c0c2090612e8d742178b0580d2dc940c837851ad723096f87af6663e"; };
     features = builtins.concatLists [
       [ "alloc" ]
+      [ "default" ]
       [ "std" ]
     ];
   });
@@ -1263,6 +1649,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1e28d1d997f585e54aebc3f97d39e72338912123a67330d723fdbb564d646c9f"; };
     features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
       [ "std" ]
     ];
     dependencies = {
@@ -1278,10 +1665,46 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9e5c1b78ca4aae1ac06c48a526a655760685149f0d465d21f37abfe57ce075c6"; };
     features = builtins.concatLists [
+      [ "default" ]
       [ "std" ]
     ];
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".futures-lite."2.6.1" = overridableMkRustCrate (profileName: rec {
+    name = "futures-lite";
+    version = "2.6.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "f78e10609fe0e0b3f4157ffab1876319b5b0db102a2c60dc4626306dc46b44ad"; };
+    features = builtins.concatLists [
+      [ "alloc" ]
+      [ "default" ]
+      [ "fastrand" ]
+      [ "futures-io" ]
+      [ "parking" ]
+      [ "race" ]
+      [ "std" ]
+    ];
+    dependencies = {
+      fastrand = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".fastrand."2.3.0" { inherit profileName; }).out;
+      futures_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      futures_io = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-io."0.3.31" { inherit profileName; }).out;
+      parking = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".parking."2.2.1" { inherit profileName; }).out;
+      pin_project_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".futures-macro."0.3.31" = overridableMkRustCrate (profileName: rec {
+    name = "futures-macro";
+    version = "0.3.31";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "162ee34ebcb7c64a8abebc059ce0fee27c2262618d7b60ed8faf72fef13c3650"; };
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".futures-sink."0.3.31" = overridableMkRustCrate (profileName: rec {
     name = "futures-sink";
     version = "0.3.31";
@@ -1289,6 +1712,7 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "e575fab7d1e0dcb8d0c7bcf9a63ee213816ab51902e6d244a95819acacf1d4f7"; };
     features = builtins.concatLists [
       [ "alloc" ]
+      [ "default" ]
       [ "std" ]
     ];
   });
@@ -1311,9 +1735,12 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "9fa08315bb612088cc391249efdc3bc77536f16c91f6cf495e6fbe85b20a4a81"; };
     features = builtins.concatLists [
       [ "alloc" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "async-await")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "async-await-macro")
       [ "channel" ]
       [ "futures-channel" ]
       [ "futures-io" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "futures-macro")
       [ "futures-sink" ]
       [ "io" ]
       [ "memchr" ]
@@ -1325,6 +1752,7 @@ in
       futures_channel = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-channel."0.3.31" { inherit profileName; }).out;
       futures_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
       futures_io = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-io."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-macro."0.3.31" { profileName = "__noProfile"; }).out;
       futures_sink = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-sink."0.3.31" { inherit profileName; }).out;
       futures_task = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-task."0.3.31" { inherit profileName; }).out;
       memchr = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".memchr."2.7.5" { inherit profileName; }).out;
@@ -1340,26 +1768,26 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6ab24cc62135b40090e31a76a9b2766a501979f3070fa27f689c27ec04377d32"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "f16" ]
-      [ "gemm-f16" ]
-      [ "rayon" ]
-      [ "std" ]
-      [ "wasm-simd128-enable" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "f16")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "gemm-f16")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "wasm-simd128-enable")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
-      gemm_c32 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-c32."0.17.1" { inherit profileName; }).out;
-      gemm_c64 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-c64."0.17.1" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
-      gemm_f16 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f16."0.17.1" { inherit profileName; }).out;
-      gemm_f32 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f32."0.17.1" { inherit profileName; }).out;
-      gemm_f64 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f64."0.17.1" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_c32" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-c32."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_c64" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-c64."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_f16" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f16."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_f32" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f32."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_f64" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f64."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1369,26 +1797,26 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ab96b703d31950f1aeddded248bc95543c9efc7ac9c4a21fda8703a83ee35451"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "f16" ]
-      [ "gemm-f16" ]
-      [ "rayon" ]
-      [ "std" ]
-      [ "wasm-simd128-enable" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "f16")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "gemm-f16")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "wasm-simd128-enable")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
-      gemm_c32 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-c32."0.18.2" { inherit profileName; }).out;
-      gemm_c64 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-c64."0.18.2" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
-      gemm_f16 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f16."0.18.2" { inherit profileName; }).out;
-      gemm_f32 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f32."0.18.2" { inherit profileName; }).out;
-      gemm_f64 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f64."0.18.2" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_c32" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-c32."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_c64" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-c64."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_f16" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f16."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_f32" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f32."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_f64" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f64."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1398,17 +1826,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b9c030d0b983d1e34a546b86e08f600c11696fde16199f971cd46c12e67512c0"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1418,17 +1846,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f6db9fd9f40421d00eea9dd0770045a5603b8d684654816637732463f4073847"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1438,17 +1866,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "fbb5f2e79fefb9693d18e1066a557b4546cd334b226beadc68b11a8f9431852a"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1458,17 +1886,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "dfcad8a3d35a43758330b635d02edad980c1e143dc2f21e6fd25f9e4eada8edf"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1478,26 +1906,26 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a2e7ea062c987abcd8db95db917b4ffb4ecdfd0668471d8dc54734fdff2354e8"; };
     features = builtins.concatLists [
-      [ "f16" ]
-      [ "half" ]
-      [ "rayon" ]
-      [ "std" ]
-      [ "sysctl" ]
-      [ "wasm-simd128-enable" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "f16")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "half")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "sysctl")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "wasm-simd128-enable")
     ];
     dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
-      half = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      pulp = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pulp."0.18.22" { inherit profileName; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
-      ${ if hostPlatform.parsed.vendor.name == "apple" then "sysctl" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sysctl."0.5.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "half" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "pulp" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pulp."0.18.22" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.parsed.vendor.name == "apple" then "sysctl" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sysctl."0.5.5" { inherit profileName; }).out;
     };
   });
   
@@ -1507,27 +1935,27 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a352d4a69cbe938b9e2a9cb7a3a63b7e72f9349174a2752a558a8a563510d0f3"; };
     features = builtins.concatLists [
-      [ "f16" ]
-      [ "half" ]
-      [ "rayon" ]
-      [ "std" ]
-      [ "sysctl" ]
-      [ "wasm-simd128-enable" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "f16")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "half")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "sysctl")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "wasm-simd128-enable")
     ];
     dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
-      half = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
-      libm = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libm."0.2.15" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      pulp = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pulp."0.21.5" { inherit profileName; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
-      ${ if hostPlatform.parsed.vendor.name == "apple" then "sysctl" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sysctl."0.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "half" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "libm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libm."0.2.15" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "pulp" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pulp."0.21.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.parsed.vendor.name == "apple" then "sysctl" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sysctl."0.6.0" { inherit profileName; }).out;
     };
   });
   
@@ -1537,20 +1965,20 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7ca4c06b9b11952071d317604acb332e924e817bd891bec8dfb494168c7cedd4"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
-      gemm_f32 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f32."0.17.1" { inherit profileName; }).out;
-      half = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_f32" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f32."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "half" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1560,20 +1988,20 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cff95ae3259432f3c3410eaa919033cd03791d81cebd18018393dc147952e109"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
-      gemm_f32 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f32."0.18.2" { inherit profileName; }).out;
-      half = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_f32" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-f32."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "half" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1583,17 +2011,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e9a69f51aaefbd9cf12d18faf273d3e982d9d711f60775645ed5c8047b4ae113"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1603,17 +2031,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "bc8d3d4385393304f407392f754cd2dc4b315d05063f62cf09f47b58de276864"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1623,17 +2051,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "aa397a48544fadf0b81ec8741e5c0fba0043008113f71f2034def1935645d2b0"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.17.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."10.7.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1643,17 +2071,33 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "35b2a4f76ce4b8b16eadc11ccf2e083252d8237c1b589558a49b0183545015bd"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      dyn_stack = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
-      gemm_common = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      raw_cpuid = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
-      seq_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "dyn_stack" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dyn-stack."0.13.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm_common" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm-common."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "raw_cpuid" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".raw-cpuid."11.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "seq_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" { profileName = "__noProfile"; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".generic-array."0.14.7" = overridableMkRustCrate (profileName: rec {
+    name = "generic-array";
+    version = "0.14.7";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "85649ca51fd72272d7821adaf274ad91c288277713d9c18820d8499a7ff69e9a"; };
+    features = builtins.concatLists [
+      [ "more_lengths" ]
+    ];
+    dependencies = {
+      typenum = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".typenum."1.20.1" { inherit profileName; }).out;
+    };
+    buildDependencies = {
+      version_check = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".version_check."0.9.5" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -1663,18 +2107,18 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "335ff9f135e4384c8150d6f27c6daed433577f86b4750418338c01a1a2528592"; };
     features = builtins.concatLists [
-      [ "js" ]
-      [ "js-sys" ]
-      [ "rdrand" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "js")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "js-sys")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "rdrand")
       [ "std" ]
-      [ "wasm-bindgen" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "wasm-bindgen")
     ];
     dependencies = {
       cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
-      ${ if (hostPlatform.parsed.cpu.name == "wasm32" || hostPlatform.parsed.cpu.name == "wasm64") && hostPlatform.parsed.kernel.name == "unknown" then "js_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".js-sys."0.3.77" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.cpu.name == "wasm32" || hostPlatform.parsed.cpu.name == "wasm64") && hostPlatform.parsed.kernel.name == "unknown" then "js_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".js-sys."0.3.77" { inherit profileName; }).out;
       ${ if hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
       ${ if hostPlatform.parsed.kernel.name == "wasi" then "wasi" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasi."0.11.1+wasi-snapshot-preview1" { inherit profileName; }).out;
-      ${ if (hostPlatform.parsed.cpu.name == "wasm32" || hostPlatform.parsed.cpu.name == "wasm64") && hostPlatform.parsed.kernel.name == "unknown" then "wasm_bindgen" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.cpu.name == "wasm32" || hostPlatform.parsed.cpu.name == "wasm64") && hostPlatform.parsed.kernel.name == "unknown" then "wasm_bindgen" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
     };
   });
   
@@ -1700,14 +2144,14 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3fb2d69b19215e18bb912fa30f7ce15846e301408695e44e0ef719f1da9e19f2"; };
     features = builtins.concatLists [
-      [ "color_quant" ]
-      [ "default" ]
-      [ "raii_no_panic" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "color_quant")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "raii_no_panic")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      color_quant = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".color_quant."1.1.0" { inherit profileName; }).out;
-      weezl = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".weezl."0.1.10" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "color_quant" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".color_quant."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "weezl" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".weezl."0.1.10" { inherit profileName; }).out;
     };
   });
   
@@ -1723,32 +2167,25 @@ in
     version = "2.6.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "459196ed295495a68f7d7fe1d84f6c4b7ff0e21fe3017b2f283c6fac3ad803c9"; };
-    features = builtins.concatLists [
-      [ "alloc" ]
-      [ "bytemuck" ]
-      [ "default" ]
-      [ "num-traits" ]
-      [ "rand_distr" ]
-      [ "std" ]
-      [ "use-intrinsics" ]
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "bytemuck")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "num-traits")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rand_distr")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "use-intrinsics")
     ];
     dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
-      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.cpu.name == "spirv" then "crunchy" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crunchy."0.2.3" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      rand = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.9.1" { inherit profileName; }).out;
-      rand_distr = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand_distr."0.5.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.parsed.cpu.name == "spirv" then "crunchy" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crunchy."0.2.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rand" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rand_distr" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand_distr."0.5.1" { inherit profileName; }).out;
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".hashbrown."0.14.5" = overridableMkRustCrate (profileName: rec {
-    name = "hashbrown";
-    version = "0.14.5";
-    registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "e5274423e17b7c9fc20b6e7e208532f9b19825d82dfd615708b70edd83df41f1"; };
-  });
-  
   "registry+https://github.com/rust-lang/crates.io-index".hashbrown."0.15.4" = overridableMkRustCrate (profileName: rec {
     name = "hashbrown";
     version = "0.15.4";
@@ -1773,26 +2210,38 @@ in
     ];
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".hex."0.4.3" = overridableMkRustCrate (profileName: rec {
+    name = "hex";
+    version = "0.4.3";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "7f24254aa9a54b5c858eaee2f5bccdb46aaf0e486a595ed5fd8f86ba55232a70"; };
+    features = builtins.concatLists [
+      [ "alloc" ]
+      [ "default" ]
+      [ "std" ]
+    ];
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".hf-hub."0.4.3" = overridableMkRustCrate (profileName: rec {
     name = "hf-hub";
     version = "0.4.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "629d8f3bbeda9d148036d6b0de0a3ab947abd08ce90626327fc3547a49d59d97"; };
     features = builtins.concatLists [
-      [ "ureq" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "ureq")
     ];
     dependencies = {
-      dirs = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dirs."6.0.0" { inherit profileName; }).out;
-      http = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
-      indicatif = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".indicatif."0.17.11" { inherit profileName; }).out;
-      ${ if hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
-      rand = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.9.1" { inherit profileName; }).out;
-      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
-      serde_json = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_json."1.0.140" { inherit profileName; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
-      ureq = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ureq."2.12.1" { inherit profileName; }).out;
-      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.60.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "dirs" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dirs."6.0.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "indicatif" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".indicatif."0.17.11" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "rand" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "serde_json" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_json."1.0.140" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "ureq" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ureq."2.12.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.60.2" { inherit profileName; }).out;
     };
   });
   
@@ -1802,7 +2251,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "589533453244b0995c858700322199b2becb13b627df2851f64a2775d024abcf"; };
     dependencies = {
-      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.59.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.59.0" { inherit profileName; }).out;
     };
   });
   
@@ -1812,13 +2261,117 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f4a85d31aea989eead29a3aaf9e1115a180df8282431156e533de47660892565"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "std")
     ];
     dependencies = {
-      bytes = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
-      fnv = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".fnv."1.0.7" { inherit profileName; }).out;
-      itoa = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".itoa."1.0.15" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "fnv" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".fnv."1.0.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "itoa" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".itoa."1.0.15" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".http-body."1.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "http-body";
+    version = "1.1.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "ca2a8f2913ee65f60facd6a5905613afaa448497a0230cc41ce022d93290bc2c"; };
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".http-body-util."0.1.5" = overridableMkRustCrate (profileName: rec {
+    name = "http-body-util";
+    version = "0.1.5";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "23169fe34a5fbcdd3f3862e78fb9b6fccd5f02a6dc6f732547005d45631ce71c"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http_body" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http-body."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "pin_project_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".httparse."1.10.1" = overridableMkRustCrate (profileName: rec {
+    name = "httparse";
+    version = "1.10.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "6dbf3de79e51f3d586ab4cb9d5c3e2c14aa28ed23d180cf89b4df0454a69cc87"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/otel") "std")
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".httpdate."1.0.3" = overridableMkRustCrate (profileName: rec {
+    name = "httpdate";
+    version = "1.0.3";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "df3b46402a9d5adb4c86a0cf463f42e19994e3ee891101b1841f30a545cb49a9"; };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".hyper."1.12.0" = overridableMkRustCrate (profileName: rec {
+    name = "hyper";
+    version = "1.12.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "2c3e324da4c95177d6291d4c8730197c0d1822f8a9766814a4a44fa5ab797c9c"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "client")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "http1")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "atomic_waker" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".atomic-waker."1.1.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http_body" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http-body."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "httparse" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".httparse."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "itoa" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".itoa."1.0.15" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "pin_project_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "smallvec" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".smallvec."1.15.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tokio" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tokio."1.50.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "want" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".want."0.3.2" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".hyper-util."0.1.21" = overridableMkRustCrate (profileName: rec {
+    name = "hyper-util";
+    version = "0.1.21";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "ddc03d96684f9226b8a787cdb71488417b53ab5ea8fdb1dac946cb9431cc8bff"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "client")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "client-legacy")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "client-proxy")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "http1")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "tokio")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "base64" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".base64."0.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_channel" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-channel."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-util."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http_body" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http-body."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "httparse" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".httparse."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "hyper" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hyper."1.12.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "ipnet" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ipnet."2.12.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "percent_encoding" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".percent-encoding."2.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "pin_project_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "socket2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".socket2."0.6.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tokio" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tokio."1.50.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tower_service" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower-service."0.3.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tracing" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.44" { inherit profileName; }).out;
     };
   });
   
@@ -1965,41 +2518,41 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "db35664ce6b9810857a38a906215e75a9c879f0696556a39f59c62829710251a"; };
     features = builtins.concatLists [
-      [ "avif" ]
-      [ "bmp" ]
-      [ "dds" ]
-      [ "default" ]
-      [ "default-formats" ]
-      [ "exr" ]
-      [ "ff" ]
-      [ "gif" ]
-      [ "hdr" ]
-      [ "ico" ]
-      [ "jpeg" ]
-      [ "png" ]
-      [ "pnm" ]
-      [ "qoi" ]
-      [ "rayon" ]
-      [ "tga" ]
-      [ "tiff" ]
-      [ "webp" ]
-    ];
-    dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
-      byteorder_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder-lite."0.1.0" { inherit profileName; }).out;
-      color_quant = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".color_quant."1.1.0" { inherit profileName; }).out;
-      exr = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".exr."1.73.0" { inherit profileName; }).out;
-      gif = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gif."0.13.1" { inherit profileName; }).out;
-      image_webp = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".image-webp."0.2.2" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      png = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".png."0.17.16" { inherit profileName; }).out;
-      qoi = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".qoi."0.4.1" { inherit profileName; }).out;
-      ravif = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ravif."0.11.12" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
-      rgb = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rgb."0.8.50" { inherit profileName; }).out;
-      tiff = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tiff."0.9.1" { inherit profileName; }).out;
-      zune_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zune-core."0.4.12" { inherit profileName; }).out;
-      zune_jpeg = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zune-jpeg."0.4.17" { inherit profileName; }).out;
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "avif")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "bmp")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "dds")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default-formats")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "exr")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "ff")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "gif")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "hdr")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "ico")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "jpeg")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "png")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "pnm")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "qoi")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "tga")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "tiff")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "webp")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "byteorder_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder-lite."0.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "color_quant" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".color_quant."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "exr" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".exr."1.73.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gif" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gif."0.13.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "image_webp" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".image-webp."0.2.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "png" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".png."0.17.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "qoi" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".qoi."0.4.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "ravif" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ravif."0.11.12" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rgb" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rgb."0.8.50" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "tiff" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tiff."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "zune_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zune-core."0.4.12" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "zune_jpeg" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zune-jpeg."0.4.17" { inherit profileName; }).out;
     };
   });
   
@@ -2009,8 +2562,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "14d75c7014ddab93c232bc6bb9f64790d3dfd1d605199acd4b40b6d69e691e9f"; };
     dependencies = {
-      byteorder_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder-lite."0.1.0" { inherit profileName; }).out;
-      quick_error = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quick-error."2.0.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "byteorder_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder-lite."0.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quick_error" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quick-error."2.0.1" { inherit profileName; }).out;
     };
   });
   
@@ -2020,8 +2573,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d0263a3d970d5c054ed9312c0057b4f3bde9c0b33836d3637361d4a9e6e7a408"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "deprecated" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "deprecated")
     ];
   });
   
@@ -2046,15 +2599,15 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "183b3088984b400f4cfac3620d5e076c84da5364016b4f49473de574b2586235"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "unicode-width" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "unicode-width")
     ];
     dependencies = {
-      console = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".console."0.15.11" { inherit profileName; }).out;
-      number_prefix = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".number_prefix."0.4.0" { inherit profileName; }).out;
-      portable_atomic = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".portable-atomic."1.11.1" { inherit profileName; }).out;
-      unicode_width = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".unicode-width."0.2.1" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.cpu.name == "wasm32" then "web_time" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".web-time."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "console" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".console."0.15.11" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "number_prefix" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".number_prefix."0.4.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "portable_atomic" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".portable-atomic."1.11.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "unicode_width" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".unicode-width."0.2.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.parsed.cpu.name == "wasm32" then "web_time" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".web-time."1.1.0" { inherit profileName; }).out;
     };
   });
   
@@ -2064,12 +2617,23 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c34819042dc3d3971c46c2190835914dfbe0c3c13f61449b2997f4e9722dfa60"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".ipnet."2.12.2" = overridableMkRustCrate (profileName: rec {
+    name = "ipnet";
+    version = "2.12.2";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "791930b43c0d5973160d90a8f3894509f2b273430f5c5c73b668636d0287c5c0"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "std")
+    ];
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".is_terminal_polyfill."1.70.1" = overridableMkRustCrate (profileName: rec {
     name = "is_terminal_polyfill";
     version = "1.70.1";
@@ -2086,9 +2650,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ba291022dbbd398a455acf126c1e341954079855bc60dfdda641363bd6922569"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "default")
       [ "use_alloc" ]
-      [ "use_std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "use_std")
     ];
     dependencies = {
       either = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".either."1.15.0" { inherit profileName; }).out;
@@ -2108,8 +2672,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "38f262f097c174adebe41eb73d66ae9c06b2844fb0da69969647bbddd9b0538a"; };
     dependencies = {
-      ${ if hostPlatform.isWindows then "getrandom" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".getrandom."0.3.3" { inherit profileName; }).out;
-      ${ if hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.isWindows then "getrandom" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".getrandom."0.3.3" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
   });
   
@@ -2126,12 +2690,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1cfaf33c695fc6e08064efbc1f72ec937429614f25eef83af942d0e227c3a28f"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "std")
     ];
     dependencies = {
-      once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      wasm_bindgen = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "wasm_bindgen" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
     };
   });
   
@@ -2174,14 +2738,14 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cf78f52d400cf2d84a3a973a78a592b4adc535739e0a5597a0da6f0c357adc75"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "link_libfuzzer" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "link_libfuzzer")
     ];
     dependencies = {
-      arbitrary = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arbitrary."1.4.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "arbitrary" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arbitrary."1.4.1" { inherit profileName; }).out;
     };
     buildDependencies = {
-      cc = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cc."1.2.27" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "cc" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cc."1.2.27" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -2191,8 +2755,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "07033963ba89ebaf1584d767badaa2e8fcec21aedea6b8c0346d487d49c28667"; };
     dependencies = {
-      ${ if hostPlatform.isUnix then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
-      ${ if hostPlatform.isWindows then "windows_targets" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-targets."0.53.2" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.isUnix then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.isWindows then "windows_targets" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-targets."0.53.2" { inherit profileName; }).out;
     };
   });
   
@@ -2202,8 +2766,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f9fbbcab51052fe104eb5e5d351cf728d30a5be1fe14d9be8a3b097481fb97de"; };
     features = builtins.concatLists [
-      [ "arch" ]
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "arch")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
     ];
   });
   
@@ -2213,12 +2777,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c0ff37bd590ca25063e35af745c343cb7a0271906fb7b37e4813e8f79f00268d"; };
     features = builtins.concatLists [
-      [ "call" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "call")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
   });
   
@@ -2228,11 +2792,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d26c52dbd32dccf2d10cac7725f8eae5296885fb5703b261f7d0a0739ec807ab"; };
     features = builtins.concatLists [
-      [ "elf" ]
-      [ "errno" ]
-      [ "general" ]
-      [ "ioctl" ]
-      [ "no_std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "elf")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "errno")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "general")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "ioctl")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "no_std")
     ];
   });
   
@@ -2245,8 +2809,13 @@ in
       [ "elf" ]
       [ "errno" ]
       [ "general" ]
+      [ "if_ether" ]
       [ "ioctl" ]
+      [ "net" ]
+      [ "netlink" ]
       [ "no_std" ]
+      [ "prctl" ]
+      [ "xdp" ]
     ];
   });
   
@@ -2293,7 +2862,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0fae87c125b03c1d2c0150c90365d7d6bcc53fb73a9acaef207d2d065860f062"; };
     dependencies = {
-      imgref = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".imgref."1.11.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "imgref" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".imgref."1.11.0" { inherit profileName; }).out;
     };
   });
   
@@ -2303,17 +2872,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "62bb907fe88d54d8d9ce32a3cceab4218ed2f6b7d35617cafe9adf84e43919cb"; };
     dependencies = {
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".matchers."0.1.0" = overridableMkRustCrate (profileName: rec {
+  "registry+https://github.com/rust-lang/crates.io-index".matchers."0.2.0" = overridableMkRustCrate (profileName: rec {
     name = "matchers";
-    version = "0.1.0";
+    version = "0.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "8263075bb86c5a1b1427b5ae862e8889656f126e9f77c484496e8b47cf5c5558"; };
+    src = fetchCratesIo { inherit name version; sha256 = "d1525a2a28c7f4fa0fc98bb91ae755d1e2d1505079e05539e35bc876b5d65ae9"; };
     dependencies = {
-      regex_automata = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".regex-automata."0.1.10" { inherit profileName; }).out;
+      regex_automata = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".regex-automata."0.4.9" { inherit profileName; }).out;
     };
   });
   
@@ -2323,12 +2892,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8ea1f30cedd69f0a2954655f7188c6a834246d2bcf1e315e2ac40c4b24dc9519"; };
     features = builtins.concatLists [
-      [ "rayon" ]
-      [ "threads" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "rayon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "threads")
     ];
     dependencies = {
-      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
     };
   });
   
@@ -2350,11 +2919,24 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "fd3f7eed9d3848f8b98834af67102b720745c4ec028fcd0aa0239277e7de374f"; };
     features = builtins.concatLists [
-      [ "stable_deref_trait" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "stable_deref_trait")
     ];
     dependencies = {
-      ${ if hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      stable_deref_trait = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".stable_deref_trait."1.2.0" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "stable_deref_trait" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".stable_deref_trait."1.2.0" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".memoffset."0.9.1" = overridableMkRustCrate (profileName: rec {
+    name = "memoffset";
+    version = "0.9.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "488016bfae457b036d996092f6cb448677611ce4449e970ceaf42695203f218a"; };
+    features = builtins.concatLists [
+      [ "default" ]
+    ];
+    buildDependencies = {
+      autocfg = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".autocfg."1.4.0" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -2364,17 +2946,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e198a0ee42bdbe9ef2c09d0b9426f3b2b47d90d93a4a9b0395c4cea605e92dc0"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "private" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "private")
     ];
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
-      block = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".block."0.1.6" { inherit profileName; }).out;
-      cocoa = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cocoa."0.20.2" { inherit profileName; }).out;
-      core_graphics = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-graphics."0.19.2" { inherit profileName; }).out;
-      foreign_types = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".foreign-types."0.3.2" { inherit profileName; }).out;
-      log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
-      objc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc."0.2.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "block" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".block."0.1.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "cocoa" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cocoa."0.20.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "core_graphics" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-graphics."0.19.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "foreign_types" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".foreign-types."0.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "objc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc."0.2.7" { inherit profileName; }).out;
     };
   });
   
@@ -2384,7 +2966,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "68354c5c6bd36d73ff3feceb05efa59b6acb7626617f4962be322a825e61f79a"; };
     features = builtins.concatLists [
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
   });
   
@@ -2394,14 +2976,31 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1fa76a2c86f704bdb222d66965fb3d63269ce38518b83cb0575fca855ebb6316"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "simd" ]
-      [ "simd-adler32" ]
-      [ "with-alloc" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "simd")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "simd-adler32")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "with-alloc")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "adler2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".adler2."2.0.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "simd_adler32" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".simd-adler32."0.3.7" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".mio."1.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "mio";
+    version = "1.1.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "69d83b0086dc8ecf3ce9ae2874b2d1290252e2a30720bea58a5c6639b0092873"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "net")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "os-ext")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "os-poll")
     ];
     dependencies = {
-      adler2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".adler2."2.0.1" { inherit profileName; }).out;
-      simd_adler32 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".simd-adler32."0.3.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && (hostPlatform.isUnix || hostPlatform.parsed.kernel.name == "hermit" || hostPlatform.parsed.kernel.name == "wasi") then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.parsed.kernel.name == "wasi" then "wasi" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasi."0.11.1+wasi-snapshot-preview1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.61.2" { inherit profileName; }).out;
     };
   });
   
@@ -2411,14 +3010,14 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b7891b80aaa86097d38d276eb98b3805d6280708c4e0a1e6f6aed9380c51fec9"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
     ];
     dependencies = {
-      arrayvec = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arrayvec."0.7.6" { inherit profileName; }).out;
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      mozjpeg_sys = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".mozjpeg-sys."2.2.3" { inherit profileName; }).out;
-      rgb = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rgb."0.8.50" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "arrayvec" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arrayvec."0.7.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "mozjpeg_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".mozjpeg-sys."2.2.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "rgb" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rgb."0.8.50" { inherit profileName; }).out;
     };
   });
   
@@ -2428,19 +3027,19 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7f0dc668bf9bf888c88e2fb1ab16a406d2c380f1d082b20d51dd540ab2aa70c1"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "nasm_simd" ]
-      [ "parallel" ]
-      [ "unwinding" ]
-      [ "with_simd" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "nasm_simd")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "parallel")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "unwinding")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "with_simd")
     ];
     dependencies = {
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
     buildDependencies = {
-      cc = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cc."1.2.27" { profileName = "__noProfile"; }).out;
-      dunce = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".dunce."1.0.5" { profileName = "__noProfile"; }).out;
-      nasm_rs = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".nasm-rs."0.3.0" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "cc" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cc."1.2.27" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "dunce" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".dunce."1.0.5" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nasm_rs" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".nasm-rs."0.3.0" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -2457,17 +3056,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6a51313c5820b0b02bd422f4b44776fbf47961755c74ce64afc73bfad10226c3"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "chacha" ]
-      [ "default" ]
-      [ "getrandom" ]
-      [ "pcg64" ]
-      [ "std" ]
-      [ "tls" ]
-      [ "wyrand" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "chacha")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "getrandom")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "pcg64")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "std")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "tls")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "wyrand")
     ];
     dependencies = {
-      getrandom = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".getrandom."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "getrandom" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".getrandom."0.2.16" { inherit profileName; }).out;
     };
   });
   
@@ -2477,11 +3076,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "12fcfa1bd49e0342ec1d07ed2be83b59963e7acbeb9310e1bb2c07b69dadd959"; };
     features = builtins.concatLists [
-      [ "jobserver" ]
-      [ "parallel" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "jobserver")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "parallel")
     ];
     dependencies = {
-      jobserver = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".jobserver."0.1.33" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "jobserver" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".jobserver."0.1.33" { inherit profileName; }).out;
     };
   });
   
@@ -2492,31 +3091,54 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "650eef8c711430f1a879fdd01d4745a7deea475becfb90269c06775983bbf086"; };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".nix."0.29.0" = overridableMkRustCrate (profileName: rec {
+    name = "nix";
+    version = "0.29.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "71e2746dc3a24dd78b3cfcb7be93368c6de9963d30f43a6a73998a9cf4b17b46"; };
+    features = builtins.concatLists [
+      [ "feature" ]
+      [ "memoffset" ]
+      [ "socket" ]
+      [ "uio" ]
+      [ "user" ]
+    ];
+    dependencies = {
+      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
+      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      memoffset = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".memoffset."0.9.1" { inherit profileName; }).out;
+    };
+    buildDependencies = {
+      cfg_aliases = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg_aliases."0.2.2" { profileName = "__noProfile"; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".nokhwa."0.10.7" = overridableMkRustCrate (profileName: rec {
     name = "nokhwa";
     version = "0.10.7";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "726195ba627960de1df8695400807eb929d196cae7194dc1cf7ee06728136168"; };
     features = builtins.concatLists [
-      [ "decoding" ]
-      [ "default" ]
-      [ "input-avfoundation" ]
-      [ "input-msmf" ]
-      [ "input-native" ]
-      [ "input-v4l" ]
-      [ "nokhwa-bindings-linux" ]
-      [ "nokhwa-bindings-macos" ]
-      [ "nokhwa-bindings-windows" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "decoding")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "input-avfoundation")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "input-msmf")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "input-native")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "input-v4l")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "nokhwa-bindings-linux")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "nokhwa-bindings-macos")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "nokhwa-bindings-windows")
     ];
     dependencies = {
-      flume = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".flume."0.11.1" { inherit profileName; }).out;
-      image = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".image."0.25.6" { inherit profileName; }).out;
-      nokhwa_bindings_linux = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-bindings-linux."0.1.1" { inherit profileName; }).out;
-      nokhwa_bindings_macos = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-bindings-macos."0.2.2" { inherit profileName; }).out;
-      nokhwa_bindings_windows = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-bindings-windows."0.4.2" { inherit profileName; }).out;
-      nokhwa_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-core."0.1.5" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "flume" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".flume."0.11.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "image" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".image."0.25.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nokhwa_bindings_linux" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-bindings-linux."0.1.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nokhwa_bindings_macos" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-bindings-macos."0.2.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nokhwa_bindings_windows" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-bindings-windows."0.4.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nokhwa_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-core."0.1.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
     };
   });
   
@@ -2526,8 +3148,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9f1abe593709a177b1a6b87ebbae0bfe5ecc2f8d80d81e89e0a9b68487490a01"; };
     dependencies = {
-      nokhwa_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-core."0.1.5" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "linux" then "v4l" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".v4l."0.14.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nokhwa_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-core."0.1.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && hostPlatform.parsed.kernel.name == "linux" then "v4l" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".v4l."0.14.0" { inherit profileName; }).out;
     };
   });
   
@@ -2537,15 +3159,15 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f12deaea95ab828355b0a86ec0dffde895e4d5d9b79714a3be2caa294d868109"; };
     dependencies = {
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "block" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".block."0.1.6" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "cocoa_foundation" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cocoa-foundation."0.1.2" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "core_foundation" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.9.4" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "core_media_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-media-sys."0.1.2" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "core_video_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-video-sys."0.1.4" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "flume" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".flume."0.11.1" { inherit profileName; }).out;
-      nokhwa_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-core."0.1.5" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "objc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc."0.2.7" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "block" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".block."0.1.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "cocoa_foundation" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cocoa-foundation."0.1.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "core_foundation" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.9.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "core_media_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-media-sys."0.1.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "core_video_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-video-sys."0.1.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "flume" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".flume."0.11.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nokhwa_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-core."0.1.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "objc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc."0.2.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
     };
   });
   
@@ -2555,9 +3177,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "21bdd1a21fba66d677559b3e216cdcf23234569853afbf26954d0502c93ff18b"; };
     dependencies = {
-      nokhwa_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-core."0.1.5" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "windows" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "windows" then "windows" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows."0.43.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "nokhwa_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nokhwa-core."0.1.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && hostPlatform.parsed.kernel.name == "windows" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && hostPlatform.parsed.kernel.name == "windows" then "windows" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows."0.43.0" { inherit profileName; }).out;
     };
   });
   
@@ -2567,15 +3189,15 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b09efd57b56917acc6bd0edad5111b1d073531f14f8625f980875a1fd78a3644"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "mjpeg" ]
-      [ "mozjpeg" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "mjpeg")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "mozjpeg")
     ];
     dependencies = {
-      bytes = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
-      image = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".image."0.25.6" { inherit profileName; }).out;
-      mozjpeg = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".mozjpeg."0.10.13" { inherit profileName; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "image" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".image."0.25.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "mozjpeg" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".mozjpeg."0.10.13" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
     };
   });
   
@@ -2585,13 +3207,13 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d273983c5a657a70a3e8f2a01329822f3b8c8172b73826411a55751e404a0a4a"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      memchr = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".memchr."2.7.5" { inherit profileName; }).out;
-      minimal_lexical = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".minimal-lexical."0.2.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "memchr" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".memchr."2.7.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "minimal_lexical" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".minimal-lexical."0.2.1" { inherit profileName; }).out;
     };
   });
   
@@ -2616,14 +3238,17 @@ in
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".nu-ansi-term."0.46.0" = overridableMkRustCrate (profileName: rec {
+  "registry+https://github.com/rust-lang/crates.io-index".nu-ansi-term."0.50.3" = overridableMkRustCrate (profileName: rec {
     name = "nu-ansi-term";
-    version = "0.46.0";
+    version = "0.50.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "77a8165726e8236064dbb45459242600304b42a5ea24ee2948e18e023bf7ba84"; };
+    src = fetchCratesIo { inherit name version; sha256 = "7957b9740744892f114936ab4a57b3f487491bbeafaf8083688b16841a4240e5"; };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "std" ]
+    ];
     dependencies = {
-      overload = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".overload."0.1.1" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "windows" then "winapi" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "windows" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.61.2" { inherit profileName; }).out;
     };
   });
   
@@ -2633,17 +3258,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "35bd024e8b2ff75562e5f34e7f4905839deb4b22955ef5e73d2fea1b9813cb23"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "num-bigint" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "num-bigint")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      num_bigint = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-bigint."0.4.6" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      num_integer = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-integer."0.1.46" { inherit profileName; }).out;
-      num_iter = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-iter."0.1.45" { inherit profileName; }).out;
-      num_rational = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-rational."0.4.2" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_bigint" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-bigint."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_integer" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-integer."0.1.46" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_iter" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-iter."0.1.45" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_rational" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-rational."0.4.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
     };
   });
   
@@ -2653,11 +3278,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a5e44f723f1133c9deac646763579fdb3ac745e418f2a7af9cd0c431da1f20b9"; };
     features = builtins.concatLists [
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      num_integer = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-integer."0.1.46" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_integer" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-integer."0.1.46" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
     };
   });
   
@@ -2667,12 +3292,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "73f88a1307638156682bada9d7604135552957b7818057dcef22705b4d509495"; };
     features = builtins.concatLists [
-      [ "bytemuck" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "bytemuck")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
     };
   });
   
@@ -2682,9 +3307,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ed3955f1a9c7c0c15e092f9c887db08b1fc683305fdf6eb6684f22555355e202"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
@@ -2694,11 +3319,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7969661fd2958a5cb096e56c8e1ad0444ac2bbcd0061bd28660485a44879858f"; };
     features = builtins.concatLists [
-      [ "i128" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "i128")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
     };
   });
   
@@ -2708,15 +3333,15 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1429034a0490724d0075ebb2bc9e875d6503c3cf69e235a8941aa757d83ef5bf"; };
     features = builtins.concatLists [
-      [ "i128" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "i128")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      num_integer = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-integer."0.1.46" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_integer" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-integer."0.1.46" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
     };
     buildDependencies = {
-      autocfg = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".autocfg."1.4.0" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "autocfg" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".autocfg."1.4.0" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -2726,15 +3351,15 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f83d14da390562dca69fc84082e73e548e1ad308d24accdedd2720017cb37824"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "num-bigint" ]
-      [ "num-bigint-std" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "num-bigint")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "num-bigint-std")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      num_bigint = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-bigint."0.4.6" { inherit profileName; }).out;
-      num_integer = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-integer."0.1.46" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_bigint" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-bigint."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_integer" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-integer."0.1.46" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
     };
   });
   
@@ -2744,13 +3369,13 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "071dfc062690e90b734c0b2273ce72ad0ffa95f0c74596bc250dcfd960262841"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "i128" ]
-      [ "libm" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "i128")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "libm")
       [ "std" ]
     ];
     dependencies = {
-      libm = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libm."0.2.15" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "libm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libm."0.2.15" { inherit profileName; }).out;
     };
     buildDependencies = {
       autocfg = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".autocfg."1.4.0" { profileName = "__noProfile"; }).out;
@@ -2763,8 +3388,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "91df4bbde75afed763b708b7eee1e8e7651e02d97f6d5dd763e89367e957b23b"; };
     dependencies = {
-      ${ if hostPlatform.parsed.kernel.name == "hermit" then "hermit_abi" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hermit-abi."0.5.2" { inherit profileName; }).out;
-      ${ if !hostPlatform.isWindows then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.parsed.kernel.name == "hermit" then "hermit_abi" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hermit-abi."0.5.2" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && !hostPlatform.isWindows then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
     };
   });
   
@@ -2774,11 +3399,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4e613fc340b2220f734a8595782c551f1250e969d87d3be1ae0579e8d4065179"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      num_enum_derive = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".num_enum_derive."0.7.3" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_enum_derive" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".num_enum_derive."0.7.3" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -2788,14 +3413,14 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "af1844ef2428cc3e1cb900be36181049ef3d3193c63e43026cfe202983b27a56"; };
     features = builtins.concatLists [
-      [ "proc-macro-crate" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "proc-macro-crate")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      proc_macro_crate = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro-crate."3.3.0" { inherit profileName; }).out;
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro_crate" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro-crate."3.3.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
@@ -2805,8 +3430,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "830b246a0e5f20af87141b25c173cd1b609bd7779a4617d6ec582abaf90870f3"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "std")
     ];
   });
   
@@ -2816,12 +3441,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "915b1b472bc21c53464d6c8461c9d3af805ba1ef837e1cac254428f4a77177b1"; };
     features = builtins.concatLists [
-      [ "exception" ]
-      [ "objc_exception" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "exception")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "objc_exception")
     ];
     dependencies = {
-      malloc_buf = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".malloc_buf."0.0.6" { inherit profileName; }).out;
-      objc_exception = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc_exception."0.1.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "malloc_buf" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".malloc_buf."0.0.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "objc_exception" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".objc_exception."0.1.2" { inherit profileName; }).out;
     };
   });
   
@@ -2874,7 +3499,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ad970fb455818ad6cba4c122ad012fae53ae8b4795f86378bce65e4f6bab2ca4"; };
     buildDependencies = {
-      cc = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cc."1.2.27" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "cc" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cc."1.2.27" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -2901,6 +3526,126 @@ in
     ];
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".opentelemetry."0.31.0" = overridableMkRustCrate (profileName: rec {
+    name = "opentelemetry";
+    version = "0.31.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "b84bcd6ae87133e903af7ef497404dda70c60d0ea14895fc8a5e6722754fc2a0"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "futures")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "futures-core")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "futures-sink")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "internal-logs")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "logs")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "metrics")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "pin-project-lite")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "thiserror")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "trace")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "tracing")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_sink" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-sink."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.parsed.cpu.name == "wasm32" && !(hostPlatform.parsed.kernel.name == "wasi") then "js_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".js-sys."0.3.77" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "pin_project_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tracing" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.44" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".opentelemetry-http."0.31.0" = overridableMkRustCrate (profileName: rec {
+    name = "opentelemetry-http";
+    version = "0.31.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "d7a6d09a73194e6b66df7c8f1b680f156d916a1a942abf2de06823dd02b7855d"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "reqwest")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "reqwest-blocking")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "async_trait" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".async-trait."0.1.92" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry."0.31.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "reqwest" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".reqwest."0.12.28" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".opentelemetry-otlp."0.31.1" = overridableMkRustCrate (profileName: rec {
+    name = "opentelemetry-otlp";
+    version = "0.31.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "1f69cd6acbb9af919df949cd1ec9e5e7fdc2ef15d234b6b795aaa525cc02f71f"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "http")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "http-proto")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "metrics")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "opentelemetry-http")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "prost")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "reqwest")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "reqwest-blocking-client")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "trace")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry."0.31.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry_http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry-http."0.31.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry_proto" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry-proto."0.31.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry_sdk" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry_sdk."0.31.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "prost" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost."0.14.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "reqwest" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".reqwest."0.12.28" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".opentelemetry-proto."0.31.0" = overridableMkRustCrate (profileName: rec {
+    name = "opentelemetry-proto";
+    version = "0.31.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "a7175df06de5eaee9909d4805a3d07e28bb752c34cab57fa9cff549da596b30f"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "gen-tonic-messages")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "metrics")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "prost")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "tonic")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "tonic-prost")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "trace")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry."0.31.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry_sdk" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry_sdk."0.31.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "prost" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost."0.14.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tonic" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tonic."0.14.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tonic_prost" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tonic-prost."0.14.6" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".opentelemetry_sdk."0.31.0" = overridableMkRustCrate (profileName: rec {
+    name = "opentelemetry_sdk";
+    version = "0.31.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "e14ae4f5991976fd48df6d843de219ca6d31b01daaab2dad5af2badeded372bd"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "internal-logs")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "logs")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "metrics")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "percent-encoding")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "rand")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "trace")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_channel" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-channel."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_executor" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-executor."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-util."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry."0.31.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "percent_encoding" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".percent-encoding."2.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "rand" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".option-ext."0.2.0" = overridableMkRustCrate (profileName: rec {
     name = "option-ext";
     version = "0.2.0";
@@ -2908,11 +3653,22 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "04744f49eae99ab78e0d5c0b603ab218f515ea8cfe5a456d7629ad883a3b6e7d"; };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".overload."0.1.1" = overridableMkRustCrate (profileName: rec {
-    name = "overload";
-    version = "0.1.1";
+  "registry+https://github.com/rust-lang/crates.io-index".ordered-stream."0.2.0" = overridableMkRustCrate (profileName: rec {
+    name = "ordered-stream";
+    version = "0.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "b15813163c1d831bf4a13c3610c05c0d03b39feb07f7e09fa234dac9b15aaf39"; };
+    src = fetchCratesIo { inherit name version; sha256 = "9aa2b01e1d916879f73a53d01d1d6cee68adbb31d6d9177a8cfce093cced1d50"; };
+    dependencies = {
+      futures_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      pin_project_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".parking."2.2.1" = overridableMkRustCrate (profileName: rec {
+    name = "parking";
+    version = "2.2.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "f38d5652c16fde515bb1ecef450ab0f6a219d619a7274976324d5e377f7dceba"; };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".parking_lot."0.12.4" = overridableMkRustCrate (profileName: rec {
@@ -2920,9 +3676,6 @@ in
     version = "0.12.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "70d58bf43669b5795d1576d0641cfb6fbb2057bf629506267a92807158584a13"; };
-    features = builtins.concatLists [
-      [ "default" ]
-    ];
     dependencies = {
       lock_api = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lock_api."0.4.13" { inherit profileName; }).out;
       parking_lot_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".parking_lot_core."0.9.11" { inherit profileName; }).out;
@@ -2964,6 +3717,7 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "e3148f5046208a5d56bcfc03053e3ca6334e51da8dfb19b6cdc8b306fae3283e"; };
     features = builtins.concatLists [
       [ "alloc" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
       [ "std" ]
     ];
   });
@@ -2974,8 +3728,30 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b4c5cc86750666a3ed20bdaf5ca2a0344f9c67674cae0515bec2da16fbaa47db"; };
     dependencies = {
-      fixedbitset = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".fixedbitset."0.4.2" { inherit profileName; }).out;
-      indexmap = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".indexmap."2.9.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "fixedbitset" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".fixedbitset."0.4.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "indexmap" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".indexmap."2.9.0" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".pin-project."1.1.13" = overridableMkRustCrate (profileName: rec {
+    name = "pin-project";
+    version = "1.1.13";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "2466b2336ed02bcdca6b294417127b90ec92038d1d5c4fbeac971a922e0e0924"; };
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "pin_project_internal" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-internal."1.1.13" { profileName = "__noProfile"; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".pin-project-internal."1.1.13" = overridableMkRustCrate (profileName: rec {
+    name = "pin-project-internal";
+    version = "1.1.13";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "c96395f0a926bc13b1c17622aaddda1ecb55d49c8f1bf9777e4d877800a43f8b"; };
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
@@ -2993,6 +3769,23 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "8b870d8c151b6f2fb93e84a13146138f05d02ed11c7e7c54f8826aaaf7c9f184"; };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".piper."0.2.5" = overridableMkRustCrate (profileName: rec {
+    name = "piper";
+    version = "0.2.5";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "c835479a4443ded371d6c535cbfd8d31ad92c5d23ae9770a61bc155e4992a3c1"; };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "futures-io" ]
+      [ "std" ]
+    ];
+    dependencies = {
+      atomic_waker = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".atomic-waker."1.1.2" { inherit profileName; }).out;
+      fastrand = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".fastrand."2.3.0" { inherit profileName; }).out;
+      futures_io = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-io."0.3.31" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".pkg-config."0.3.32" = overridableMkRustCrate (profileName: rec {
     name = "pkg-config";
     version = "0.3.32";
@@ -3006,11 +3799,26 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "82151a2fc869e011c153adc57cf2789ccb8d9906ce52c0b39a6b5697749d7526"; };
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
-      crc32fast = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crc32fast."1.4.2" { inherit profileName; }).out;
-      fdeflate = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".fdeflate."0.3.7" { inherit profileName; }).out;
-      flate2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".flate2."1.1.2" { inherit profileName; }).out;
-      miniz_oxide = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".miniz_oxide."0.8.9" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "crc32fast" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crc32fast."1.4.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "fdeflate" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".fdeflate."0.3.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "flate2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".flate2."1.1.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "miniz_oxide" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".miniz_oxide."0.8.9" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".polling."3.11.0" = overridableMkRustCrate (profileName: rec {
+    name = "polling";
+    version = "3.11.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "5d0e4f59085d47d8241c88ead0f274e8a0cb551f3625263c05eb8dd897c34218"; };
+    dependencies = {
+      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "concurrent_queue" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".concurrent-queue."2.5.0" { inherit profileName; }).out;
+      ${ if hostPlatform.parsed.kernel.name == "hermit" then "hermit_abi" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hermit-abi."0.5.2" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "pin_project_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+      ${ if hostPlatform.isUnix || hostPlatform.parsed.kernel.name == "fuchsia" || hostPlatform.parsed.kernel.name == "vxworks" then "rustix" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustix."1.0.7" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.61.2" { inherit profileName; }).out;
     };
   });
   
@@ -3020,8 +3828,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f84267b20a16ea918e43c6a88433c2d54fa145c92a811b5b047ccbe153674483"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "fallback" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "fallback")
     ];
   });
   
@@ -3058,8 +3866,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6837b9e10d61f45f987d50808f83d1ee3d206c66acf650c3e4ae2e1f6ddedf55"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
@@ -3093,12 +3901,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "afbdc74edc00b6f6a218ca6a5364d6226a259d4b8ea1af4a0ea063f27e179f4d"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "procmacros" ]
-      [ "profiling-procmacros" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "procmacros")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "profiling-procmacros")
     ];
     dependencies = {
-      profiling_procmacros = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".profiling-procmacros."1.0.16" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "profiling_procmacros" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".profiling-procmacros."1.0.16" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -3108,8 +3916,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a65f2e60fbf1063868558d69c6beacf412dc755f9fc020f514b7955fc914fe30"; };
     dependencies = {
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
@@ -3153,7 +3961,7 @@ in
     features = builtins.concatLists [
       [ "default" ]
       [ "derive" ]
-      [ "prost-derive" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "prost-derive")
       [ "std" ]
     ];
     dependencies = {
@@ -3162,29 +3970,45 @@ in
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".prost."0.14.4" = overridableMkRustCrate (profileName: rec {
+    name = "prost";
+    version = "0.14.4";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "528ac67416ff8646872a3c02cad9cc4ee5dc9f9540c9b10771855c95cb2e5ae1"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "derive")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "std")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "prost_derive" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".prost-derive."0.14.4" { profileName = "__noProfile"; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".prost-build."0.12.6" = overridableMkRustCrate (profileName: rec {
     name = "prost-build";
     version = "0.12.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "22505a5c94da8e3b7c2996394d1c933236c4d743e81a410bcca4e6989fc066a4"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "format" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "format")
     ];
     dependencies = {
-      bytes = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
-      heck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".heck."0.5.0" { inherit profileName; }).out;
-      itertools = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".itertools."0.12.1" { inherit profileName; }).out;
-      log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
-      multimap = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".multimap."0.10.1" { inherit profileName; }).out;
-      once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      petgraph = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".petgraph."0.6.5" { inherit profileName; }).out;
-      prettyplease = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prettyplease."0.2.34" { inherit profileName; }).out;
-      prost = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost."0.12.6" { inherit profileName; }).out;
-      prost_types = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost-types."0.12.6" { inherit profileName; }).out;
-      regex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".regex."1.11.1" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
-      tempfile = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tempfile."3.20.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "heck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".heck."0.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "itertools" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".itertools."0.12.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "multimap" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".multimap."0.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "petgraph" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".petgraph."0.6.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "prettyplease" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prettyplease."0.2.34" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "prost" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost."0.12.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "prost_types" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost-types."0.12.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "regex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".regex."1.11.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "tempfile" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tempfile."3.20.0" { inherit profileName; }).out;
     };
   });
   
@@ -3202,13 +4026,27 @@ in
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".prost-derive."0.14.4" = overridableMkRustCrate (profileName: rec {
+    name = "prost-derive";
+    version = "0.14.4";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "b570b25f7617e43d59005d0990ccb79e950a423952cea19671b7a876da390adf"; };
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "anyhow" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".anyhow."1.0.98" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "itertools" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".itertools."0.12.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".prost-types."0.12.6" = overridableMkRustCrate (profileName: rec {
     name = "prost-types";
     version = "0.12.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9091c90b0a32608e984ff2fa4091273cbdd755d54935c51d520887f4a1dbd5b0"; };
     dependencies = {
-      prost = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost."0.12.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "prost" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost."0.12.6" { inherit profileName; }).out;
     };
   });
   
@@ -3218,13 +4056,13 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a0a01a0dc67cf4558d279f0c25b0962bd08fc6dec0137699eae304103e882fe6"; };
     features = builtins.concatLists [
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
-      libm = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libm."0.2.15" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      reborrow = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".reborrow."0.5.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "libm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libm."0.2.15" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "reborrow" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".reborrow."0.5.5" { inherit profileName; }).out;
     };
   });
   
@@ -3234,17 +4072,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "96b86df24f0a7ddd5e4b95c94fc9ed8a98f1ca94d3b01bdce2824097e7835907"; };
     features = builtins.concatLists [
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
-      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
-      libm = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libm."0.2.15" { inherit profileName; }).out;
-      num_complex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
-      reborrow = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".reborrow."0.5.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "libm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libm."0.2.15" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_complex" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-complex."0.4.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "reborrow" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".reborrow."0.5.5" { inherit profileName; }).out;
     };
     buildDependencies = {
-      version_check = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".version_check."0.9.5" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "version_check" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".version_check."0.9.5" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -3254,11 +4092,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7f6d64c71eb498fe9eae14ce4ec935c555749aef511cca85b5568910d6e48001"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
     };
   });
   
@@ -3325,12 +4163,12 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "9fbfd9d094a40bf3ae768db9361049ace4c0e04a4fd6b359518bd7b73a73dd97"; };
     features = builtins.concatLists [
       [ "alloc" ]
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
       [ "os_rng" ]
-      [ "small_rng" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "small_rng")
       [ "std" ]
-      [ "std_rng" ]
-      [ "thread_rng" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "std_rng")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "thread_rng")
     ];
     dependencies = {
       rand_chacha = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand_chacha."0.9.0" { inherit profileName; }).out;
@@ -3344,7 +4182,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e6c10a63a0fa32252be49d21e7709d4d4baf8d231c2dbce1eaa8141b9b127d88"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
       [ "std" ]
     ];
     dependencies = {
@@ -3402,13 +4240,13 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6a8615d50dcf34fa31f7ab52692afec947c4dd0ab803cc87cb3b0b4570ff7463"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      rand = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rand" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.9.1" { inherit profileName; }).out;
     };
   });
   
@@ -3428,40 +4266,40 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cd87ce80a7665b1cce111f8a16c1f3929f6547ce91ade6addf4ec86a8dda5ce9"; };
     features = builtins.concatLists [
-      [ "threading" ]
-      [ "wasm" ]
-      [ "wasm-bindgen" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "threading")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "wasm")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "wasm-bindgen")
     ];
     dependencies = {
       ${ if false then "arbitrary" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arbitrary."1.4.1" { inherit profileName; }).out;
-      arg_enum_proc_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".arg_enum_proc_macro."0.3.4" { profileName = "__noProfile"; }).out;
-      arrayvec = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arrayvec."0.7.6" { inherit profileName; }).out;
-      av1_grain = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".av1-grain."0.2.4" { inherit profileName; }).out;
-      bitstream_io = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitstream-io."2.6.0" { inherit profileName; }).out;
-      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "arg_enum_proc_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".arg_enum_proc_macro."0.3.4" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "arrayvec" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arrayvec."0.7.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "av1_grain" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".av1-grain."0.2.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bitstream_io" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitstream-io."2.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
       ${ if false then "interpolate_name" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".interpolate_name."0.2.4" { profileName = "__noProfile"; }).out;
-      itertools = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".itertools."0.12.1" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "itertools" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".itertools."0.12.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
       ${ if false then "libfuzzer_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libfuzzer-sys."0.4.9" { inherit profileName; }).out;
-      log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".maybe-rayon."0.1.1" { inherit profileName; }).out;
-      debug_unreachable = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".new_debug_unreachable."1.0.6" { inherit profileName; }).out;
-      noop_proc_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".noop_proc_macro."0.3.0" { profileName = "__noProfile"; }).out;
-      num_derive = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".num-derive."0.4.2" { profileName = "__noProfile"; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      paste = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
-      profiling = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".profiling."1.0.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".maybe-rayon."0.1.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "debug_unreachable" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".new_debug_unreachable."1.0.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "noop_proc_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".noop_proc_macro."0.3.0" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_derive" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".num-derive."0.4.2" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "paste" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".paste."1.0.15" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "profiling" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".profiling."1.0.16" { inherit profileName; }).out;
       ${ if false then "rand" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.8.5" { inherit profileName; }).out;
       ${ if false then "rand_chacha" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand_chacha."0.3.1" { inherit profileName; }).out;
-      simd_helpers = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".simd_helpers."0.1.0" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "simd_helpers" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".simd_helpers."0.1.0" { profileName = "__noProfile"; }).out;
       ${ if false then "system_deps" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".system-deps."6.2.2" { inherit profileName; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
-      v_frame = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".v_frame."0.3.9" { inherit profileName; }).out;
-      wasm_bindgen = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "v_frame" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".v_frame."0.3.9" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "wasm_bindgen" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
     };
     buildDependencies = {
-      built = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".built."0.7.7" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "built" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".built."0.7.7" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -3471,16 +4309,16 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d6a5f31fcf7500f9401fea858ea4ab5525c99f2322cfcee732c0e6c74208c0c6"; };
     features = builtins.concatLists [
-      [ "threading" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "threading")
     ];
     dependencies = {
-      avif_serialize = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".avif-serialize."0.8.3" { inherit profileName; }).out;
-      imgref = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".imgref."1.11.0" { inherit profileName; }).out;
-      loop9 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".loop9."0.1.5" { inherit profileName; }).out;
-      quick_error = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quick-error."2.0.1" { inherit profileName; }).out;
-      rav1e = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rav1e."0.7.1" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
-      rgb = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rgb."0.8.50" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "avif_serialize" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".avif-serialize."0.8.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "imgref" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".imgref."1.11.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "loop9" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".loop9."0.1.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quick_error" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quick-error."2.0.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rav1e" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rav1e."0.7.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rgb" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rgb."0.8.50" { inherit profileName; }).out;
     };
   });
   
@@ -3490,7 +4328,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6c297679cb867470fa8c9f67dbba74a78d78e3e98d7cf2b08d6d71540f797332"; };
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
     };
   });
   
@@ -3500,7 +4338,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c6df7ab838ed27997ba19a4664507e6f82b41fe6e20be42929332156e5e85146"; };
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
     };
   });
   
@@ -3510,8 +4348,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b418a60154510ca1a002a752ca9714984e21e4241e804d32555251faf8b78ffa"; };
     dependencies = {
-      either = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".either."1.15.0" { inherit profileName; }).out;
-      rayon_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon-core."1.12.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "either" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".either."1.15.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon-core."1.12.1" { inherit profileName; }).out;
     };
   });
   
@@ -3521,8 +4359,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1465873a3dfdaa8ae7cb14b4383657caab0b3e8a0aa9ae8e04b044854c8dfce2"; };
     dependencies = {
-      crossbeam_deque = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-deque."0.8.6" { inherit profileName; }).out;
-      crossbeam_utils = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.8.21" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "crossbeam_deque" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-deque."0.8.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "crossbeam_utils" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.8.21" { inherit profileName; }).out;
     };
   });
   
@@ -3532,7 +4370,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "03251193000f4bd3b042892be858ee50e8b3719f2b08e5833ac4353724632430"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
     ];
   });
   
@@ -3556,9 +4394,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "dd6f9d3d47bdd2ad6945c5015a226ec6155d0bcdfd8f7cd29f86b71f8de99d2b"; };
     dependencies = {
-      getrandom = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".getrandom."0.2.16" { inherit profileName; }).out;
-      libredox = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libredox."0.1.3" { inherit profileName; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "getrandom" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".getrandom."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "libredox" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libredox."0.1.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."2.0.12" { inherit profileName; }).out;
     };
   });
   
@@ -3568,6 +4406,14 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b544ef1b4eac5dc2db33ea63606ae9ffcfac26c1416a2806ae0bf5f56b201191"; };
     features = builtins.concatLists [
+      [ "default" ]
+      [ "perf" ]
+      [ "perf-backtrack" ]
+      [ "perf-cache" ]
+      [ "perf-dfa" ]
+      [ "perf-inline" ]
+      [ "perf-literal" ]
+      [ "perf-onepass" ]
       [ "std" ]
       [ "unicode" ]
       [ "unicode-age" ]
@@ -3586,21 +4432,6 @@ in
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".regex-automata."0.1.10" = overridableMkRustCrate (profileName: rec {
-    name = "regex-automata";
-    version = "0.1.10";
-    registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "6c230d73fb8d8c1b9c0b3135c5142a8acee3a0558fb8db5cf1cb65f8d7862132"; };
-    features = builtins.concatLists [
-      [ "default" ]
-      [ "regex-syntax" ]
-      [ "std" ]
-    ];
-    dependencies = {
-      regex_syntax = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".regex-syntax."0.6.29" { inherit profileName; }).out;
-    };
-  });
-  
   "registry+https://github.com/rust-lang/crates.io-index".regex-automata."0.4.9" = overridableMkRustCrate (profileName: rec {
     name = "regex-automata";
     version = "0.4.9";
@@ -3608,9 +4439,18 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "809e8dc61f6de73b46c85f4c96486310fe304c434cfa43669d7b40f711150908"; };
     features = builtins.concatLists [
       [ "alloc" ]
+      [ "dfa-build" ]
+      [ "dfa-onepass" ]
+      [ "dfa-search" ]
+      [ "hybrid" ]
       [ "meta" ]
+      [ "nfa-backtrack" ]
       [ "nfa-pikevm" ]
       [ "nfa-thompson" ]
+      [ "perf-inline" ]
+      [ "perf-literal" ]
+      [ "perf-literal-multisubstring" ]
+      [ "perf-literal-substring" ]
       [ "std" ]
       [ "syntax" ]
       [ "unicode" ]
@@ -3630,13 +4470,14 @@ in
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".regex-syntax."0.6.29" = overridableMkRustCrate (profileName: rec {
+  "registry+https://github.com/rust-lang/crates.io-index".regex-syntax."0.8.5" = overridableMkRustCrate (profileName: rec {
     name = "regex-syntax";
-    version = "0.6.29";
+    version = "0.8.5";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "f162c6dd7b008981e4d40210aca20b4bd0f9b60ca9271061b07f78537722f2e1"; };
+    src = fetchCratesIo { inherit name version; sha256 = "2b15c43186be67a4fd63bee50d0303afffcef381492ebe2c5d87f324e1b8815c"; };
     features = builtins.concatLists [
       [ "default" ]
+      [ "std" ]
       [ "unicode" ]
       [ "unicode-age" ]
       [ "unicode-bool" ]
@@ -3648,23 +4489,42 @@ in
     ];
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".regex-syntax."0.8.5" = overridableMkRustCrate (profileName: rec {
-    name = "regex-syntax";
-    version = "0.8.5";
+  "registry+https://github.com/rust-lang/crates.io-index".reqwest."0.12.28" = overridableMkRustCrate (profileName: rec {
+    name = "reqwest";
+    version = "0.12.28";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "2b15c43186be67a4fd63bee50d0303afffcef381492ebe2c5d87f324e1b8815c"; };
+    src = fetchCratesIo { inherit name version; sha256 = "eddd3ca559203180a307f12d114c268abf583f59b03cb906fd0b3ff8646c1147"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
-      [ "unicode" ]
-      [ "unicode-age" ]
-      [ "unicode-bool" ]
-      [ "unicode-case" ]
-      [ "unicode-gencat" ]
-      [ "unicode-perl" ]
-      [ "unicode-script" ]
-      [ "unicode-segment" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "blocking")
     ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "base64" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".base64."0.22.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "futures_channel" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-channel."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-util."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "http_body" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http-body."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "http_body_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http-body-util."0.1.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "hyper" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hyper."1.12.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "hyper_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hyper-util."0.1.21" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.parsed.cpu.name == "wasm32" then "js_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".js-sys."0.3.77" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "percent_encoding" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".percent-encoding."2.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "pin_project_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.parsed.cpu.name == "wasm32" then "serde_json" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_json."1.0.140" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "serde_urlencoded" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_urlencoded."0.7.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "sync_wrapper" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sync_wrapper."1.0.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "tokio" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tokio."1.50.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "tower" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower."0.5.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "tower_http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower-http."0.6.11" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && !(hostPlatform.parsed.cpu.name == "wasm32") then "tower_service" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower-service."0.3.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "url" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".url."2.5.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.parsed.cpu.name == "wasm32" then "wasm_bindgen" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.parsed.cpu.name == "wasm32" then "wasm_bindgen_futures" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-futures."0.4.50" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.parsed.cpu.name == "wasm32" then "web_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".web-sys."0.3.77" { inherit profileName; }).out;
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".rgb."0.8.50" = overridableMkRustCrate (profileName: rec {
@@ -3673,10 +4533,10 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "57397d16646700483b67d2dd6511d79318f9d057fdbd21a4066aeac8b41d310a"; };
     features = builtins.concatLists [
-      [ "bytemuck" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "bytemuck")
     ];
     dependencies = {
-      bytemuck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bytemuck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytemuck."1.23.1" { inherit profileName; }).out;
     };
   });
   
@@ -3686,19 +4546,19 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a4689e6c2294d81e88dc6261c768b63bc4fcdb852be6d1352498b114f61383b7"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "default" ]
-      [ "dev_urandom_fallback" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "dev_urandom_fallback")
     ];
     dependencies = {
-      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
-      getrandom = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".getrandom."0.2.16" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.significantByte == "littleEndian" && hostPlatform.parsed.vendor.name == "apple" && (hostPlatform.parsed.kernel.name == "ios" || hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "tvos" || hostPlatform.parsed.kernel.name == "visionos" || hostPlatform.parsed.kernel.name == "watchos") || (hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.significantByte == "littleEndian" || (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l") && hostPlatform.parsed.cpu.significantByte == "littleEndian") && (hostPlatform.parsed.kernel.name == "android" || hostPlatform.parsed.kernel.name == "linux") then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      untrusted = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".untrusted."0.9.0" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.significantByte == "littleEndian" && hostPlatform.parsed.kernel.name == "windows" then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.52.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "getrandom" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".getrandom."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && (hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.significantByte == "littleEndian" && hostPlatform.parsed.vendor.name == "apple" && (hostPlatform.parsed.kernel.name == "ios" || hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "tvos" || hostPlatform.parsed.kernel.name == "visionos" || hostPlatform.parsed.kernel.name == "watchos") || (hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.significantByte == "littleEndian" || (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l") && hostPlatform.parsed.cpu.significantByte == "littleEndian") && (hostPlatform.parsed.kernel.name == "android" || hostPlatform.parsed.kernel.name == "linux")) then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "untrusted" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".untrusted."0.9.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.significantByte == "littleEndian" && hostPlatform.parsed.kernel.name == "windows" then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.52.0" { inherit profileName; }).out;
     };
     buildDependencies = {
-      cc = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cc."1.2.27" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "cc" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cc."1.2.27" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -3708,8 +4568,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "08d43f7aa6b08d49f382cde6a7982047c3426db949b1424bc4b7ec9ae12c6ce2"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "std")
     ];
   });
   
@@ -3719,17 +4579,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "fdb5bc1ae2baa591800df16c9ca78619bf65c0488b41b96ccec5d11220d8c154"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "fs" ]
-      [ "libc-extra-traits" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "fs")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "libc-extra-traits")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "std")
     ];
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64) || !hostPlatform.isWindows && !(hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64)) || hostPlatform.isWindows then "libc_errno" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".errno."0.3.12" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64) || !hostPlatform.isWindows && !(hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64)) then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64) || (hostPlatform.parsed.kernel.name == "android" || hostPlatform.parsed.kernel.name == "linux") && !(hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64)) then "linux_raw_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".linux-raw-sys."0.4.15" { inherit profileName; }).out;
-      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.59.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64) || !hostPlatform.isWindows && !(hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64)) || hostPlatform.isWindows) then "libc_errno" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".errno."0.3.12" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64) || !hostPlatform.isWindows && !(hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64))) then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64) || (hostPlatform.parsed.kernel.name == "android" || hostPlatform.parsed.kernel.name == "linux") && !(hostPlatform.parsed.kernel.name == "linux" && (hostPlatform.parsed.cpu.significantByte == "littleEndian" || hostPlatform.parsed.cpu.name == "s390x") && (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l" || hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.cpu.bits == 64 || hostPlatform.parsed.cpu.name == "riscv64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.cpu.bits == 64))) then "linux_raw_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".linux-raw-sys."0.4.15" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.59.0" { inherit profileName; }).out;
     };
   });
   
@@ -3741,8 +4601,13 @@ in
     features = builtins.concatLists [
       [ "alloc" ]
       [ "default" ]
+      [ "event" ]
       [ "fs" ]
+      [ "net" ]
+      [ "pipe" ]
+      [ "process" ]
       [ "std" ]
+      [ "time" ]
     ];
     dependencies = {
       bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
@@ -3759,20 +4624,20 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7160e3e10bf4535308537f3c4e1641468cd0e485175d6163087c0393c7d46643"; };
     features = builtins.concatLists [
-      [ "log" ]
-      [ "logging" ]
-      [ "ring" ]
-      [ "std" ]
-      [ "tls12" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "log")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "logging")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "ring")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "std")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "tls12")
     ];
     dependencies = {
-      log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
-      once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      ring = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ring."0.17.14" { inherit profileName; }).out;
-      pki_types = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls-pki-types."1.12.0" { inherit profileName; }).out;
-      webpki = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls-webpki."0.103.3" { inherit profileName; }).out;
-      subtle = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".subtle."2.6.1" { inherit profileName; }).out;
-      zeroize = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zeroize."1.8.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "ring" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ring."0.17.14" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "pki_types" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls-pki-types."1.12.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "webpki" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls-webpki."0.103.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "subtle" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".subtle."2.6.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "zeroize" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zeroize."1.8.1" { inherit profileName; }).out;
     };
   });
   
@@ -3782,12 +4647,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "229a4a4c221013e7e1f1a043678c5cc39fe5171437c88fb47151a21e6f5b5c79"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      zeroize = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zeroize."1.8.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "zeroize" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zeroize."1.8.1" { inherit profileName; }).out;
     };
   });
   
@@ -3797,14 +4662,14 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e4a72fe2bcf7a6ac6fd7d0b9e5cb68aeb7d4c0a0271730218b3e92d43b4eb435"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "ring" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "ring")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      ring = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ring."0.17.14" { inherit profileName; }).out;
-      pki_types = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls-pki-types."1.12.0" { inherit profileName; }).out;
-      untrusted = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".untrusted."0.9.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "ring" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ring."0.17.14" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "pki_types" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls-pki-types."1.12.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "untrusted" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".untrusted."0.9.0" { inherit profileName; }).out;
     };
   });
   
@@ -3845,8 +4710,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "44560c11236a6130a46ce36c836a62936dc81ebf8c36a37947423571be0e55b6"; };
     dependencies = {
-      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
-      serde_json = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_json."1.0.140" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde_json" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_json."1.0.140" { inherit profileName; }).out;
     };
   });
   
@@ -3856,7 +4721,17 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "93fc1dc3aaa9bfed95e02e6eadabb4baf7e3078b0bd1b4d7b6b0b68378900502"; };
     dependencies = {
-      ${ if hostPlatform.isWindows then "winapi_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-util."0.1.9" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.isWindows then "winapi_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-util."0.1.9" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".scc."2.3.4" = overridableMkRustCrate (profileName: rec {
+    name = "scc";
+    version = "2.3.4";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "22b2d775fb28f245817589471dd49c5edf64237f4a19d10ce9a92ff4651a27f4"; };
+    dependencies = {
+      sdd = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sdd."3.0.8" { inherit profileName; }).out;
     };
   });
   
@@ -3867,6 +4742,13 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "94143f37725109f92c262ed2cf5e59bce7498c01bcc1502d7b9afe439a4e9f49"; };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".sdd."3.0.8" = overridableMkRustCrate (profileName: rec {
+    name = "sdd";
+    version = "3.0.8";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "584e070911c7017da6cb2eb0788d09f43d789029b5877d3e5ecc8acf86ceee21"; };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".seq-macro."0.3.6" = overridableMkRustCrate (profileName: rec {
     name = "seq-macro";
     version = "0.3.6";
@@ -3923,48 +4805,72 @@ in
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".serde_repr."0.1.21" = overridableMkRustCrate (profileName: rec {
+    name = "serde_repr";
+    version = "0.1.21";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "8d3b1629de253c70a0508c3899572da79ca359fdab27c7920ff00406df418906"; };
+    dependencies = {
+      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."3.0.9" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".serde_spanned."0.6.9" = overridableMkRustCrate (profileName: rec {
     name = "serde_spanned";
     version = "0.6.9";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "bf41e0cfaf7226dca15e8197172c295a782857fcb97fad1808a166870dee75a3"; };
     features = builtins.concatLists [
-      [ "serde" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "serde")
     ];
     dependencies = {
-      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".serde_urlencoded."0.7.1" = overridableMkRustCrate (profileName: rec {
+    name = "serde_urlencoded";
+    version = "0.7.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "d3491c14715ca2294c4d6a88f15e84739788c1d030eed8c110436aafdaa2f3fd"; };
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "form_urlencoded" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".form_urlencoded."1.2.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "itoa" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".itoa."1.0.15" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "ryu" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ryu."1.0.20" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".serial_test."2.0.0" = overridableMkRustCrate (profileName: rec {
+  "registry+https://github.com/rust-lang/crates.io-index".serial_test."3.2.0" = overridableMkRustCrate (profileName: rec {
     name = "serial_test";
-    version = "2.0.0";
+    version = "3.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "0e56dd856803e253c8f298af3f4d7eb0ae5e23a737252cd90bb4f3b435033b2d"; };
+    src = fetchCratesIo { inherit name version; sha256 = "1b258109f244e1d6891bf1053a55d63a5cd4f8f4c30cf9a1280989f80e7a1fa9"; };
     features = builtins.concatLists [
       [ "async" ]
       [ "default" ]
-      [ "futures" ]
-      [ "log" ]
       [ "logging" ]
     ];
     dependencies = {
-      dashmap = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".dashmap."5.5.3" { inherit profileName; }).out;
       futures = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures."0.3.31" { inherit profileName; }).out;
-      lazy_static = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazy_static."1.5.0" { inherit profileName; }).out;
       log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
       parking_lot = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".parking_lot."0.12.4" { inherit profileName; }).out;
-      serial_test_derive = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".serial_test_derive."2.0.0" { profileName = "__noProfile"; }).out;
+      scc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".scc."2.3.4" { inherit profileName; }).out;
+      serial_test_derive = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".serial_test_derive."3.2.0" { profileName = "__noProfile"; }).out;
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".serial_test_derive."2.0.0" = overridableMkRustCrate (profileName: rec {
+  "registry+https://github.com/rust-lang/crates.io-index".serial_test_derive."3.2.0" = overridableMkRustCrate (profileName: rec {
     name = "serial_test_derive";
-    version = "2.0.0";
+    version = "3.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "91d129178576168c589c9ec973feedf7d3126c01ac2bf08795109aa35b69fb8f"; };
+    src = fetchCratesIo { inherit name version; sha256 = "5d69265a08751de7844521fd15003ae0a888e035773ba05695c5c759a6f89eef"; };
     features = builtins.concatLists [
       [ "async" ]
+      [ "default" ]
     ];
     dependencies = {
       proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
@@ -3973,6 +4879,22 @@ in
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".sha1."0.10.7" = overridableMkRustCrate (profileName: rec {
+    name = "sha1";
+    version = "0.10.7";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "a978451301f4db1d02937a4ab3ccce137717b81826e79b7d49ffe3244a13c3b8"; };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "std" ]
+    ];
+    dependencies = {
+      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if hostPlatform.parsed.cpu.name == "aarch64" || hostPlatform.parsed.cpu.name == "i686" || hostPlatform.parsed.cpu.name == "x86_64" then "cpufeatures" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cpufeatures."0.2.17" { inherit profileName; }).out;
+      digest = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".digest."0.10.7" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".sharded-slab."0.1.7" = overridableMkRustCrate (profileName: rec {
     name = "sharded-slab";
     version = "0.1.7";
@@ -3994,15 +4916,42 @@ in
     ];
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".signal-hook."0.3.18" = overridableMkRustCrate (profileName: rec {
+    name = "signal-hook";
+    version = "0.3.18";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "d881a16cf4426aa584979d30bd82cb33429027e42122b169753d6ef1085ed6e2"; };
+    features = builtins.concatLists [
+      [ "channel" ]
+      [ "default" ]
+      [ "iterator" ]
+    ];
+    dependencies = {
+      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      signal_hook_registry = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".signal-hook-registry."1.4.8" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".signal-hook-registry."1.4.8" = overridableMkRustCrate (profileName: rec {
+    name = "signal-hook-registry";
+    version = "1.4.8";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "c4db69cba1110affc0e9f7bcd48bbf87b3f4fc7c61fc9155afd4c469eb3d6c1b"; };
+    dependencies = {
+      errno = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".errno."0.3.12" { inherit profileName; }).out;
+      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".simd-adler32."0.3.7" = overridableMkRustCrate (profileName: rec {
     name = "simd-adler32";
     version = "0.3.7";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d66dc143e6b11c1eddc06d5c423cfc97062865baf299914ab64caa38182078fe"; };
     features = builtins.concatLists [
-      [ "const-generics" ]
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "const-generics")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
   });
   
@@ -4012,7 +4961,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "95890f873bec569a0362c235787f3aca6e1e887302ba4840839bcc6459c42da6"; };
     dependencies = {
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
     };
   });
   
@@ -4034,7 +4983,22 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "67b1b7a3b5fe4f1376887184045fcf45c69e92af734b7aaddc05fb777b6fbd03"; };
     features = builtins.concatLists [
       [ "const_generics" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "const_new")
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".socket2."0.6.5" = overridableMkRustCrate (profileName: rec {
+    name = "socket2";
+    version = "0.6.5";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "c3d1e2c7f27f8d4cb10542a02c49005dbd6e93095799d6f3be745fae9f8fedd4"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "all")
     ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" && (hostPlatform.isUnix || hostPlatform.parsed.kernel.name == "wasi") then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.61.2" { inherit profileName; }).out;
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".socks."0.3.4" = overridableMkRustCrate (profileName: rec {
@@ -4043,9 +5007,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f0c3dbbd9ae980613c6dd8e28a9407b50509d3803b57624d5dfe8315218cd58b"; };
     dependencies = {
-      byteorder = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder."1.5.0" { inherit profileName; }).out;
-      ${ if hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      ${ if hostPlatform.isWindows then "winapi" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "byteorder" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder."1.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" && hostPlatform.isWindows then "winapi" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; }).out;
     };
   });
   
@@ -4055,18 +5019,18 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6980e8d7511241f8acf4aebddbb1ff938df5eebe98691418c4468d0b72a96a67"; };
     features = builtins.concatLists [
-      [ "barrier" ]
-      [ "default" ]
-      [ "lazy" ]
-      [ "lock_api" ]
-      [ "lock_api_crate" ]
-      [ "mutex" ]
-      [ "once" ]
-      [ "rwlock" ]
-      [ "spin_mutex" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "barrier")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "lazy")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "lock_api")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "lock_api_crate")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "mutex")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "once")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "rwlock")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "spin_mutex")
     ];
     dependencies = {
-      lock_api_crate = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lock_api."0.4.13" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "lock_api_crate" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".lock_api."0.4.13" { inherit profileName; }).out;
     };
   });
   
@@ -4077,11 +5041,18 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "a8f112729512f8e442d81f95a8a7ddf2b7c6b8a1a6f509a95864142b30cab2d3"; };
     features = builtins.concatLists [
       [ "alloc" ]
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".static_assertions."1.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "static_assertions";
+    version = "1.1.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "a2eb9349b6444b326872e140eb1cf5e7c522154d69e7a0ffb0fb81c06b37543f"; };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".strsim."0.11.1" = overridableMkRustCrate (profileName: rec {
     name = "strsim";
     version = "0.11.1";
@@ -4121,6 +5092,42 @@ in
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".syn."3.0.9" = overridableMkRustCrate (profileName: rec {
+    name = "syn";
+    version = "3.0.9";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "d78c8dee4c7bf0e14673097256fed6142ce9d3b85a408189d07482442145823b"; };
+    features = builtins.concatLists [
+      [ "clone-impls" ]
+      [ "default" ]
+      [ "derive" ]
+      [ "full" ]
+      [ "parsing" ]
+      [ "printing" ]
+      [ "proc-macro" ]
+      [ "visit-mut" ]
+    ];
+    dependencies = {
+      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      unicode_ident = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".unicode-ident."1.0.18" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".sync_wrapper."1.0.2" = overridableMkRustCrate (profileName: rec {
+    name = "sync_wrapper";
+    version = "1.0.2";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "0bf256ce5efdfa370213c1dabab5935a12e49f2c58d15e9eac2870d3b4f27263"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "futures")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "futures-core")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".synstructure."0.13.2" = overridableMkRustCrate (profileName: rec {
     name = "synstructure";
     version = "0.13.2";
@@ -4143,12 +5150,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ec7dddc5f0fee506baf8b9fdb989e242f17e4b11c61dfbb0635b705217199eea"; };
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
-      byteorder = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder."1.5.0" { inherit profileName; }).out;
-      enum_as_inner = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".enum-as-inner."0.6.1" { profileName = "__noProfile"; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "android" || hostPlatform.parsed.kernel.name == "linux" then "walkdir" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".walkdir."2.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "byteorder" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder."1.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "enum_as_inner" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".enum-as-inner."0.6.1" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && (hostPlatform.parsed.kernel.name == "android" || hostPlatform.parsed.kernel.name == "linux") then "walkdir" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".walkdir."2.5.0" { inherit profileName; }).out;
     };
   });
   
@@ -4158,12 +5165,12 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "01198a2debb237c62b6826ec7081082d951f46dbb64b0e8c7649a452230d1dfc"; };
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
-      byteorder = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder."1.5.0" { inherit profileName; }).out;
-      enum_as_inner = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".enum-as-inner."0.6.1" { profileName = "__noProfile"; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.kernel.name == "android" || hostPlatform.parsed.kernel.name == "linux" then "walkdir" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".walkdir."2.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "byteorder" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder."1.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "enum_as_inner" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".enum-as-inner."0.6.1" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && (hostPlatform.parsed.kernel.name == "android" || hostPlatform.parsed.kernel.name == "linux") then "walkdir" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".walkdir."2.5.0" { inherit profileName; }).out;
     };
   });
   
@@ -4199,11 +5206,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a3e535eb8dded36d55ec13eddacd30dec501792ff23a0b1682c38601b8cf2349"; };
     dependencies = {
-      cfg_expr = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-expr."0.15.8" { inherit profileName; }).out;
-      heck = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".heck."0.5.0" { inherit profileName; }).out;
-      pkg_config = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pkg-config."0.3.32" { inherit profileName; }).out;
-      toml = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".toml."0.8.23" { inherit profileName; }).out;
-      version_compare = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".version-compare."0.2.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "cfg_expr" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-expr."0.15.8" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "heck" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".heck."0.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "pkg_config" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pkg-config."0.3.32" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "toml" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".toml."0.8.23" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "version_compare" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".version-compare."0.2.0" { inherit profileName; }).out;
     };
   });
   
@@ -4213,7 +5220,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "61c41af27dd6d1e27b1b16b489db798443478cef1f06a660c96db617ba5de3b1"; };
     features = builtins.concatLists [
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
     ];
   });
   
@@ -4241,7 +5248,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b6aaf5339b578ea85b50e080feb250a3e8ae8cfcdff9a461c9ec2904bc923f52"; };
     dependencies = {
-      thiserror_impl = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror-impl."1.0.69" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" then "thiserror_impl" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror-impl."1.0.69" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -4251,11 +5258,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "567b8a2dae586314f7be2a752ec7474332959c6460e02bde30d702a66d488708"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
     dependencies = {
-      thiserror_impl = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror-impl."2.0.12" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "thiserror_impl" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror-impl."2.0.12" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -4265,9 +5272,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4fee6c4efc90059e10f81e6d42c60a18f76588c3d74cb83a0b242a2b6c7504c1"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/http" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
@@ -4277,9 +5284,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7f7cf42b4507d8ea322120659672cf1b9dbb93f8f2d4ecfd6e51350ff5b17a1d"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
@@ -4299,9 +5306,25 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ba1310fcea54c6a9a4fd1aad794ecc02c31682f6bfbecdf460bf19533eed1e3e"; };
     dependencies = {
-      flate2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".flate2."1.1.2" { inherit profileName; }).out;
-      jpeg = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".jpeg-decoder."0.3.1" { inherit profileName; }).out;
-      weezl = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".weezl."0.1.10" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "flate2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".flate2."1.1.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "jpeg" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".jpeg-decoder."0.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "weezl" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".weezl."0.1.10" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tiny_http."0.12.0" = overridableMkRustCrate (profileName: rec {
+    name = "tiny_http";
+    version = "0.12.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "389915df6413a2e74fb181895f933386023c71110878cd0825588928e64cdc82"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "default")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/http" then "ascii" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ascii."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "chunked_transfer" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".chunked_transfer."1.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "httpdate" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".httpdate."1.0.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
     };
   });
   
@@ -4320,19 +5343,56 @@ in
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".tokio."1.50.0" = overridableMkRustCrate (profileName: rec {
+    name = "tokio";
+    version = "1.50.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "27ad5e34374e03cfffefc301becb44e9dc3c17584f414349ebe29ed26661822d"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "libc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "mio")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "net")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "rt")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "socket2")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "sync")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "time")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "windows-sys")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "mio" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".mio."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "pin_project_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "socket2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".socket2."0.6.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.61.2" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tokio-stream."0.1.19" = overridableMkRustCrate (profileName: rec {
+    name = "tokio-stream";
+    version = "0.1.19";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "a3d06f0b082ba57c26b79407372e57cf2a1e28124f78e9479fe80322cf53420b"; };
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "pin_project_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tokio" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tokio."1.50.0" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".toml."0.8.23" = overridableMkRustCrate (profileName: rec {
     name = "toml";
     version = "0.8.23";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "dc1beb996b9d83529a9e75c17a1686767d148d70663143c7854d8b4a09ced362"; };
     features = builtins.concatLists [
-      [ "parse" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "parse")
     ];
     dependencies = {
-      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
-      serde_spanned = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_spanned."0.6.9" { inherit profileName; }).out;
-      toml_datetime = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".toml_datetime."0.6.11" { inherit profileName; }).out;
-      toml_edit = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".toml_edit."0.22.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde_spanned" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_spanned."0.6.9" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "toml_datetime" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".toml_datetime."0.6.11" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "toml_edit" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".toml_edit."0.22.27" { inherit profileName; }).out;
     };
   });
   
@@ -4342,10 +5402,10 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "22cddaf88f4fbc13c51aebbf5f8eceb5c7c5a9da2ac40a13519eb5b0a0e8f11c"; };
     features = builtins.concatLists [
-      [ "serde" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "serde")
     ];
     dependencies = {
-      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
     };
   });
   
@@ -4356,22 +5416,123 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "41fe8c660ae4257887cf66394862d21dbca4a6ddd26f04a3560410406a2f819a"; };
     features = builtins.concatLists [
       [ "parse" ]
-      [ "serde" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "serde")
     ];
     dependencies = {
       indexmap = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".indexmap."2.9.0" { inherit profileName; }).out;
-      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
-      serde_spanned = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_spanned."0.6.9" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde_spanned" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_spanned."0.6.9" { inherit profileName; }).out;
       toml_datetime = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".toml_datetime."0.6.11" { inherit profileName; }).out;
       winnow = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".winnow."0.7.11" { inherit profileName; }).out;
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.41" = overridableMkRustCrate (profileName: rec {
+  "registry+https://github.com/rust-lang/crates.io-index".tonic."0.14.6" = overridableMkRustCrate (profileName: rec {
+    name = "tonic";
+    version = "0.14.6";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "ac2a5518c70fa84342385732db33fb3f44bc4cc748936eb5833d2df34d6445ef"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "codegen")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "async_trait" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".async-trait."0.1.92" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "base64" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".base64."0.22.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http_body" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http-body."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http_body_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http-body-util."0.1.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "percent_encoding" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".percent-encoding."2.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "pin_project" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project."1.1.13" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "sync_wrapper" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sync_wrapper."1.0.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tokio_stream" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tokio-stream."0.1.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tower_layer" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower-layer."0.3.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tower_service" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower-service."0.3.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tracing" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.44" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tonic-prost."0.14.6" = overridableMkRustCrate (profileName: rec {
+    name = "tonic-prost";
+    version = "0.14.6";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "50849f68853be452acf590cde0b146665b8d507b3b8af17261df47e02c209ea0"; };
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "prost" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".prost."0.14.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tonic" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tonic."0.14.6" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tower."0.5.3" = overridableMkRustCrate (profileName: rec {
+    name = "tower";
+    version = "0.5.3";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "ebe5ef63511595f1344e2d5cfa636d973292adc0eec1f0ad45fae9f0851ab1d4"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "futures-core")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "futures-util")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "pin-project-lite")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "retry")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "sync_wrapper")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "timeout")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "tokio")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "util")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-util."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "pin_project_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "sync_wrapper" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sync_wrapper."1.0.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tokio" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tokio."1.50.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tower_layer" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower-layer."0.3.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tower_service" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower-service."0.3.3" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tower-http."0.6.11" = overridableMkRustCrate (profileName: rec {
+    name = "tower-http";
+    version = "0.6.11";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "4cfcf7e2740e6fc6d4d688b4ef00650406bb94adf4731e43c096c3a19fe40840"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "follow-redirect")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "futures-util")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "tower")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."2.9.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "futures_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-util."0.3.31" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "http_body" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http-body."1.1.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "pin_project_lite" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tower" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower."0.5.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tower_layer" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower-layer."0.3.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tower_service" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tower-service."0.3.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "url" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".url."2.5.4" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tower-layer."0.3.3" = overridableMkRustCrate (profileName: rec {
+    name = "tower-layer";
+    version = "0.3.3";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "121c2a6cda46980bb0fcd1647ffaf6cd3fc79a013de288782836f6df9c48780e"; };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tower-service."0.3.3" = overridableMkRustCrate (profileName: rec {
+    name = "tower-service";
+    version = "0.3.3";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "8df9b6e13f2d32c91b9bd719c00d1958837bc7dec474d94952798cc8e69eeec3"; };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.44" = overridableMkRustCrate (profileName: rec {
     name = "tracing";
-    version = "0.1.41";
+    version = "0.1.44";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "784e0ac535deb450455cbfa28a6f0df145ea1bb7ae51b821cf5e7927fdcfbdd0"; };
+    src = fetchCratesIo { inherit name version; sha256 = "63e71662fa4b2a2c3a26f570f037eb95bb1f85397f3cd8076caed2f026a6d100"; };
     features = builtins.concatLists [
       [ "attributes" ]
       [ "default" ]
@@ -4380,16 +5541,16 @@ in
     ];
     dependencies = {
       pin_project_lite = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.16" { inherit profileName; }).out;
-      tracing_attributes = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-attributes."0.1.29" { profileName = "__noProfile"; }).out;
-      tracing_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.34" { inherit profileName; }).out;
+      tracing_attributes = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-attributes."0.1.31" { profileName = "__noProfile"; }).out;
+      tracing_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.36" { inherit profileName; }).out;
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".tracing-attributes."0.1.29" = overridableMkRustCrate (profileName: rec {
+  "registry+https://github.com/rust-lang/crates.io-index".tracing-attributes."0.1.31" = overridableMkRustCrate (profileName: rec {
     name = "tracing-attributes";
-    version = "0.1.29";
+    version = "0.1.31";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "1b1ffbcf9c6f6b99d386e7444eb608ba646ae452a36b39737deb9663b610f662"; };
+    src = fetchCratesIo { inherit name version; sha256 = "7490cfa5ec963746568740651ac6781f701c9c5ea257c58e057f3ba8cf69e8da"; };
     dependencies = {
       proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
       quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
@@ -4397,11 +5558,11 @@ in
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.34" = overridableMkRustCrate (profileName: rec {
+  "registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.36" = overridableMkRustCrate (profileName: rec {
     name = "tracing-core";
-    version = "0.1.34";
+    version = "0.1.36";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "b9d12581f227e93f094d3af2ae690a574abb8a2b9b7a96e7cfe9647b2b617678"; };
+    src = fetchCratesIo { inherit name version; sha256 = "db97caf9d906fbde555dd62fa95ddba9eecfd14cb388e4f491a66d74cd5fb79a"; };
     features = builtins.concatLists [
       [ "default" ]
       [ "once_cell" ]
@@ -4413,6 +5574,18 @@ in
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".tracing-journald."0.3.2" = overridableMkRustCrate (profileName: rec {
+    name = "tracing-journald";
+    version = "0.3.2";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "2d3a81ed245bfb62592b1e2bc153e77656d94ee6a0497683a65a12ccaf2438d0"; };
+    dependencies = {
+      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      tracing_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.36" { inherit profileName; }).out;
+      tracing_subscriber = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-subscriber."0.3.23" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".tracing-log."0.2.0" = overridableMkRustCrate (profileName: rec {
     name = "tracing-log";
     version = "0.2.0";
@@ -4425,44 +5598,137 @@ in
     dependencies = {
       log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
       once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      tracing_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.34" { inherit profileName; }).out;
+      tracing_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.36" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tracing-opentelemetry."0.32.1" = overridableMkRustCrate (profileName: rec {
+    name = "tracing-opentelemetry";
+    version = "0.32.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "1ac28f2d093c6c477eaa76b23525478f38de514fa9aeb1285738d4b97a9552fc"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "metrics")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "smallvec")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "tracing-log")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.parsed.cpu.name == "wasm32" && !(hostPlatform.parsed.kernel.name == "wasi") then "js_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".js-sys."0.3.77" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "opentelemetry" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".opentelemetry."0.31.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "smallvec" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".smallvec."1.15.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tracing" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.44" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tracing_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.36" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tracing_log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-log."0.2.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "tracing_subscriber" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-subscriber."0.3.23" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" && hostPlatform.parsed.cpu.name == "wasm32" && !(hostPlatform.parsed.kernel.name == "wasi") then "web_time" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".web-time."1.1.0" { inherit profileName; }).out;
     };
   });
   
-  "registry+https://github.com/rust-lang/crates.io-index".tracing-subscriber."0.3.19" = overridableMkRustCrate (profileName: rec {
+  "registry+https://github.com/rust-lang/crates.io-index".tracing-serde."0.2.0" = overridableMkRustCrate (profileName: rec {
+    name = "tracing-serde";
+    version = "0.2.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "704b1aeb7be0d0a84fc9828cae51dab5970fee5088f83d1dd7ee6f6246fc6ff1"; };
+    dependencies = {
+      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      tracing_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.36" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tracing-subscriber."0.3.23" = overridableMkRustCrate (profileName: rec {
     name = "tracing-subscriber";
-    version = "0.3.19";
+    version = "0.3.23";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
-    src = fetchCratesIo { inherit name version; sha256 = "e8189decb5ac0fa7bc8b96b7cb9b2701d60d48805aca84a238004d665fcc4008"; };
+    src = fetchCratesIo { inherit name version; sha256 = "cb7f578e5945fb242538965c2d0b04418d38ec25c79d160cd279bf0731c8d319"; };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "ansi" ]
       [ "default" ]
       [ "env-filter" ]
       [ "fmt" ]
+      [ "json" ]
       [ "matchers" ]
       [ "nu-ansi-term" ]
       [ "once_cell" ]
-      [ "regex" ]
       [ "registry" ]
+      [ "serde" ]
+      [ "serde_json" ]
       [ "sharded-slab" ]
       [ "smallvec" ]
       [ "std" ]
       [ "thread_local" ]
       [ "tracing" ]
       [ "tracing-log" ]
+      [ "tracing-serde" ]
     ];
     dependencies = {
-      matchers = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".matchers."0.1.0" { inherit profileName; }).out;
-      nu_ansi_term = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nu-ansi-term."0.46.0" { inherit profileName; }).out;
+      matchers = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".matchers."0.2.0" { inherit profileName; }).out;
+      nu_ansi_term = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nu-ansi-term."0.50.3" { inherit profileName; }).out;
       once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      regex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".regex."1.11.1" { inherit profileName; }).out;
+      regex_automata = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".regex-automata."0.4.9" { inherit profileName; }).out;
+      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      serde_json = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_json."1.0.140" { inherit profileName; }).out;
       sharded_slab = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sharded-slab."0.1.7" { inherit profileName; }).out;
       smallvec = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".smallvec."1.15.1" { inherit profileName; }).out;
       thread_local = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thread_local."1.1.9" { inherit profileName; }).out;
-      tracing = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.41" { inherit profileName; }).out;
-      tracing_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.34" { inherit profileName; }).out;
+      tracing = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.44" { inherit profileName; }).out;
+      tracing_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-core."0.1.36" { inherit profileName; }).out;
       tracing_log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-log."0.2.0" { inherit profileName; }).out;
+      tracing_serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing-serde."0.2.0" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".try-lock."0.2.5" = overridableMkRustCrate (profileName: rec {
+    name = "try-lock";
+    version = "0.2.5";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "e421abadd41a4225275504ea4d6566923418b7f05506fbc9c0fe86ba7396114b"; };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".tungstenite."0.24.0" = overridableMkRustCrate (profileName: rec {
+    name = "tungstenite";
+    version = "0.24.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "18e5b8366ee7a95b16d32197d0b2604b43a0be89dc5fac9f8e96ccafbaedda8a"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "data-encoding")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "handshake")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "http")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "httparse")
+      (lib.optional (rootFeatures' ? "bongo-modulator/http") "sha1")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/http" then "byteorder" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".byteorder."1.5.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "bytes" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "data_encoding" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".data-encoding."2.11.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "http" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."1.3.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "httparse" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".httparse."1.10.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "rand" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.8.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "sha1" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sha1."0.10.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/http" then "utf8" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".utf-8."0.7.6" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".typenum."1.20.1" = overridableMkRustCrate (profileName: rec {
+    name = "typenum";
+    version = "1.20.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "b6f5e870be6c3b371b77fe0ee0bafb859fa4964b4404c27de1d380043c4dda20"; };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".uds_windows."1.2.1" = overridableMkRustCrate (profileName: rec {
+    name = "uds_windows";
+    version = "1.2.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "f2f6fb2847f6742cd76af783a2a2c49e9375d0a111c7bef6f71cd9e738c72d6e"; };
+    dependencies = {
+      memoffset = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".memoffset."0.9.1" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "tempfile" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tempfile."3.20.0" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.61.2" { inherit profileName; }).out;
     };
   });
   
@@ -4472,19 +5738,19 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "90b70b37e9074642bc5f60bb23247fd072a84314ca9e71cdf8527593406a0dd3"; };
     dependencies = {
-      gemm = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm."0.18.2" { inherit profileName; }).out;
-      half = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
-      libloading = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libloading."0.8.8" { inherit profileName; }).out;
-      memmap2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".memmap2."0.9.5" { inherit profileName; }).out;
-      num = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num."0.4.3" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      num_cpus = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num_cpus."1.17.0" { inherit profileName; }).out;
-      rayon = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
-      safetensors = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".safetensors."0.4.5" { inherit profileName; }).out;
-      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
-      tracing = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.41" { inherit profileName; }).out;
-      yoke = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".yoke."0.7.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "gemm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".gemm."0.18.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "half" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".half."2.6.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "libloading" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libloading."0.8.8" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "memmap2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".memmap2."0.9.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num."0.4.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_cpus" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num_cpus."1.17.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "rayon" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rayon."1.10.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "safetensors" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".safetensors."0.4.5" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "tracing" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.44" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "yoke" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".yoke."0.7.5" { inherit profileName; }).out;
     };
   });
   
@@ -4508,8 +5774,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4a1a07cc7db3810833284e8d372ccdc6da29741639ecc70c9ec107df0fa6154c"; };
     features = builtins.concatLists [
-      [ "cjk" ]
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "cjk")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "default")
     ];
   });
   
@@ -4526,24 +5792,24 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "02d1a66277ed75f640d608235660df48c8e3c19f3b4edb6a263315626cc3c01d"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "gzip" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "gzip")
       [ "json" ]
-      [ "socks-proxy" ]
-      [ "tls" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "socks-proxy")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "tls")
     ];
     dependencies = {
       base64 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".base64."0.22.1" { inherit profileName; }).out;
-      flate2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".flate2."1.1.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "flate2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".flate2."1.1.2" { inherit profileName; }).out;
       log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
       once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      rustls = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls."0.23.28" { inherit profileName; }).out;
-      rustls_pki_types = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls-pki-types."1.12.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "rustls" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls."0.23.28" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "rustls_pki_types" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls-pki-types."1.12.0" { inherit profileName; }).out;
       serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
       serde_json = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_json."1.0.140" { inherit profileName; }).out;
-      socks = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".socks."0.3.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "socks" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".socks."0.3.4" { inherit profileName; }).out;
       url = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".url."2.5.4" { inherit profileName; }).out;
-      webpki_roots = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".webpki-roots."0.26.11" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "webpki_roots" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".webpki-roots."0.26.11" { inherit profileName; }).out;
     };
   });
   
@@ -4563,6 +5829,13 @@ in
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".utf-8."0.7.6" = overridableMkRustCrate (profileName: rec {
+    name = "utf-8";
+    version = "0.7.6";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "09cc8ee72d2a9becf2f2febe0205bbed8fc6615b7cb429ad062dc7b7ddd036a9"; };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".utf8_iter."1.0.4" = overridableMkRustCrate (profileName: rec {
     name = "utf8_iter";
     version = "1.0.4";
@@ -4586,14 +5859,14 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d8fbfea44a46799d62c55323f3c55d06df722fbe577851d848d328a1041c3403"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "v4l2" ]
-      [ "v4l2-sys" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "v4l2")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "v4l2-sys")
     ];
     dependencies = {
-      bitflags = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
-      libc = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
-      v4l2_sys = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".v4l2-sys-mit."0.3.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bitflags" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.3.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "v4l2_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".v4l2-sys-mit."0.3.0" { inherit profileName; }).out;
     };
   });
   
@@ -4603,7 +5876,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6779878362b9bacadc7893eac76abe69612e8837ef746573c4a5239daf11990b"; };
     buildDependencies = {
-      bindgen = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".bindgen."0.65.1" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "bindgen" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".bindgen."0.65.1" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -4613,9 +5886,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "666b7727c8875d6ab5db9533418d7c764233ac9c0cff1d469aec8fa127597be2"; };
     dependencies = {
-      aligned_vec = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".aligned-vec."0.6.4" { inherit profileName; }).out;
-      num_traits = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
-      ${ if hostPlatform.config == "wasm32-unknown-unknown" then "wasm_bindgen" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "aligned_vec" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".aligned-vec."0.6.4" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_traits" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num-traits."0.2.19" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.config == "wasm32-unknown-unknown" then "wasm_bindgen" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
     };
   });
   
@@ -4660,8 +5933,18 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "29790946404f91d9c5d06f9874efddea1dc06c5efe94541a7d6863108e3a5e4b"; };
     dependencies = {
-      same_file = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".same-file."1.0.6" { inherit profileName; }).out;
-      ${ if hostPlatform.isWindows then "winapi_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-util."0.1.9" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "same_file" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".same-file."1.0.6" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.isWindows then "winapi_util" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-util."0.1.9" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".want."0.3.2" = overridableMkRustCrate (profileName: rec {
+    name = "want";
+    version = "0.3.2";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "ec4cdd0dd910afe868b7ef477227d8d538b46b3075031afee8a9f2acb0a2ed0b"; };
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "try_lock" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".try-lock."0.2.5" { inherit profileName; }).out;
     };
   });
   
@@ -4670,6 +5953,10 @@ in
     version = "0.11.1+wasi-snapshot-preview1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ccf3ec651a847eb01de73ccad15eb7d99f80485de043efb2f370cd654f4ea44b"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "std")
+    ];
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".wasi."0.14.2+wasi-0.2.4" = overridableMkRustCrate (profileName: rec {
@@ -4688,16 +5975,16 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1edc8929d7499fc4e8f0be2262a241556cfc54a0bea223790e71446f2aab1ef5"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "msrv" ]
-      [ "rustversion" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "msrv")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "rustversion")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel") "std")
     ];
     dependencies = {
-      cfg_if = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
-      once_cell = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      rustversion = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".rustversion."1.0.21" { profileName = "__noProfile"; }).out;
-      wasm_bindgen_macro = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-macro."0.2.100" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "rustversion" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".rustversion."1.0.21" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "wasm_bindgen_macro" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-macro."0.2.100" { profileName = "__noProfile"; }).out;
     };
   });
   
@@ -4707,12 +5994,30 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2f0a0651a5c2bc21487bde11ee802ccaf4c51935d0d3d42a6101f98161700bc6"; };
     dependencies = {
-      bumpalo = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bumpalo."3.18.1" { inherit profileName; }).out;
-      log = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
-      wasm_bindgen_shared = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-shared."0.2.100" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "bumpalo" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".bumpalo."3.18.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "log" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.27" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "wasm_bindgen_shared" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-shared."0.2.100" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-futures."0.4.50" = overridableMkRustCrate (profileName: rec {
+    name = "wasm-bindgen-futures";
+    version = "0.4.50";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "555d470ec0bc3bb57890405e5d4322cc9ea83cebb085523ced7be4144dac1e61"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "std")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "cfg_if" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.1" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "js_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".js-sys."0.3.77" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "wasm_bindgen" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
+      ${ if false then "web_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".web-sys."0.3.77" { inherit profileName; }).out;
     };
   });
   
@@ -4722,8 +6027,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7fe63fc6d09ed3792bd0897b314f53de8e16568c2b3f7982f468c0bf9bd0b407"; };
     dependencies = {
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      wasm_bindgen_macro_support = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-macro-support."0.2.100" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "wasm_bindgen_macro_support" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-macro-support."0.2.100" { inherit profileName; }).out;
     };
   });
   
@@ -4733,11 +6038,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8ae87ea40c9f689fc23f209965b6fb8a99ad69aeeb0231408be24920604395de"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
-      wasm_bindgen_backend = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-backend."0.2.100" { inherit profileName; }).out;
-      wasm_bindgen_shared = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-shared."0.2.100" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "wasm_bindgen_backend" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-backend."0.2.100" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "wasm_bindgen_shared" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-shared."0.2.100" { inherit profileName; }).out;
     };
   });
   
@@ -4747,7 +6052,43 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1a05d73b933a847d6cccdda8f838a22ff101ad9bf93e33684f39c1f5f0eece3d"; };
     dependencies = {
-      unicode_ident = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".unicode-ident."1.0.18" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" || rootFeatures' ? "bongo-modulator/otel" then "unicode_ident" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".unicode-ident."1.0.18" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".web-sys."0.3.77" = overridableMkRustCrate (profileName: rec {
+    name = "web-sys";
+    version = "0.3.77";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "33b6dd2ef9186f1f2072e409e99cd22a975331a6b3591b12c764e0e55c60d5d2"; };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "AbortController")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "AbortSignal")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Blob")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "BlobPropertyBag")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Event")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "EventTarget")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "File")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "FormData")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Headers")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "MessageEvent")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "ReadableStream")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Request")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "RequestCache")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "RequestCredentials")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "RequestInit")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "RequestMode")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Response")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "ServiceWorkerGlobalScope")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Window")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Worker")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "WorkerGlobalScope")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "std")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "js_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".js-sys."0.3.77" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/otel" then "wasm_bindgen" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.100" { inherit profileName; }).out;
     };
   });
   
@@ -4768,7 +6109,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "521bc38abb08001b01866da9f51eb7c5d647a19260e00054a8c7fd5f9e57f7a9"; };
     dependencies = {
-      parent = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".webpki-roots."1.0.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "parent" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".webpki-roots."1.0.0" { inherit profileName; }).out;
     };
   });
   
@@ -4778,7 +6119,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2853738d1cc4f2da3a225c18ec6c3721abb31961096e9dbf5ab35fa88b19cfdb"; };
     dependencies = {
-      pki_types = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls-pki-types."1.12.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "pki_types" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustls-pki-types."1.12.0" { inherit profileName; }).out;
     };
   });
   
@@ -4788,9 +6129,9 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a751b3277700db47d3e574514de2eced5e54dc8a5436a3bf7a0b248b2cee16f3"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "default" ]
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
   });
   
@@ -4800,10 +6141,10 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "87ba24419a2078cd2b0f2ede2691b6c66d8e47836da3b6db8265ebad47afbfc7"; };
     dependencies = {
-      either = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".either."1.15.0" { inherit profileName; }).out;
-      ${ if hostPlatform.isWindows || hostPlatform.isUnix || hostPlatform.parsed.kernel.name == "redox" then "home" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".home."0.5.11" { inherit profileName; }).out;
-      ${ if hostPlatform.isWindows then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
-      rustix = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustix."0.38.44" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "either" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".either."1.15.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.isWindows || hostPlatform.isUnix || hostPlatform.parsed.kernel.name == "redox") then "home" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".home."0.5.11" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && hostPlatform.isWindows then "once_cell" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".once_cell."1.21.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" then "rustix" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rustix."0.38.44" { inherit profileName; }).out;
     };
   });
   
@@ -4814,19 +6155,14 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "5c839a674fcd7a98952e593242ea400abe93992746761e38641405d28b00f419"; };
     features = builtins.concatLists [
       [ "cfg" ]
-      [ "consoleapi" ]
-      [ "errhandlingapi" ]
       [ "evntrace" ]
-      [ "fileapi" ]
-      [ "handleapi" ]
       [ "in6addr" ]
       [ "inaddr" ]
       [ "minwinbase" ]
       [ "ntsecapi" ]
-      [ "processenv" ]
       [ "windef" ]
       [ "winioctl" ]
-      [ "winsock2" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "winsock2")
     ];
     dependencies = {
       ${ if hostPlatform.config == "i686-pc-windows-gnu" then "winapi_i686_pc_windows_gnu" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-i686-pc-windows-gnu."0.4.0" { inherit profileName; }).out;
@@ -4847,7 +6183,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cf221c93e13a30d793f7645a0e7762c55d169dbb0a49671918a2319d289b10bb"; };
     dependencies = {
-      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.59.0" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.59.0" { inherit profileName; }).out;
     };
   });
   
@@ -4864,24 +6200,24 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "04662ed0e3e5630dfa9b26e4cb823b817f1a9addda855d973a9458c236556244"; };
     features = builtins.concatLists [
-      [ "Win32" ]
-      [ "Win32_Foundation" ]
-      [ "Win32_Media" ]
-      [ "Win32_Media_DirectShow" ]
-      [ "Win32_Media_KernelStreaming" ]
-      [ "Win32_Media_MediaFoundation" ]
-      [ "Win32_System" ]
-      [ "Win32_System_Com" ]
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32_Foundation")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32_Media")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32_Media_DirectShow")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32_Media_KernelStreaming")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32_Media_MediaFoundation")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32_System")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32_System_Com")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "default")
     ];
     dependencies = {
-      ${ if hostPlatform.config == "aarch64-pc-windows-gnullvm" then "windows_aarch64_gnullvm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_aarch64_gnullvm."0.42.2" { inherit profileName; }).out;
-      ${ if hostPlatform.config == "aarch64-pc-windows-msvc" || hostPlatform.config == "aarch64-uwp-windows-msvc" then "windows_aarch64_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_aarch64_msvc."0.42.2" { inherit profileName; }).out;
-      ${ if hostPlatform.config == "i686-pc-windows-gnu" || hostPlatform.config == "i686-uwp-windows-gnu" then "windows_i686_gnu" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_i686_gnu."0.42.2" { inherit profileName; }).out;
-      ${ if hostPlatform.config == "i686-pc-windows-msvc" || hostPlatform.config == "i686-uwp-windows-msvc" then "windows_i686_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_i686_msvc."0.42.2" { inherit profileName; }).out;
-      ${ if hostPlatform.config == "x86_64-pc-windows-gnu" || hostPlatform.config == "x86_64-uwp-windows-gnu" then "windows_x86_64_gnu" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_gnu."0.42.2" { inherit profileName; }).out;
-      ${ if hostPlatform.config == "x86_64-pc-windows-gnullvm" then "windows_x86_64_gnullvm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_gnullvm."0.42.2" { inherit profileName; }).out;
-      ${ if hostPlatform.config == "x86_64-pc-windows-msvc" || hostPlatform.config == "x86_64-uwp-windows-msvc" then "windows_x86_64_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_msvc."0.42.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && hostPlatform.config == "aarch64-pc-windows-gnullvm" then "windows_aarch64_gnullvm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_aarch64_gnullvm."0.42.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.config == "aarch64-pc-windows-msvc" || hostPlatform.config == "aarch64-uwp-windows-msvc") then "windows_aarch64_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_aarch64_msvc."0.42.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.config == "i686-pc-windows-gnu" || hostPlatform.config == "i686-uwp-windows-gnu") then "windows_i686_gnu" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_i686_gnu."0.42.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.config == "i686-pc-windows-msvc" || hostPlatform.config == "i686-uwp-windows-msvc") then "windows_i686_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_i686_msvc."0.42.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.config == "x86_64-pc-windows-gnu" || hostPlatform.config == "x86_64-uwp-windows-gnu") then "windows_x86_64_gnu" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_gnu."0.42.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && hostPlatform.config == "x86_64-pc-windows-gnullvm" then "windows_x86_64_gnullvm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_gnullvm."0.42.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/camera" && (hostPlatform.config == "x86_64-pc-windows-msvc" || hostPlatform.config == "x86_64-uwp-windows-msvc") then "windows_x86_64_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_msvc."0.42.2" { inherit profileName; }).out;
     };
   });
   
@@ -5016,6 +6352,13 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "5e6ad25900d524eaabdbbb96d20b4311e1e7ae1699af4fb28c17ae66c80d798a"; };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".windows-link."0.2.1" = overridableMkRustCrate (profileName: rec {
+    name = "windows-link";
+    version = "0.2.1";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "f0805222e57f7521d6a62e36fa9163bc891acd422f971defe97d64e70d0a4fe5"; };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".windows-numerics."0.2.0" = overridableMkRustCrate (profileName: rec {
     name = "windows-numerics";
     version = "0.2.0";
@@ -5061,7 +6404,14 @@ in
     features = builtins.concatLists [
       [ "Win32" ]
       [ "Win32_Foundation" ]
+      [ "Win32_NetworkManagement" ]
+      [ "Win32_NetworkManagement_IpHelper" ]
+      [ "Win32_Networking" ]
+      [ "Win32_Networking_WinSock" ]
+      [ "Win32_Security" ]
+      [ "Win32_Security_Authorization" ]
       [ "Win32_System" ]
+      [ "Win32_System_Memory" ]
       [ "Win32_System_Threading" ]
       [ "default" ]
     ];
@@ -5078,8 +6428,8 @@ in
     features = builtins.concatLists [
       [ "Win32" ]
       [ "Win32_Foundation" ]
-      [ "Win32_NetworkManagement" ]
-      [ "Win32_NetworkManagement_IpHelper" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32_NetworkManagement")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32_NetworkManagement_IpHelper")
       [ "Win32_Networking" ]
       [ "Win32_Networking_WinSock" ]
       [ "Win32_Storage" ]
@@ -5089,11 +6439,11 @@ in
       [ "Win32_System_Console" ]
       [ "Win32_System_Diagnostics" ]
       [ "Win32_System_Diagnostics_Debug" ]
-      [ "Win32_System_SystemInformation" ]
-      [ "Win32_System_Threading" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "Win32_System_SystemInformation")
+      (lib.optional (rootFeatures' ? "bongo-modulator/camera") "Win32_System_Threading")
       [ "Win32_UI" ]
-      [ "Win32_UI_Input" ]
-      [ "Win32_UI_Input_KeyboardAndMouse" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_UI_Input")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_UI_Input_KeyboardAndMouse")
       [ "Win32_UI_Shell" ]
       [ "default" ]
     ];
@@ -5108,20 +6458,54 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f2f500e4d28234f72040990ec9d39e3a6b950f9f22d3dba18416c35882612bcb"; };
     features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_Foundation")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_Storage")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_Storage_FileSystem")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_System")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_System_IO")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "default")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/hub" then "windows_targets" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-targets."0.53.2" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.61.2" = overridableMkRustCrate (profileName: rec {
+    name = "windows-sys";
+    version = "0.61.2";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "ae137229bcbd6cdf0f7b80a31df61766145077ddf49416a728b02cb3921ff3fc"; };
+    features = builtins.concatLists [
+      [ "Wdk" ]
+      [ "Wdk_Foundation" ]
+      [ "Wdk_Storage" ]
+      [ "Wdk_Storage_FileSystem" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Wdk_System")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Wdk_System_IO")
       [ "Win32" ]
       [ "Win32_Foundation" ]
-      [ "Win32_Globalization" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_Globalization")
+      [ "Win32_Networking" ]
+      [ "Win32_Networking_WinSock" ]
+      [ "Win32_Security" ]
       [ "Win32_Storage" ]
       [ "Win32_Storage_FileSystem" ]
       [ "Win32_System" ]
-      [ "Win32_System_Com" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_System_Com")
+      [ "Win32_System_Console" ]
       [ "Win32_System_IO" ]
-      [ "Win32_UI" ]
-      [ "Win32_UI_Shell" ]
+      [ "Win32_System_LibraryLoader" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Win32_System_Pipes")
+      (lib.optional (rootFeatures' ? "bongo-modulator/otel") "Win32_System_SystemServices")
+      [ "Win32_System_Threading" ]
+      [ "Win32_System_WindowsProgramming" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_UI")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "Win32_UI_Shell")
       [ "default" ]
     ];
     dependencies = {
-      windows_targets = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-targets."0.53.2" { inherit profileName; }).out;
+      windows_link = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-link."0.2.1" { inherit profileName; }).out;
     };
   });
   
@@ -5148,14 +6532,14 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c66f69fcc9ce11da9966ddb31a40968cad001c5bedeb5c2b82ede4253ab48aef"; };
     dependencies = {
-      ${ if hostPlatform.config == "aarch64-pc-windows-gnullvm" then "windows_aarch64_gnullvm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_aarch64_gnullvm."0.53.0" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.abi.name == "msvc" then "windows_aarch64_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_aarch64_msvc."0.53.0" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.cpu.name == "i686" && hostPlatform.parsed.abi.name == "gnu" then "windows_i686_gnu" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_i686_gnu."0.53.0" { inherit profileName; }).out;
-      ${ if hostPlatform.config == "i686-pc-windows-gnullvm" then "windows_i686_gnullvm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_i686_gnullvm."0.53.0" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.cpu.name == "i686" && hostPlatform.parsed.abi.name == "msvc" then "windows_i686_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_i686_msvc."0.53.0" { inherit profileName; }).out;
-      ${ if hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.abi.name == "gnu" then "windows_x86_64_gnu" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_gnu."0.53.0" { inherit profileName; }).out;
-      ${ if hostPlatform.config == "x86_64-pc-windows-gnullvm" then "windows_x86_64_gnullvm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_gnullvm."0.53.0" { inherit profileName; }).out;
-      ${ if (hostPlatform.parsed.cpu.name == "x86_64" || hostPlatform.parsed.cpu.name == "arm64ec") && hostPlatform.parsed.abi.name == "msvc" then "windows_x86_64_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_msvc."0.53.0" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.config == "aarch64-pc-windows-gnullvm" then "windows_aarch64_gnullvm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_aarch64_gnullvm."0.53.0" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.parsed.cpu.name == "aarch64" && hostPlatform.parsed.abi.name == "msvc" then "windows_aarch64_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_aarch64_msvc."0.53.0" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.parsed.cpu.name == "i686" && hostPlatform.parsed.abi.name == "gnu" then "windows_i686_gnu" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_i686_gnu."0.53.0" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.config == "i686-pc-windows-gnullvm" then "windows_i686_gnullvm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_i686_gnullvm."0.53.0" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.parsed.cpu.name == "i686" && hostPlatform.parsed.abi.name == "msvc" then "windows_i686_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_i686_msvc."0.53.0" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.parsed.cpu.name == "x86_64" && hostPlatform.parsed.abi.name == "gnu" then "windows_x86_64_gnu" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_gnu."0.53.0" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && hostPlatform.config == "x86_64-pc-windows-gnullvm" then "windows_x86_64_gnullvm" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_gnullvm."0.53.0" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && (hostPlatform.parsed.cpu.name == "x86_64" || hostPlatform.parsed.cpu.name == "arm64ec") && hostPlatform.parsed.abi.name == "msvc" then "windows_x86_64_msvc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows_x86_64_msvc."0.53.0" { inherit profileName; }).out;
     };
   });
   
@@ -5365,22 +6749,33 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "ea2f10b9bb0928dfb1b42b65e1f9e36f7f54dbdf08457afefb38afcdec4fa2bb"; };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".xdg-home."1.3.0" = overridableMkRustCrate (profileName: rec {
+    name = "xdg-home";
+    version = "1.3.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "ec1cdab258fb55c0da61328dc52c8764709b249011b2cad0454c72f0bf10a1f6"; };
+    dependencies = {
+      ${ if hostPlatform.isUnix then "libc" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.173" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.59.0" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".yoke."0.7.5" = overridableMkRustCrate (profileName: rec {
     name = "yoke";
     version = "0.7.5";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "120e6aef9aa629e3d4f52dc8cc43a015c7724194c97dfaf45180d2daf2b77f40"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "default" ]
-      [ "derive" ]
-      [ "zerofrom" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "derive")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "zerofrom")
     ];
     dependencies = {
-      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
-      stable_deref_trait = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".stable_deref_trait."1.2.0" { inherit profileName; }).out;
-      yoke_derive = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".yoke-derive."0.7.5" { profileName = "__noProfile"; }).out;
-      zerofrom = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zerofrom."0.1.6" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "serde" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "stable_deref_trait" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".stable_deref_trait."1.2.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "yoke_derive" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".yoke-derive."0.7.5" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "zerofrom" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zerofrom."0.1.6" { inherit profileName; }).out;
     };
   });
   
@@ -5408,10 +6803,10 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2380878cad4ac9aac1e2435f3eb4020e8374b5f13c296cb75b4620ff8e229154"; };
     dependencies = {
-      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
-      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
-      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
-      synstructure = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".synstructure."0.13.2" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "proc_macro2" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "quote" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "syn" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "synstructure" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".synstructure."0.13.2" { inherit profileName; }).out;
     };
   });
   
@@ -5428,6 +6823,80 @@ in
     };
   });
   
+  "registry+https://github.com/rust-lang/crates.io-index".zbus."4.4.0" = overridableMkRustCrate (profileName: rec {
+    name = "zbus";
+    version = "4.4.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "bb97012beadd29e654708a0fdb4c84bc046f537aecfde2c3ee0a9e4b4d48c725"; };
+    features = builtins.concatLists [
+      [ "async-executor" ]
+      [ "async-fs" ]
+      [ "async-io" ]
+      [ "async-lock" ]
+      [ "async-task" ]
+      [ "blocking" ]
+      [ "default" ]
+    ];
+    dependencies = {
+      async_broadcast = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-broadcast."0.7.2" { inherit profileName; }).out;
+      async_executor = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-executor."1.14.0" { inherit profileName; }).out;
+      async_fs = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-fs."2.2.0" { inherit profileName; }).out;
+      async_io = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-io."2.6.0" { inherit profileName; }).out;
+      async_lock = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-lock."3.4.2" { inherit profileName; }).out;
+      ${ if hostPlatform.parsed.kernel.name == "darwin" then "async_process" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-process."2.5.0" { inherit profileName; }).out;
+      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.isWindows then "async_recursion" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".async-recursion."1.2.0" { profileName = "__noProfile"; }).out;
+      async_task = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".async-task."4.7.1" { inherit profileName; }).out;
+      async_trait = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".async-trait."0.1.92" { profileName = "__noProfile"; }).out;
+      blocking = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".blocking."1.7.0" { inherit profileName; }).out;
+      enumflags2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".enumflags2."0.7.12" { inherit profileName; }).out;
+      event_listener = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".event-listener."5.4.2" { inherit profileName; }).out;
+      futures_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.31" { inherit profileName; }).out;
+      futures_sink = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-sink."0.3.31" { inherit profileName; }).out;
+      futures_util = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-util."0.3.31" { inherit profileName; }).out;
+      hex = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".hex."0.4.3" { inherit profileName; }).out;
+      ${ if hostPlatform.isUnix then "nix" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".nix."0.29.0" { inherit profileName; }).out;
+      ordered_stream = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".ordered-stream."0.2.0" { inherit profileName; }).out;
+      rand = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.8.5" { inherit profileName; }).out;
+      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      serde_repr = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_repr."0.1.21" { profileName = "__noProfile"; }).out;
+      sha1 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".sha1."0.10.7" { inherit profileName; }).out;
+      static_assertions = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".static_assertions."1.1.0" { inherit profileName; }).out;
+      tracing = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.44" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "uds_windows" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".uds_windows."1.2.1" { inherit profileName; }).out;
+      ${ if hostPlatform.isWindows then "windows_sys" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".windows-sys."0.52.0" { inherit profileName; }).out;
+      xdg_home = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".xdg-home."1.3.0" { inherit profileName; }).out;
+      zbus_macros = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".zbus_macros."4.4.0" { profileName = "__noProfile"; }).out;
+      zbus_names = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zbus_names."3.0.0" { inherit profileName; }).out;
+      zvariant = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zvariant."4.2.0" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".zbus_macros."4.4.0" = overridableMkRustCrate (profileName: rec {
+    name = "zbus_macros";
+    version = "4.4.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "267db9407081e90bbfa46d841d3cbc60f59c0351838c4bc65199ecd79ab1983e"; };
+    dependencies = {
+      proc_macro_crate = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro-crate."3.3.0" { inherit profileName; }).out;
+      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      zvariant_utils = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zvariant_utils."2.1.0" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".zbus_names."3.0.0" = overridableMkRustCrate (profileName: rec {
+    name = "zbus_names";
+    version = "3.0.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "4b9b1fef7d021261cc16cba64c351d291b715febe0fa10dc3a443ac5a5022e6c"; };
+    dependencies = {
+      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      static_assertions = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".static_assertions."1.1.0" { inherit profileName; }).out;
+      zvariant = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zvariant."4.2.0" { inherit profileName; }).out;
+    };
+  });
+  
   "registry+https://github.com/rust-lang/crates.io-index".zerocopy."0.8.25" = overridableMkRustCrate (profileName: rec {
     name = "zerocopy";
     version = "0.8.25";
@@ -5486,8 +6955,8 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ced3678a2879b30306d323f4542626697a464a97c0a07c9aebf7ebca65cd4dde"; };
     features = builtins.concatLists [
-      [ "alloc" ]
-      [ "default" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "alloc")
+      (lib.optional (rootFeatures' ? "bongo-modulator/hub") "default")
     ];
   });
   
@@ -5543,12 +7012,12 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "9cc23c04387f4da0374be4533ad1208cbb091d5c11d070dfef13676ad6497164"; };
     dependencies = {
       ${ if false then "arbitrary" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".arbitrary."1.4.1" { inherit profileName; }).out;
-      crc32fast = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crc32fast."1.4.2" { inherit profileName; }).out;
-      ${ if (hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l") && hostPlatform.parsed.cpu.bits == 32 || hostPlatform.parsed.cpu.name == "mips" || hostPlatform.parsed.cpu.name == "powerpc" then "crossbeam_utils" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.8.21" { inherit profileName; }).out;
-      displaydoc = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".displaydoc."0.2.5" { profileName = "__noProfile"; }).out;
-      indexmap = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".indexmap."2.9.0" { inherit profileName; }).out;
-      num_enum = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num_enum."0.7.3" { inherit profileName; }).out;
-      thiserror = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "crc32fast" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crc32fast."1.4.2" { inherit profileName; }).out;
+      ${ if (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") && ((hostPlatform.parsed.cpu.name == "armv6l" || hostPlatform.parsed.cpu.name == "armv7l") && hostPlatform.parsed.cpu.bits == 32 || hostPlatform.parsed.cpu.name == "mips" || hostPlatform.parsed.cpu.name == "powerpc") then "crossbeam_utils" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.8.21" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "displaydoc" else null } = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".displaydoc."0.2.5" { profileName = "__noProfile"; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "indexmap" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".indexmap."2.9.0" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "num_enum" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".num_enum."0.7.3" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "thiserror" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".thiserror."1.0.69" { inherit profileName; }).out;
     };
   });
   
@@ -5558,7 +7027,7 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3f423a2c17029964870cfaabb1f13dfab7d092a62a29a89264f4d36990ca414a"; };
     features = builtins.concatLists [
-      [ "std" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
     ];
   });
   
@@ -5568,11 +7037,11 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "73ab332fe2f6680068f3582b16a24f90ad7096d5d39b974d1c0aff0125116f02"; };
     features = builtins.concatLists [
-      [ "simd-adler32" ]
-      [ "zlib" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "simd-adler32")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "zlib")
     ];
     dependencies = {
-      simd_adler32 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".simd-adler32."0.3.7" { inherit profileName; }).out;
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "simd_adler32" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".simd-adler32."0.3.7" { inherit profileName; }).out;
     };
   });
   
@@ -5582,13 +7051,56 @@ in
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0f6fe2e33d02a98ee64423802e16df3de99c43e5cf5ff983767e1128b394c8ac"; };
     features = builtins.concatLists [
-      [ "default" ]
-      [ "neon" ]
-      [ "std" ]
-      [ "x86" ]
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "default")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "neon")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "std")
+      (lib.optional (rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub") "x86")
+    ];
+    dependencies = {
+      ${ if rootFeatures' ? "bongo-modulator/ai" || rootFeatures' ? "bongo-modulator/camera" || rootFeatures' ? "bongo-modulator/hub" then "zune_core" else null } = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zune-core."0.4.12" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".zvariant."4.2.0" = overridableMkRustCrate (profileName: rec {
+    name = "zvariant";
+    version = "4.2.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "2084290ab9a1c471c38fc524945837734fbf124487e105daec2bb57fd48c81fe"; };
+    features = builtins.concatLists [
+      [ "enumflags2" ]
     ];
     dependencies = {
-      zune_core = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zune-core."0.4.12" { inherit profileName; }).out;
+      endi = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".endi."1.1.1" { inherit profileName; }).out;
+      enumflags2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".enumflags2."0.7.12" { inherit profileName; }).out;
+      serde = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.219" { inherit profileName; }).out;
+      static_assertions = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".static_assertions."1.1.0" { inherit profileName; }).out;
+      zvariant_derive = (buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".zvariant_derive."4.2.0" { profileName = "__noProfile"; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".zvariant_derive."4.2.0" = overridableMkRustCrate (profileName: rec {
+    name = "zvariant_derive";
+    version = "4.2.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "73e2ba546bda683a90652bac4a279bc146adad1386f25379cf73200d2002c449"; };
+    dependencies = {
+      proc_macro_crate = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro-crate."3.3.0" { inherit profileName; }).out;
+      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
+      zvariant_utils = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".zvariant_utils."2.1.0" { inherit profileName; }).out;
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".zvariant_utils."2.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "zvariant_utils";
+    version = "2.1.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "c51bcff7cc3dbb5055396bcf774748c3dab426b4b8659046963523cee4808340"; };
+    dependencies = {
+      proc_macro2 = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.95" { inherit profileName; }).out;
+      quote = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.40" { inherit profileName; }).out;
+      syn = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".syn."2.0.103" { inherit profileName; }).out;
     };
   });
   
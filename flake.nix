@@ -23,6 +23,11 @@
       rustPkgs = pkgs.rustBuilder.makePackageSet {
         rustToolchain = rustToolchain;
         packageFun = import ./Cargo.nix;
+        rootFeatures = [
+          "bongo-modulator/default"
+          "bongo-modulator/camera"
+          "bongo-modulator/hub"
+        ];
         # enforce strict Cargo.nix vs Cargo.lock matching for reproducibility
         ignoreLockHash = false;
         packageOverrides = pkgs:
@@ -52,19 +57,8 @@
         LIBCLANG_PATH = "${pkgs.llvmPackages.libclang.lib}/lib";
         BINDGEN_EXTRA_CLANG_ARGS = "-I${pkgs.linuxHeaders}/include -I${pkgs.glibc.dev}/include";
         postInstall = (old.postInstall or "") + ''
-          mkdir -p $out/lib/systemd/system
-          cat > $out/lib/systemd/system/bongo-modulator.service <<EOF
-          [Unit]
-          Description=Bongo Modulator daemon
-          After=network.target
-
-          [Service]
-          ExecStart=$out/bin/bongo-modulator daemon
-          Restart=on-failure
-
-          [Install]
-          WantedBy=multi-user.target
-          EOF
+          $out/bin/bongo-modulator install-service \
+            --dir $out/lib/systemd/user --exe $out/bin/bongo-modulator
         '';
       });
 
@@ -79,6 +73,8 @@
           pkgs.llvmPackages.libclang
           pkgs.linuxHeaders
           pkgs.libv4l
+          # dbus-daemon for the D-Bus and logind tests
+          pkgs.dbus
         ];
         LIBCLANG_PATH = "${pkgs.llvmPackages.libclang.lib}/lib";
         BINDGEN_EXTRA_CLANG_ARGS = "-I${pkgs.linuxHeaders}/include -I${pkgs.glibc.dev}/include";
@@ -93,6 +89,7 @@
           pkgs.llvmPackages.libclang
           pkgs.linuxHeaders
           pkgs.libv4l
+          pkgs.dbus
         ];
         commonEnv = {
           LIBCLANG_PATH = "${pkgs.llvmPackages.libclang.lib}/lib";
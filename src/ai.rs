@@ -1,180 +1,562 @@
+use crate::capture::{mean_luminance, FrameSource};
+#[cfg(feature = "camera")]
+use crate::capture::{CameraSource, ReplaySource};
+use crate::config::Config;
+use crate::events::{Event, EventBus};
+use crate::face::{decode_face_outputs, facing_count, FaceLayout};
+use crate::metrics::{Metrics, Stage};
+use crate::policy::{FpsPolicy, Signals};
+use crate::pose::{decode_poses, DrummingTracker};
+use crate::scheduler::Rate;
+use crate::session::LockState;
+use crate::status::{AiHealth, Detection, WorkerState};
 use candle_core::{DType, Device, Tensor};
+use candle_onnx::onnx::tensor_shape_proto::dimension;
+use candle_onnx::onnx::type_proto;
 use candle_onnx::{onnx, read_file, simple_eval};
+#[cfg(feature = "hub")]
 use hf_hub::api::sync::Api;
 use image::imageops::FilterType;
-use nokhwa::{
-    pixel_format::RgbFormat,
-    utils::{CameraFormat, CameraIndex, FrameFormat, RequestedFormat, RequestedFormatType},
-    Camera,
-};
 use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::Path;
-use std::sync::{
-    atomic::{AtomicBool, AtomicU32, Ordering},
-    Arc,
-};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
 use std::time::{Duration, Instant};
-use tracing::{debug, error};
+use tracing::{debug, error, info, info_span};
 
-pub fn spawn_ai_thread(fps: Arc<AtomicU32>, enabled: Arc<AtomicBool>) {
-    std::thread::spawn(move || {
-        let format = RequestedFormat::new::<RgbFormat>(RequestedFormatType::None);
-        let mut cam = None;
-        let mut last_err = None;
-        for (w, h) in [(1280, 720), (640, 480)] {
-            for fmt in [FrameFormat::RAWRGB, FrameFormat::MJPEG, FrameFormat::YUYV] {
-                let req = RequestedFormat::new::<RgbFormat>(RequestedFormatType::Closest(
-                    CameraFormat::new_from(w, h, fmt, 30),
-                ));
-                debug!(width = w, height = h, ?fmt, "trying camera format");
-                match Camera::new(CameraIndex::Index(0), req) {
-                    Ok(c) => {
-                        cam = Some(c);
-                        break;
-                    }
-                    Err(e) => {
-                        debug!(width = w, height = h, ?fmt, error = ?e, "camera format failed");
-                        last_err = Some(e);
-                    }
-                }
-            }
-            if cam.is_some() {
-                break;
-            }
+const DEFAULT_MODEL: &str = "yolov8n-onnx-web/yolov8n.onnx";
+const DEFAULT_REPO: &str = "salim4n/yolov8n-detect-onnx";
+/// Input side used for image dimensions a model leaves dynamic.
+const DEFAULT_INPUT: u32 = 640;
+
+/// Resolves the model file, downloading it from `repo` when it is not
+/// available locally and the `hub` feature is enabled.
+pub fn resolve_model_path(filename: &str, repo: &str) -> Result<String, String> {
+    if Path::new(filename).exists() {
+        return Ok(filename.to_string());
+    }
+    #[cfg(feature = "hub")]
+    {
+        Api::new()
+            .and_then(|api| api.model(repo.to_string()).get(filename))
+            .map(|p| p.to_string_lossy().into())
+            .map_err(|e| format!("failed to download model {filename} from {repo}: {e}"))
+    }
+    #[cfg(not(feature = "hub"))]
+    {
+        Err(format!(
+            "model {filename} not found locally; build with the `hub` feature to download it from {repo}"
+        ))
+    }
+}
+
+/// Resolves a model spec, either a local path or `repo:file` on the hub.
+/// Bare filenames that do not exist locally are fetched from
+/// `BONGO_YOLO_REPO`.
+pub fn resolve_model_spec(spec: &str) -> Result<String, String> {
+    if Path::new(spec).exists() {
+        return Ok(spec.to_string());
+    }
+    match spec.split_once(':') {
+        Some((repo, file)) if !repo.is_empty() && !file.is_empty() => {
+            resolve_model_path(file, repo)
         }
-        let mut cam = match cam.or_else(|| Camera::new(CameraIndex::Index(0), format).ok()) {
-            Some(c) => c,
-            None => {
-                if let Some(e) = last_err {
-                    error!("failed to open camera: {e}");
-                } else {
-                    error!("failed to open camera");
-                }
-                return;
+        _ => {
+            let repo =
+                std::env::var("BONGO_YOLO_REPO").unwrap_or_else(|_| DEFAULT_REPO.to_string());
+            resolve_model_path(spec, &repo)
+        }
+    }
+}
+
+/// Returns the model spec used when none has been configured.
+pub fn default_model_spec() -> String {
+    std::env::var("BONGO_YOLO_MODEL").unwrap_or_else(|_| DEFAULT_MODEL.to_string())
+}
+
+/// Reads an ONNX model and patches the ops candle cannot evaluate.
+pub fn load_model(path: &str) -> Result<onnx::ModelProto, String> {
+    let mut model = read_file(path).map_err(|e| format!("failed to load model: {e}"))?;
+    patch_maxpool_padding(&mut model);
+    patch_resize_identity(&mut model);
+    patch_pad_tensors(&mut model);
+    match &model.graph {
+        None => Err("model graph missing".to_string()),
+        Some(g) if g.input.is_empty() || g.output.is_empty() => {
+            Err("model graph has no inputs or outputs".to_string())
+        }
+        Some(_) => Ok(model),
+    }
+}
+
+/// Runs the model once on a blank frame to make sure candle can evaluate it.
+pub fn validate_model(model: &onnx::ModelProto) -> Result<(), String> {
+    let blank = image::DynamicImage::new_rgb8(640, 640);
+    infer(model, blank, &Device::Cpu)
+        .map(|_| ())
+        .ok_or_else(|| "model failed to run on a test frame".to_string())
+}
+
+/// Shared slot holding the model used by the AI worker.
+///
+/// Replacements are loaded and validated first, so a failed swap leaves the
+/// active model untouched.
+#[derive(Clone, Default)]
+pub struct ModelHandle(Arc<Mutex<Option<Arc<onnx::ModelProto>>>>);
+
+impl ModelHandle {
+    pub fn current(&self) -> Option<Arc<onnx::ModelProto>> {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// Loads the model described by `spec` and swaps it in once it has been
+    /// validated.
+    pub fn load(&self, spec: &str) -> Result<(), String> {
+        debug!(spec, "loading model");
+        let path = resolve_model_spec(spec)?;
+        let model = load_model(&path)?;
+        validate_model(&model)?;
+        *self.0.lock().unwrap() = Some(Arc::new(model));
+        info!(spec, path = %path, "model activated");
+        Ok(())
+    }
+}
+
+/// How a model expects its input pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pixels {
+    /// RGB scaled to `0..=1`, as YOLO exports expect.
+    #[default]
+    Unit,
+    /// RGB mapped to `(v - 127) / 128`, as UltraFace expects.
+    Centered,
+    /// BGR left at `0..=255`, as YuNet expects.
+    RawBgr,
+}
+
+/// The `(width, height)` of the model's NCHW image input, with dynamic
+/// dimensions taken as 640.
+pub fn input_size(model: &onnx::ModelProto) -> (u32, u32) {
+    let dims: Vec<Option<u32>> = model
+        .graph
+        .as_ref()
+        .and_then(|g| g.input.first())
+        .and_then(|input| input.r#type.as_ref())
+        .and_then(|t| match &t.value {
+            Some(type_proto::Value::TensorType(tensor)) => tensor.shape.as_ref(),
+            _ => None,
+        })
+        .map(|shape| {
+            shape
+                .dim
+                .iter()
+                .map(|d| match d.value {
+                    Some(dimension::Value::DimValue(v)) => u32::try_from(v).ok().filter(|&v| v > 0),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    match dims[..] {
+        [_, _, height, width] => (
+            width.unwrap_or(DEFAULT_INPUT),
+            height.unwrap_or(DEFAULT_INPUT),
+        ),
+        _ => (DEFAULT_INPUT, DEFAULT_INPUT),
+    }
+}
+
+/// Resizes a frame to the model input and runs the model on it, returning
+/// every graph output by name.
+pub fn infer_outputs(
+    model: &onnx::ModelProto,
+    img: image::DynamicImage,
+    device: &Device,
+    pixels: Pixels,
+) -> Option<HashMap<String, Tensor>> {
+    run_model(model, img, device, pixels, None)
+}
+
+/// [`infer_outputs`], timing the preprocessing and evaluation stages in
+/// `metrics` when given.
+fn run_model(
+    model: &onnx::ModelProto,
+    img: image::DynamicImage,
+    device: &Device,
+    pixels: Pixels,
+    metrics: Option<&Metrics>,
+) -> Option<HashMap<String, Tensor>> {
+    let graph = model.graph.as_ref()?;
+    let input_name = graph.input[0].name.clone();
+    let size = input_size(model);
+    let tensor = info_span!("preprocess").in_scope(|| {
+        timed(metrics, Stage::Preprocess, || {
+            preprocess(img, size, pixels, device)
+        })
+    })?;
+    let mut inputs = HashMap::new();
+    inputs.insert(input_name, tensor);
+    let outputs = info_span!("simple_eval")
+        .in_scope(|| timed(metrics, Stage::Eval, || simple_eval(model, inputs)));
+    match outputs {
+        Ok(outputs) => Some(outputs),
+        Err(e) => {
+            error!("failed to run model: {e}");
+            None
+        }
+    }
+}
+
+/// Runs a model taking `0..=1` RGB input on a frame, returning the first
+/// graph output.
+pub fn infer(
+    model: &onnx::ModelProto,
+    img: image::DynamicImage,
+    device: &Device,
+) -> Option<Tensor> {
+    run_first(model, img, device, None)
+}
+
+/// [`infer`], timing its stages in `metrics` when given.
+fn run_first(
+    model: &onnx::ModelProto,
+    img: image::DynamicImage,
+    device: &Device,
+    metrics: Option<&Metrics>,
+) -> Option<Tensor> {
+    let output_name = &model.graph.as_ref()?.output[0].name;
+    let mut outputs = run_model(model, img, device, Pixels::Unit, metrics)?;
+    match outputs.remove(output_name) {
+        Some(o) => Some(o),
+        None => {
+            error!("model output missing");
+            None
+        }
+    }
+}
+
+/// Turns a frame into the model's 1x3xHxW input tensor.
+fn preprocess(
+    img: image::DynamicImage,
+    (width, height): (u32, u32),
+    pixels: Pixels,
+    device: &Device,
+) -> Option<Tensor> {
+    let img = img.resize_exact(width, height, FilterType::CatmullRom);
+    let mut data = img.into_rgb8().into_raw();
+    let (scale, offset) = match pixels {
+        Pixels::Unit => (1.0 / 255.0, 0.0),
+        Pixels::Centered => (1.0 / 128.0, -127.0 / 128.0),
+        Pixels::RawBgr => {
+            data.chunks_exact_mut(3).for_each(|px| px.swap(0, 2));
+            (1.0, 0.0)
+        }
+    };
+    let shape = (height as usize, width as usize, 3);
+    let tensor = match Tensor::from_vec(data, shape, device) {
+        Ok(t) => match t
+            .permute((2, 0, 1))
+            .and_then(|t| t.to_dtype(DType::F32))
+            .and_then(|t| t.affine(scale, offset))
+        {
+            Ok(v) => v,
+            Err(e) => {
+                error!("failed to prepare tensor: {e}");
+                return None;
             }
-        };
-        if let Err(e) = cam.open_stream() {
-            error!("failed to open camera stream: {e}");
+        },
+        Err(e) => {
+            error!("failed to create tensor: {e}");
+            return None;
+        }
+    };
+    match tensor.unsqueeze(0) {
+        Ok(t) => Some(t),
+        Err(e) => {
+            error!("failed to unsqueeze tensor: {e}");
+            None
+        }
+    }
+}
+
+/// Models used by the AI worker, each swappable at runtime.
+#[derive(Clone, Default)]
+pub struct Models {
+    pub objects: ModelHandle,
+    pub faces: ModelHandle,
+    pub pose: ModelHandle,
+}
+
+/// Returns the face model spec from `BONGO_FACE_MODEL`, if set.
+pub fn face_model_spec() -> Option<String> {
+    std::env::var("BONGO_FACE_MODEL").ok()
+}
+
+/// Returns the pose model spec from `BONGO_POSE_MODEL`, if set.
+pub fn pose_model_spec() -> Option<String> {
+    std::env::var("BONGO_POSE_MODEL").ok()
+}
+
+const FACE_CONFIDENCE: f32 = 0.5;
+
+/// Tells a face model's layout from its output names.
+fn face_layout(model: &onnx::ModelProto) -> FaceLayout {
+    let outputs = model.graph.iter().flat_map(|g| &g.output);
+    FaceLayout::detect(outputs.map(|o| o.name.as_str()))
+}
+
+fn face_pixels(layout: FaceLayout) -> Pixels {
+    match layout {
+        FaceLayout::Yolo => Pixels::Unit,
+        FaceLayout::UltraFace => Pixels::Centered,
+        FaceLayout::YuNet => Pixels::RawBgr,
+    }
+}
+const FRONTAL_THRESHOLD: f32 = 0.5;
+const POSE_CONFIDENCE: f32 = 0.5;
+const KEYPOINT_CONFIDENCE: f32 = 0.5;
+/// Longest pause between passes while object detection keeps failing.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// Pause before starting the AI worker again after it panicked.
+const RESTART_DELAY: Duration = Duration::from_secs(1);
+
+/// What the AI worker reads and drives, shared with the rest of the daemon.
+#[derive(Clone)]
+pub struct Worker {
+    /// The rate the worker sets from its detections.
+    pub fps: Arc<Rate>,
+    /// Whether AI mode is on; the worker pauses while it is not.
+    pub enabled: Arc<AtomicBool>,
+    pub session: Arc<LockState>,
+    pub models: Models,
+    pub policy: Arc<Mutex<FpsPolicy>>,
+    pub health: Arc<AiHealth>,
+    /// Where detections and rate changes are announced.
+    pub events: Arc<EventBus>,
+    pub metrics: Arc<Metrics>,
+    pub cfg: Config,
+}
+
+/// Starts the AI worker on the camera, or on the replay directory from
+/// `BONGO_CAMERA_REPLAY` when set.
+#[cfg(feature = "camera")]
+pub fn spawn_ai_thread(worker: Worker) -> JoinHandle<()> {
+    std::thread::spawn(move || {
+        // Don't light up the camera before there is a lockscreen to drive.
+        worker.health.set(WorkerState::Waiting);
+        if !worker.session.wait_locked() {
+            worker.health.set(WorkerState::Stopped);
             return;
         }
-        debug!(format = ?cam.camera_format(), "camera stream opened");
-
-        let filename = std::env::var("BONGO_YOLO_MODEL")
-            .unwrap_or_else(|_| "yolov8n-onnx-web/yolov8n.onnx".to_string());
-        let repo = std::env::var("BONGO_YOLO_REPO")
-            .unwrap_or_else(|_| "salim4n/yolov8n-detect-onnx".to_string());
-        let model_path = if Path::new(&filename).exists() {
-            filename.clone()
+        if let Some(replay) = ReplaySource::from_env() {
+            supervise(replay, worker);
+        } else if let Some(cam) = CameraSource::open() {
+            supervise(cam, worker);
         } else {
-            match Api::new().and_then(|api| api.model(repo).get(&filename)) {
-                Ok(p) => p.to_string_lossy().into(),
-                Err(e) => {
-                    error!("failed to download model: {e}");
-                    return;
-                }
-            }
-        };
-        let mut model = match read_file(&model_path) {
-            Ok(m) => m,
-            Err(e) => {
-                error!("failed to load model: {e}");
-                return;
+            worker.health.set(WorkerState::NoSource);
+            worker.health.error("no camera could be opened");
+        }
+    })
+}
+
+/// Starts the AI worker on an arbitrary frame source. It stops, releasing
+/// the source, once the session is closed.
+pub fn spawn_worker<S: FrameSource + Send + 'static>(source: S, worker: Worker) -> JoinHandle<()> {
+    std::thread::spawn(move || supervise(source, worker))
+}
+
+/// Runs the worker on `source` until the session is closed, starting it
+/// again whenever it panics.
+fn supervise<S: FrameSource>(mut source: S, worker: Worker) {
+    loop {
+        let run = panic::catch_unwind(AssertUnwindSafe(|| run_worker(&mut source, worker.clone())));
+        if run.is_ok() {
+            return;
+        }
+        source.release();
+        worker.metrics.set_camera_open(false);
+        worker.metrics.worker_restarted();
+        worker.health.error("AI worker panicked; restarting it");
+        error!("AI worker panicked; restarting it");
+        std::thread::sleep(RESTART_DELAY);
+    }
+}
+
+/// Runs one stage of an AI pass, recording how long it took.
+fn timed<T>(metrics: Option<&Metrics>, stage: Stage, run: impl FnOnce() -> T) -> T {
+    let started = Instant::now();
+    let output = run();
+    if let Some(metrics) = metrics {
+        metrics.inference(stage, started.elapsed());
+    }
+    output
+}
+
+fn run_worker<S: FrameSource>(source: &mut S, worker: Worker) {
+    let Worker {
+        fps,
+        enabled,
+        session,
+        models,
+        policy,
+        health,
+        events,
+        metrics,
+        cfg,
+    } = worker;
+    health.set(WorkerState::Starting);
+    if models.objects.current().is_none() {
+        let spec = cfg.model.unwrap_or_else(default_model_spec);
+        if let Err(e) = models.objects.load(&spec) {
+            error!("{e}");
+            health.error(e);
+        }
+    }
+    if models.faces.current().is_none() {
+        if let Some(spec) = cfg.face_model.or_else(face_model_spec) {
+            if let Err(e) = models.faces.load(&spec) {
+                error!("face backend disabled: {e}");
+                health.error(format!("face backend disabled: {e}"));
             }
-        };
-        patch_maxpool_padding(&mut model);
-        patch_resize_identity(&mut model);
-        patch_pad_tensors(&mut model);
-        let graph = match &model.graph {
-            Some(g) => g,
-            None => {
-                error!("model graph missing");
-                return;
+        }
+    }
+    if models.pose.current().is_none() {
+        if let Some(spec) = cfg.pose_model.or_else(pose_model_spec) {
+            if let Err(e) = models.pose.load(&spec) {
+                error!("pose backend disabled: {e}");
+                health.error(format!("pose backend disabled: {e}"));
             }
-        };
-        let input_name = graph.input[0].name.clone();
-        let output_name = graph.output[0].name.clone();
-        let device = Device::Cpu;
-        debug!("AI thread started");
+        }
+    }
+    let mut tracker = DrummingTracker::default();
+    let device = Device::Cpu;
+    debug!("AI thread started");
 
-        let start = Instant::now();
+    let start = Instant::now();
+    let mut backoff = Duration::ZERO;
 
-        loop {
-            if !enabled.load(Ordering::Relaxed) {
-                std::thread::sleep(Duration::from_millis(100));
-                continue;
-            }
-            let frame = match cam.frame() {
-                Ok(f) => f,
-                Err(e) => {
-                    error!("failed to capture frame: {e}");
-                    continue;
-                }
-            };
-            let img = match frame.decode_image::<RgbFormat>() {
-                Ok(i) => image::DynamicImage::ImageRgb8(i),
-                Err(e) => {
-                    error!("failed to decode frame: {e}");
-                    continue;
-                }
-            };
-            let img = img.resize_exact(640, 640, FilterType::CatmullRom);
-            let data = img.into_rgb8().into_raw();
-            let tensor = match Tensor::from_vec(data, (640, 640, 3), &device) {
-                Ok(t) => match t
-                    .permute((2, 0, 1))
-                    .and_then(|t| t.to_dtype(DType::F32))
-                    .and_then(|t| t.affine(1.0 / 255.0, 0.0))
-                {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error!("failed to prepare tensor: {e}");
-                        continue;
-                    }
-                },
+    loop {
+        if session.is_closed() {
+            source.release();
+            metrics.set_camera_open(false);
+            health.set(WorkerState::Stopped);
+            debug!("AI worker stopped");
+            return;
+        }
+        if !session.is_locked() {
+            source.release();
+            metrics.set_camera_open(false);
+            health.set(WorkerState::Idle);
+            debug!("session unlocked; AI worker idle");
+            session.wait_locked();
+            continue;
+        }
+        if !enabled.load(Ordering::Relaxed) {
+            health.set(WorkerState::Paused);
+            std::thread::sleep(Duration::from_millis(100));
+            continue;
+        }
+        health.set(WorkerState::Running);
+        let pass = info_span!("frame").entered();
+        let img = info_span!("capture")
+            .in_scope(|| timed(Some(&metrics), Stage::Capture, || source.next_frame()));
+        let captured = Instant::now();
+        metrics.set_camera_open(source.is_open());
+        let Some(img) = img else {
+            drop(pass);
+            std::thread::sleep(Duration::from_millis(100));
+            continue;
+        };
+        let policy = policy.lock().unwrap().clone();
+        let brightness = policy.dark_threshold.map(|_| mean_luminance(&img));
+        let facing = models.faces.current().and_then(|face_model| {
+            let layout = face_layout(&face_model);
+            let outputs = info_span!("infer", model = "faces").in_scope(|| {
+                let pixels = face_pixels(layout);
+                run_model(&face_model, img.clone(), &device, pixels, Some(&metrics))
+            })?;
+            let _span = info_span!("postprocess", model = "faces").entered();
+            let size = input_size(&face_model);
+            let faces = timed(Some(&metrics), Stage::Postprocess, || {
+                decode_face_outputs(layout, &outputs, size, FACE_CONFIDENCE)
+            });
+            match faces {
+                Ok(faces) => Some(facing_count(&faces, FRONTAL_THRESHOLD)),
                 Err(e) => {
-                    error!("failed to create tensor: {e}");
-                    continue;
+                    error!("failed to decode faces: {e}");
+                    health.error(format!("failed to decode faces: {e}"));
+                    None
                 }
-            };
-            let mut inputs = HashMap::new();
-            let tensor = match tensor.unsqueeze(0) {
-                Ok(t) => t,
-                Err(e) => {
-                    error!("failed to unsqueeze tensor: {e}");
-                    continue;
+            }
+        });
+        let drumming = models.pose.current().and_then(|pose_model| {
+            let output = info_span!("infer", model = "pose")
+                .in_scope(|| run_first(&pose_model, img.clone(), &device, Some(&metrics)))?;
+            let _span = info_span!("postprocess", model = "pose").entered();
+            let poses = timed(Some(&metrics), Stage::Postprocess, || {
+                decode_poses(&output, POSE_CONFIDENCE)
+            });
+            match poses {
+                Ok(poses) => {
+                    let arms = poses.first().map(|p| p.arms(KEYPOINT_CONFIDENCE));
+                    tracker.update(arms)
                 }
-            };
-            inputs.insert(input_name.clone(), tensor);
-            let mut outputs = match simple_eval(&model, inputs) {
-                Ok(o) => o,
                 Err(e) => {
-                    error!("failed to run model: {e}");
-                    continue;
+                    error!("failed to decode poses: {e}");
+                    health.error(format!("failed to decode poses: {e}"));
+                    None
                 }
-            };
-            let output = match outputs.remove(&output_name) {
-                Some(o) => o,
+            }
+        });
+        let objects = match models.objects.current() {
+            Some(model) => match info_span!("infer", model = "objects")
+                .in_scope(|| run_first(&model, img, &device, Some(&metrics)))
+            {
+                Some(output) => info_span!("postprocess", model = "objects").in_scope(|| {
+                    timed(Some(&metrics), Stage::Postprocess, || {
+                        output.dims().get(1).copied().unwrap_or(0)
+                    })
+                }),
                 None => {
-                    error!("model output missing");
+                    // A broken model fails every frame; don't spin on it.
+                    health.error("object detection failed");
+                    backoff = (backoff * 2).clamp(Duration::from_secs(1), MAX_BACKOFF);
+                    drop(pass);
+                    std::thread::sleep(backoff);
                     continue;
                 }
-            };
-            let dims = output.dims();
-            let count = dims.get(1).copied().unwrap_or(0);
-            let ratio = (start.elapsed().as_millis() % 1000) as f32 / 1000.0;
-            let computed = compute_fps(count, ratio);
-            debug!(fps = computed, count, ratio = ratio, "AI updated FPS");
-            fps.store(computed.to_bits(), Ordering::Relaxed);
-            std::thread::sleep(Duration::from_secs(1));
+            },
+            None => 0,
+        };
+        backoff = Duration::ZERO;
+        let signals = Signals {
+            objects,
+            facing,
+            drumming,
+            brightness,
+        };
+        let update = info_span!("fps_update", fps = tracing::field::Empty).entered();
+        let ratio = (start.elapsed().as_millis() % 1000) as f32 / 1000.0;
+        let computed = policy.fps(&signals, ratio);
+        update.record("fps", computed);
+        debug!(fps = computed, ?signals, ratio = ratio, "AI updated FPS");
+        health.detected(&signals, computed, captured);
+        metrics.detected(&signals);
+        events.publish(Event::Detection(Detection::new(
+            &signals,
+            computed,
+            captured.elapsed(),
+        )));
+        if fps.get() != computed {
+            events.publish(Event::Fps(computed));
         }
-    });
-}
-
-fn compute_fps(count: usize, ratio: f32) -> f32 {
-    let base = 5.0;
-    let weight = 20.0;
-    let fps = base + weight * ratio * count as f32;
-    fps.clamp(0.5, 30.0)
+        fps.set(computed);
+        drop(update);
+        drop(pass);
+        std::thread::sleep(Duration::from_secs(1));
+    }
 }
 
 fn patch_maxpool_padding(model: &mut onnx::ModelProto) {
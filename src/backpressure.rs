@@ -0,0 +1,107 @@
+use crate::target::{Outcome, Target, Tick};
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::trace;
+
+/// How long to wait for a fetch before signalling again anyway, so a
+/// consumer that dropped a signal does not stall the animation.
+pub const FETCH_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Weight of a new sample in the latency average.
+const SMOOTHING: f64 = 0.2;
+
+/// Correlates signals sent to Hyprlock with the `next-image` fetches they
+/// trigger, measuring how quickly the consumer keeps up.
+#[derive(Default)]
+pub struct FetchLatency {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// When the oldest unanswered signal went out.
+    signalled: Option<Instant>,
+    latency: Option<Duration>,
+    fetches: u64,
+}
+
+impl FetchLatency {
+    pub fn signalled(&self, at: Instant) {
+        self.state.lock().unwrap().signalled.get_or_insert(at);
+    }
+
+    /// Records a fetch; one that no signal asked for only counts towards
+    /// [`FetchLatency::fetches`].
+    pub fn fetched(&self, at: Instant) {
+        let mut state = self.state.lock().unwrap();
+        state.fetches += 1;
+        let Some(sent) = state.signalled.take() else {
+            return;
+        };
+        let sample = at.saturating_duration_since(sent);
+        let latency = match state.latency {
+            Some(avg) => avg.mul_f64(1.0 - SMOOTHING) + sample.mul_f64(SMOOTHING),
+            None => sample,
+        };
+        trace!(?sample, ?latency, "frame fetched");
+        state.latency = Some(latency);
+    }
+
+    /// Whether the consumer is ready for another signal.
+    pub fn ready(&self, now: Instant) -> bool {
+        match self.state.lock().unwrap().signalled {
+            Some(sent) => now.saturating_duration_since(sent) >= FETCH_TIMEOUT,
+            None => true,
+        }
+    }
+
+    /// Fetches recorded so far.
+    pub fn fetches(&self) -> u64 {
+        self.state.lock().unwrap().fetches
+    }
+
+    /// Smoothed signal-to-fetch latency, once a fetch has been seen.
+    pub fn latency(&self) -> Option<Duration> {
+        self.state.lock().unwrap().latency
+    }
+
+    /// The rate the consumer sustains, `fps` capped by the measured latency.
+    pub fn effective_fps(&self, fps: f32) -> f32 {
+        match self.latency() {
+            Some(l) if !l.is_zero() => fps.min(1.0 / l.as_secs_f32()),
+            _ => fps,
+        }
+    }
+}
+
+/// Holds a signal target back until Hyprlock fetched the frame for the
+/// previous signal.
+pub struct AdaptiveTarget {
+    pub inner: Box<dyn Target>,
+    pub fetches: Arc<FetchLatency>,
+}
+
+impl Target for AdaptiveTarget {
+    fn describe(&self) -> String {
+        format!("{} (adaptive)", self.inner.describe())
+    }
+
+    fn pids(&self) -> Vec<u32> {
+        self.inner.pids()
+    }
+
+    fn fire(&mut self, tick: &Tick) -> io::Result<Outcome> {
+        let now = Instant::now();
+        if !self.fetches.ready(now) {
+            trace!(target = %self.inner.describe(), "previous frame not fetched; skipping");
+            return Ok(Outcome::Skipped);
+        }
+        let outcome = self.inner.fire(tick)?;
+        // With no process to signal, no fetch will answer.
+        if outcome == Outcome::Fired {
+            self.fetches.signalled(now);
+        }
+        Ok(outcome)
+    }
+}
@@ -0,0 +1,184 @@
+use image::DynamicImage;
+#[cfg(feature = "camera")]
+use nokhwa::{
+    pixel_format::RgbFormat,
+    utils::{CameraFormat, CameraIndex, FrameFormat, RequestedFormat, RequestedFormatType},
+    Camera,
+};
+use std::path::{Path, PathBuf};
+use tracing::{debug, error, info_span};
+
+/// Produces the frames analysed by the AI worker.
+pub trait FrameSource {
+    /// Returns the next frame, or `None` when capturing failed.
+    fn next_frame(&mut self) -> Option<DynamicImage>;
+
+    /// Frees the underlying device while no frames are needed; the next
+    /// `next_frame` call reacquires it.
+    fn release(&mut self) {}
+
+    /// Whether the source currently holds a camera device.
+    fn is_open(&self) -> bool {
+        false
+    }
+}
+
+/// Live frames from the first camera.
+#[cfg(feature = "camera")]
+pub struct CameraSource {
+    cam: Option<Camera>,
+}
+
+#[cfg(feature = "camera")]
+impl CameraSource {
+    pub fn open() -> Option<Self> {
+        open_camera().map(|cam| Self { cam: Some(cam) })
+    }
+}
+
+#[cfg(feature = "camera")]
+impl FrameSource for CameraSource {
+    fn next_frame(&mut self) -> Option<DynamicImage> {
+        let cam = match &mut self.cam {
+            Some(cam) => cam,
+            None => self.cam.insert(open_camera()?),
+        };
+        let frame = match info_span!("grab").in_scope(|| cam.frame()) {
+            Ok(f) => f,
+            Err(e) => {
+                error!("failed to capture frame: {e}");
+                return None;
+            }
+        };
+        match info_span!("decode").in_scope(|| frame.decode_image::<RgbFormat>()) {
+            Ok(i) => Some(DynamicImage::ImageRgb8(i)),
+            Err(e) => {
+                error!("failed to decode frame: {e}");
+                None
+            }
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.cam.is_some()
+    }
+
+    fn release(&mut self) {
+        if let Some(mut cam) = self.cam.take() {
+            if let Err(e) = cam.stop_stream() {
+                debug!("failed to stop camera stream: {e}");
+            }
+            debug!("camera released");
+        }
+    }
+}
+
+/// Replays the images of a directory in name order, looping forever.
+///
+/// Used in place of the camera when `BONGO_CAMERA_REPLAY` is set, which makes
+/// the worker testable on machines without one.
+pub struct ReplaySource {
+    frames: Vec<PathBuf>,
+    index: usize,
+}
+
+impl ReplaySource {
+    pub fn new(dir: &Path) -> Self {
+        let mut frames: Vec<PathBuf> = std::fs::read_dir(dir)
+            .map(|rd| {
+                rd.filter_map(|e| e.ok())
+                    .map(|e| e.path())
+                    .filter(|p| p.is_file())
+                    .collect()
+            })
+            .unwrap_or_default();
+        frames.sort();
+        if frames.is_empty() {
+            error!("no replay frames found in {}", dir.display());
+        }
+        debug!(dir = %dir.display(), count = frames.len(), "replaying frames");
+        Self { frames, index: 0 }
+    }
+
+    /// Returns a replay source when `BONGO_CAMERA_REPLAY` is set.
+    pub fn from_env() -> Option<Self> {
+        std::env::var_os("BONGO_CAMERA_REPLAY").map(|dir| Self::new(Path::new(&dir)))
+    }
+}
+
+impl FrameSource for ReplaySource {
+    fn next_frame(&mut self) -> Option<DynamicImage> {
+        if self.frames.is_empty() {
+            return None;
+        }
+        let path = &self.frames[self.index % self.frames.len()];
+        self.index = (self.index + 1) % self.frames.len();
+        match info_span!("decode").in_scope(|| image::open(path)) {
+            Ok(img) => Some(img),
+            Err(e) => {
+                error!("failed to read replay frame {}: {e}", path.display());
+                None
+            }
+        }
+    }
+}
+
+/// Mean Rec. 709 luminance of a frame, from 0 (black) to 1 (white).
+pub fn mean_luminance(img: &DynamicImage) -> f32 {
+    // A thumbnail is plenty for an average and keeps this cheap per frame.
+    let small = img.thumbnail(64, 64).into_rgb8();
+    let pixels = small.pixels().len();
+    if pixels == 0 {
+        return 0.0;
+    }
+    let total: f32 = small
+        .pixels()
+        .map(|p| 0.2126 * p[0] as f32 + 0.7152 * p[1] as f32 + 0.0722 * p[2] as f32)
+        .sum();
+    total / pixels as f32 / 255.0
+}
+
+#[cfg(feature = "camera")]
+fn open_camera() -> Option<Camera> {
+    let format = RequestedFormat::new::<RgbFormat>(RequestedFormatType::None);
+    let mut cam = None;
+    let mut last_err = None;
+    for (w, h) in [(1280, 720), (640, 480)] {
+        for fmt in [FrameFormat::RAWRGB, FrameFormat::MJPEG, FrameFormat::YUYV] {
+            let req = RequestedFormat::new::<RgbFormat>(RequestedFormatType::Closest(
+                CameraFormat::new_from(w, h, fmt, 30),
+            ));
+            debug!(width = w, height = h, ?fmt, "trying camera format");
+            match Camera::new(CameraIndex::Index(0), req) {
+                Ok(c) => {
+                    cam = Some(c);
+                    break;
+                }
+                Err(e) => {
+                    debug!(width = w, height = h, ?fmt, error = ?e, "camera format failed");
+                    last_err = Some(e);
+                }
+            }
+        }
+        if cam.is_some() {
+            break;
+        }
+    }
+    let mut cam = match cam.or_else(|| Camera::new(CameraIndex::Index(0), format).ok()) {
+        Some(c) => c,
+        None => {
+            if let Some(e) = last_err {
+                error!("failed to open camera: {e}");
+            } else {
+                error!("failed to open camera");
+            }
+            return None;
+        }
+    };
+    if let Err(e) = cam.open_stream() {
+        error!("failed to open camera stream: {e}");
+        return None;
+    }
+    debug!(format = ?cam.camera_format(), "camera stream opened");
+    Some(cam)
+}
@@ -1,9 +1,17 @@
 use crate::config::{load_config, save_config};
-use crate::daemon::run_daemon;
-use crate::ipc::{send_command, ControlMessage};
+use crate::daemon::{run_daemon, DaemonOptions};
+use crate::events::EventKind;
+use crate::ipc::{send_command, Client, ClientError, ControlMessage, Response};
+use crate::process::MatchRules;
+use crate::status::Status;
+use crate::systemd::{service_unit, socket_unit, user_unit_dir};
+use crate::target::TargetSpec;
+use crate::telemetry::LogOptions;
 use clap::{Parser, Subcommand};
+use std::fs;
 use std::path::PathBuf;
-use tracing::{error, info};
+use std::process::ExitCode;
+use tracing::{debug, error, info};
 
 #[derive(Parser)]
 #[command(
@@ -14,6 +22,8 @@ use tracing::{error, info};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+    #[command(flatten)]
+    pub log: LogOptions,
 }
 
 #[derive(Subcommand)]
@@ -26,9 +36,77 @@ pub enum Commands {
         /// Hyprlock process name
         #[arg(long, default_value = "hyprlock")]
         process: String,
+        #[command(flatten)]
+        rules: MatchRules,
+        /// Target to drive, as `[N:]signal:SIG[@process]`, `[N:]exec:CMD` or
+        /// `[N:]file:PATH`; fires on every N-th tick. Repeatable; replaces
+        /// the configured targets
+        #[arg(long = "target", value_name = "SPEC")]
+        targets: Vec<TargetSpec>,
+        /// Cap the rate to how fast Hyprlock fetches frames, holding a signal
+        /// back until the previous one was answered by `next-image`
+        #[arg(long)]
+        adaptive: bool,
+        /// Animate only while logind reports the session as locked, idling
+        /// completely otherwise (Linux only)
+        #[arg(long)]
+        logind: bool,
+        /// Look for the lock screen when Hyprland reports focus leaving
+        /// every window instead of polling the process list
+        #[arg(long)]
+        hyprland: bool,
+        /// Ask an already running daemon to exit and take over from it
+        #[arg(long)]
+        replace: bool,
+        /// Also accept control connections from this user; repeatable
+        #[arg(long = "allow-uid", value_name = "UID")]
+        allow_uids: Vec<u32>,
+        /// Offer the daemon as `org.bongo.Modulator` on the session bus
+        /// (Linux only)
+        #[arg(long)]
+        dbus: bool,
+        /// Serve the HTTP API on a loopback `HOST:PORT` or `unix:PATH`
+        /// (needs the `http` feature)
+        #[arg(long, value_name = "ADDR")]
+        http: Option<String>,
+        /// Let pages on this origin, e.g. `http://localhost:3000`, call the
+        /// HTTP API from a browser
+        #[arg(long, value_name = "ORIGIN")]
+        http_origin: Option<String>,
+        /// Serve Prometheus metrics on a loopback `HOST:PORT` or `unix:PATH`
+        #[arg(long, value_name = "ADDR")]
+        metrics: Option<String>,
     },
     /// Print the path to the next image
     NextImage,
+    /// Show what the running daemon is doing
+    Status {
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print the daemon's events as they happen
+    Watch {
+        /// Only these kinds of events; repeatable or comma-separated
+        #[arg(long, value_enum, value_delimiter = ',')]
+        only: Vec<EventKind>,
+        /// Print each event as a JSON line
+        #[arg(long)]
+        json: bool,
+    },
+    /// Write systemd user units starting the daemon through its socket
+    InstallService {
+        /// Directory to write the units to; defaults to the systemd user
+        /// unit directory
+        #[arg(long)]
+        dir: Option<PathBuf>,
+        /// Binary the service runs; defaults to this executable
+        #[arg(long)]
+        exe: Option<PathBuf>,
+        /// Overwrite existing units
+        #[arg(long)]
+        force: bool,
+    },
     /// Configure operation mode
     Mode {
         #[command(subcommand)]
@@ -36,10 +114,36 @@ pub enum Commands {
     },
 }
 
+impl Commands {
+    /// The log filter without `--log-level` or `RUST_LOG`. Client commands
+    /// stay quiet; `next-image` runs for every frame Hyprlock shows and
+    /// reports failure through its exit status alone.
+    pub fn default_log_level(&self) -> &'static str {
+        match self {
+            Commands::Daemon { .. } => "info",
+            Commands::NextImage => "off",
+            _ => "error",
+        }
+    }
+}
+
 #[derive(Subcommand)]
 pub enum ModeSubcommand {
-    /// Enable AI mode (stub)
-    Ai,
+    /// Enable AI mode (requires the `camera` feature)
+    Ai {
+        /// Detection model to switch to, a local path or `repo:file`
+        #[arg(long)]
+        model: Option<String>,
+        /// Face model enabling the face backend, a local path or `repo:file`
+        #[arg(long)]
+        face_model: Option<String>,
+        /// Pose model enabling drumming detection, a local path or `repo:file`
+        #[arg(long)]
+        pose_model: Option<String>,
+        /// Cap the FPS while the camera image is darker than this luminance (0-1)
+        #[arg(long, value_parser = clap::value_parser!(f32))]
+        dark_threshold: Option<f32>,
+    },
     /// Set manual FPS
     Fps {
         #[arg(value_parser = clap::value_parser!(f32))]
@@ -47,51 +151,287 @@ pub enum ModeSubcommand {
     },
 }
 
-pub fn run_cli() {
-    tracing_subscriber::fmt::init();
+pub fn run_cli() -> ExitCode {
     let cli = Cli::parse();
-    execute(cli);
+    // Logging may start threads, after which the environment must not change.
+    if matches!(cli.command, Commands::Daemon { .. }) {
+        crate::systemd::claim_environment();
+    }
+    let telemetry = crate::telemetry::init(&cli.log, cli.command.default_log_level());
+    let code = execute(cli);
+    telemetry.shutdown();
+    code
 }
 
-pub fn execute(cli: Cli) {
+/// Runs a command. Settings saved while the daemon is not running are not
+/// a failure; it picks them up from the config on start.
+pub fn execute(cli: Cli) -> ExitCode {
     match cli.command {
-        Commands::Daemon { dir, process } => run_daemon(dir, process),
+        Commands::Daemon {
+            dir,
+            process,
+            rules,
+            targets,
+            adaptive,
+            logind,
+            hyprland,
+            replace,
+            allow_uids,
+            dbus,
+            http,
+            http_origin,
+            metrics,
+        } => run_daemon(DaemonOptions {
+            dir,
+            process,
+            rules,
+            targets,
+            adaptive,
+            logind,
+            hyprland,
+            replace,
+            allow_uids,
+            dbus,
+            http,
+            http_origin,
+            metrics,
+        }),
         Commands::NextImage => next_image(),
+        Commands::Status { json } => status(json),
+        Commands::Watch { only, json } => watch(only, json),
+        Commands::InstallService { dir, exe, force } => install_service(dir, exe, force),
         Commands::Mode { mode } => match mode {
-            ModeSubcommand::Ai => enable_ai(),
+            ModeSubcommand::Ai {
+                model,
+                face_model,
+                pose_model,
+                dark_threshold,
+            } => enable_ai(model, face_model, pose_model, dark_threshold),
             ModeSubcommand::Fps { fps } => set_fps(fps),
         },
     }
 }
 
+/// Asks the daemon for the next frame.
+pub fn fetch_next_image() -> Result<PathBuf, ClientError> {
+    match send_command(ControlMessage::NextImage)? {
+        Response::Frame(path) => Ok(path),
+        other => Err(ClientError::Protocol(format!(
+            "unexpected answer {other:?}"
+        ))),
+    }
+}
+
 pub fn next_image_path() -> Option<PathBuf> {
-    match send_command(ControlMessage::NextImage) {
-        Ok(Some(p)) if !p.is_empty() => Some(PathBuf::from(p)),
-        _ => None,
+    fetch_next_image().ok()
+}
+
+/// Asks the daemon what it is doing.
+pub fn fetch_status() -> Result<Status, ClientError> {
+    match send_command(ControlMessage::Status)? {
+        Response::Status(status) => Ok(*status),
+        other => Err(ClientError::Protocol(format!(
+            "unexpected answer {other:?}"
+        ))),
+    }
+}
+
+fn status(json: bool) -> ExitCode {
+    let status = match fetch_status() {
+        Ok(s) => s,
+        Err(e) => {
+            error!("failed to query the daemon: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if json {
+        match serde_json::to_string_pretty(&status) {
+            Ok(s) => println!("{s}"),
+            Err(e) => {
+                error!("failed to encode status: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        print!("{status}");
+    }
+    ExitCode::SUCCESS
+}
+
+fn watch(only: Vec<EventKind>, json: bool) -> ExitCode {
+    let events = match Client::connect().and_then(|c| c.subscribe(only)) {
+        Ok(events) => events,
+        Err(e) => {
+            error!("failed to subscribe to events: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for event in events {
+        let event = match event {
+            Ok(event) => event,
+            Err(e) => {
+                error!("event stream failed: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+        if json {
+            match serde_json::to_string(&event) {
+                Ok(line) => println!("{line}"),
+                Err(e) => error!("failed to encode event: {e}"),
+            }
+        } else {
+            println!("{event}");
+        }
+    }
+    info!("daemon closed the event stream");
+    ExitCode::SUCCESS
+}
+
+fn next_image() -> ExitCode {
+    match fetch_next_image() {
+        Ok(path) => {
+            println!("{}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            error!("daemon did not return an image: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Sends a setting to the daemon. A daemon that is not running is not an
+/// error; it picks the setting up from the config on start.
+fn apply(msg: ControlMessage) -> Result<(), ClientError> {
+    match send_command(msg) {
+        Ok(_) => Ok(()),
+        Err(ClientError::Unreachable(e)) => {
+            debug!("daemon unreachable: {e}");
+            Ok(())
+        }
+        Err(e) => Err(e),
     }
 }
 
-fn next_image() {
-    match next_image_path() {
-        Some(path) => println!("{}", path.display()),
-        None => error!("daemon did not return an image"),
+fn install_service(dir: Option<PathBuf>, exe: Option<PathBuf>, force: bool) -> ExitCode {
+    let Some(dir) = dir.or_else(user_unit_dir) else {
+        error!("cannot find the systemd user unit directory; pass --dir");
+        return ExitCode::FAILURE;
+    };
+    let exe = match exe.map_or_else(std::env::current_exe, Ok) {
+        Ok(exe) => exe,
+        Err(e) => {
+            error!("cannot locate this executable: {e}; pass --exe");
+            return ExitCode::FAILURE;
+        }
+    };
+    let units = [
+        ("bongo-modulator.service", service_unit(&exe)),
+        ("bongo-modulator.socket", socket_unit()),
+    ];
+    if !force {
+        for (name, _) in &units {
+            let path = dir.join(name);
+            if path.exists() {
+                error!(path = %path.display(), "unit already exists; pass --force to overwrite");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if let Err(e) = fs::create_dir_all(&dir) {
+        error!(dir = %dir.display(), "failed to create unit directory: {e}");
+        return ExitCode::FAILURE;
     }
+    for (name, unit) in &units {
+        let path = dir.join(name);
+        if let Err(e) = fs::write(&path, unit) {
+            error!(path = %path.display(), "failed to write unit: {e}");
+            return ExitCode::FAILURE;
+        }
+        info!(path = %path.display(), "unit written");
+    }
+    println!(
+        "systemctl --user daemon-reload && systemctl --user enable --now bongo-modulator.socket"
+    );
+    ExitCode::SUCCESS
 }
 
-fn enable_ai() {
+fn enable_ai(
+    model: Option<String>,
+    face_model: Option<String>,
+    pose_model: Option<String>,
+    dark_threshold: Option<f32>,
+) -> ExitCode {
+    if !cfg!(feature = "camera") {
+        error!(
+            "AI mode is not available in this build; rebuild with `--features camera,hub` \
+             to enable camera-based detection"
+        );
+        return ExitCode::FAILURE;
+    }
     let mut cfg = load_config();
+    if let Some(spec) = model {
+        match apply(ControlMessage::SetModel(spec.clone())) {
+            Ok(()) => info!(model = %spec, "detection model switched"),
+            Err(e) => {
+                error!("failed to switch model: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+        cfg.model = Some(spec);
+    }
+    if let Some(spec) = face_model {
+        match apply(ControlMessage::SetFaceModel(spec.clone())) {
+            Ok(()) => info!(model = %spec, "face model switched"),
+            Err(e) => {
+                error!("failed to switch face model: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+        cfg.face_model = Some(spec);
+    }
+    if let Some(spec) = pose_model {
+        match apply(ControlMessage::SetPoseModel(spec.clone())) {
+            Ok(()) => info!(model = %spec, "pose model switched"),
+            Err(e) => {
+                error!("failed to switch pose model: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+        cfg.pose_model = Some(spec);
+    }
+    if let Some(threshold) = dark_threshold {
+        let threshold = threshold.clamp(0.0, 1.0);
+        if let Err(e) = apply(ControlMessage::SetDarkThreshold(Some(threshold))) {
+            error!("failed to set the dark threshold: {e}");
+            return ExitCode::FAILURE;
+        }
+        cfg.dark_threshold = Some(threshold);
+    }
+    if let Err(e) = apply(ControlMessage::EnableAi) {
+        error!("failed to enable AI mode: {e}");
+        return ExitCode::FAILURE;
+    }
     cfg.ai_mode = true;
     save_config(&cfg);
-    let _ = send_command(ControlMessage::EnableAi);
-    info!("AI mode enabled (stub)");
+    info!("AI mode enabled");
+    ExitCode::SUCCESS
 }
 
-fn set_fps(fps: f32) {
-    let mut cfg = load_config();
+fn set_fps(fps: f32) -> ExitCode {
+    if !fps.is_finite() {
+        error!("invalid fps {fps}");
+        return ExitCode::FAILURE;
+    }
     let fps = fps.clamp(0.5, 30.0);
+    if let Err(e) = apply(ControlMessage::SetFps(fps)) {
+        error!("failed to set fps: {e}");
+        return ExitCode::FAILURE;
+    }
+    let mut cfg = load_config();
     cfg.fps = fps;
     cfg.ai_mode = false;
     save_config(&cfg);
-    let _ = send_command(ControlMessage::SetFps(fps));
     info!("manual fps set to {fps}");
+    ExitCode::SUCCESS
 }
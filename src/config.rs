@@ -1,11 +1,59 @@
+use crate::process::MatchRules;
+use crate::target::TargetSpec;
 use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
 use std::{env, fs, path::PathBuf};
 use tracing::error;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Config {
     pub fps: f32,
     pub ai_mode: bool,
+    /// Detection model spec, a local path or `repo:file` on the hub.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Face model spec; enables the face backend when set.
+    #[serde(default)]
+    pub face_model: Option<String>,
+    /// Pose model spec; enables drumming detection when set.
+    #[serde(default)]
+    pub pose_model: Option<String>,
+    /// Luminance below which AI mode caps the FPS; enables the brightness
+    /// signal when set.
+    #[serde(default)]
+    pub dark_threshold: Option<f32>,
+    /// Extra rules for picking the processes to signal.
+    #[serde(default)]
+    pub target: MatchRules,
+    /// What to drive on every tick; SIGUSR2 to the target processes when
+    /// empty.
+    #[serde(default)]
+    pub targets: Vec<TargetSpec>,
+    /// Hold signals to Hyprlock back until it fetched the previous frame.
+    #[serde(default)]
+    pub adaptive: bool,
+    /// Follow the logind session's lock state instead of idling only when
+    /// no target process exists.
+    #[serde(default)]
+    pub logind: bool,
+    /// Look for the lock screen on Hyprland events instead of polling.
+    #[serde(default)]
+    pub hyprland: bool,
+    /// Users besides the daemon's own allowed to use the control socket.
+    #[serde(default)]
+    pub allowed_uids: Vec<u32>,
+    /// Offer the daemon as `org.bongo.Modulator` on the session bus.
+    #[serde(default)]
+    pub dbus: bool,
+    /// Where to serve the HTTP API, as for `--http`.
+    #[serde(default)]
+    pub http: Option<String>,
+    /// The origin allowed to call the HTTP API, as for `--http-origin`.
+    #[serde(default)]
+    pub http_origin: Option<String>,
+    /// Where to serve Prometheus metrics, as for `--metrics`.
+    #[serde(default)]
+    pub metrics: Option<String>,
 }
 
 impl Default for Config {
@@ -13,6 +61,20 @@ impl Default for Config {
         Self {
             fps: 5.0,
             ai_mode: false,
+            model: None,
+            face_model: None,
+            pose_model: None,
+            dark_threshold: None,
+            target: MatchRules::default(),
+            targets: Vec::new(),
+            adaptive: false,
+            logind: false,
+            hyprland: false,
+            allowed_uids: Vec::new(),
+            dbus: false,
+            http: None,
+            http_origin: None,
+            metrics: None,
         }
     }
 }
@@ -33,6 +95,11 @@ pub fn load_config() -> Config {
     Config::default()
 }
 
+/// When `state.json` last changed, if it exists.
+pub fn config_modified() -> Option<SystemTime> {
+    fs::metadata(config_path()).and_then(|m| m.modified()).ok()
+}
+
 pub fn save_config(cfg: &Config) {
     let path = config_path();
     if let Some(parent) = path.parent() {
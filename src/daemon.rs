@@ -1,144 +1,1099 @@
-use crate::ai::spawn_ai_thread;
-use crate::config::load_config;
+#[cfg(feature = "camera")]
+use crate::ai::{spawn_ai_thread, Worker};
+use crate::backpressure::{AdaptiveTarget, FetchLatency};
+use crate::config::{config_modified, load_config, save_config, Config};
+use crate::events::{Event, EventBus, EventKind, SUBSCRIBER_BUFFER};
 use crate::frame::{image_dir, FrameCache};
-use crate::ipc::ControlMessage;
+use crate::hyprland::Hyprland;
+use crate::instance::{pid_path, InstanceLock, LockError, REPLACE_TIMEOUT};
+use crate::ipc::{
+    encode_legacy, encode_response, fallback_dir, parse_request, ControlMessage, ErrorKind,
+    Incoming, IpcError, Response, PROTOCOL_VERSION,
+};
+use crate::listen::Bind;
+use crate::metrics::{Metrics, MetricsServer};
+use crate::peer::AllowList;
+use crate::policy::FpsPolicy;
+use crate::process::MatchRules;
+use crate::scheduler::{Rate, Scheduler, SystemClock, TickStats};
+use crate::server::{serve, Connection, Limits, ReadError};
+use crate::session::LockState;
+use crate::status::{
+    millis, AiHealth, Drift, FetchStatus, FrameStatus, Mode, Status, TargetStatus, TickStatus,
+    WorkerState,
+};
+use crate::systemd::{listen_fd, Notifier};
+use crate::target::{ScheduledTarget, TargetKind, TargetSpec};
+use serde_json::Value;
+use signal_hook::consts::{SIGINT, SIGTERM};
+use signal_hook::iterator::Signals;
 use std::collections::HashMap;
 use std::io::Write;
-use std::os::unix::net::UnixListener;
-use std::path::PathBuf;
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, MetadataExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::mpsc::RecvTimeoutError;
 use std::sync::{
-    atomic::{AtomicBool, AtomicU32, Ordering},
+    atomic::{AtomicBool, Ordering},
     Arc, Mutex,
 };
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime};
 use std::{env, fs};
-use sysinfo::{Pid, ProcessesToUpdate, Signal, System};
-use tracing::{debug, error, info, trace};
+use tracing::{debug, error, info, info_span, trace, warn};
 
-fn wait_for_process(name: &str, sys: &mut System) -> Vec<Pid> {
-    loop {
-        sys.refresh_processes(ProcessesToUpdate::All, true);
-        let pids: Vec<Pid> = sys
-            .processes_by_name(std::ffi::OsStr::new(name))
-            .map(|p| p.pid())
+/// How often an idle event stream checks whether its subscriber left.
+const HANGUP_POLL: Duration = Duration::from_secs(1);
+
+/// Where the answer to a request goes. Model swaps answer from their own
+/// thread, after the connection may have moved on to other requests.
+#[derive(Clone)]
+struct Responder {
+    stream: Arc<Mutex<UnixStream>>,
+    to: Addressee,
+}
+
+#[derive(Clone)]
+enum Addressee {
+    Versioned(u64),
+    /// Clients predating the envelope want the old raw reply to this message.
+    Legacy(ControlMessage),
+}
+
+impl Responder {
+    fn send(&self, response: Response) {
+        if let Err(e) = self.try_send(response) {
+            debug!("failed to answer request: {e}");
+        }
+    }
+
+    fn try_send(&self, response: Response) -> std::io::Result<()> {
+        let data = match &self.to {
+            Addressee::Versioned(id) => encode_response(*id, response),
+            Addressee::Legacy(msg) => encode_legacy(msg, &response),
+        };
+        self.stream.lock().unwrap().write_all(&data)
+    }
+}
+
+#[cfg(feature = "ai")]
+fn model_response(spec: &str, result: Result<(), String>) -> Response {
+    match result {
+        Ok(()) => Response::Ok,
+        Err(e) => {
+            error!(spec, "model swap failed: {e}");
+            Response::Error(IpcError::new(ErrorKind::ModelRejected, e))
+        }
+    }
+}
+
+/// Loads a model off the IPC thread, since downloads and validation can take
+/// a while, and replies once it is active or rejected.
+#[cfg(feature = "ai")]
+fn spawn_model_swap(handle: crate::ai::ModelHandle, spec: String, responder: Responder) {
+    std::thread::spawn(move || {
+        let result = handle.load(&spec);
+        responder.send(model_response(&spec, result));
+    });
+}
+
+fn model_unsupported(spec: &str) -> Response {
+    error!(spec, "cannot load a model: built without the `ai` feature");
+    Response::Error(IpcError::new(
+        ErrorKind::Unsupported,
+        format!("cannot load {spec}: built without the `ai` feature"),
+    ))
+}
+
+/// What the main loop last published about its targets and ticks.
+#[derive(Default)]
+struct Progress {
+    targets: Vec<TargetStatus>,
+    ticks: TickStats,
+}
+
+impl Progress {
+    /// Records the targets' state, announcing processes that appeared or
+    /// vanished since the last call.
+    fn publish(&mut self, targets: &[ScheduledTarget], ticks: TickStats, events: &EventBus) {
+        let targets: Vec<_> = targets
+            .iter()
+            .map(|t| TargetStatus {
+                target: t.target.describe(),
+                divisor: t.divisor,
+                pids: t.target.pids(),
+                fired: t.fired,
+                failed: t.failed,
+            })
             .collect();
-        if !pids.is_empty() {
-            return pids;
+        for (i, now) in targets.iter().enumerate() {
+            let before = self.targets.get(i).map_or(&[][..], |t| &t.pids[..]);
+            for &pid in before.iter().filter(|p| !now.pids.contains(p)) {
+                events.publish(Event::TargetVanished {
+                    target: now.target.clone(),
+                    pid,
+                });
+            }
+            for &pid in now.pids.iter().filter(|p| !before.contains(p)) {
+                events.publish(Event::TargetAppeared {
+                    target: now.target.clone(),
+                    pid,
+                });
+            }
+        }
+        self.targets = targets;
+        self.ticks = ticks;
+    }
+}
+
+/// Settings whose value in `cfg` differs from the running daemon's. In AI
+/// mode the rate is computed, so only the mode itself is compared.
+fn drift(cfg: &Config, fps: f32, ai_mode: bool, dark_threshold: Option<f32>) -> Vec<Drift> {
+    let mut drift = Vec::new();
+    let mut compare = |setting: &str, config: String, daemon: String| {
+        if config != daemon {
+            drift.push(Drift {
+                setting: setting.into(),
+                config,
+                daemon,
+            });
         }
-        info!(proc = %name, "process not found; waiting");
-        std::thread::sleep(Duration::from_secs(1));
+    };
+    compare("ai_mode", cfg.ai_mode.to_string(), ai_mode.to_string());
+    if !ai_mode && !cfg.ai_mode {
+        compare("fps", cfg.fps.to_string(), fps.to_string());
     }
+    compare(
+        "dark_threshold",
+        format!("{:?}", cfg.dark_threshold),
+        format!("{dark_threshold:?}"),
+    );
+    drift
+}
+
+/// `state.json` as last read. Status requests compare against it, and it is
+/// only parsed again once the file changes.
+struct SavedConfig {
+    modified: Option<SystemTime>,
+    config: Config,
+}
+
+impl SavedConfig {
+    fn new(config: Config, modified: Option<SystemTime>) -> Self {
+        Self { modified, config }
+    }
+
+    fn load() -> Self {
+        let modified = config_modified();
+        Self::new(load_config(), modified)
+    }
+
+    /// The saved settings, read again if the file changed since.
+    fn current(&mut self) -> &Config {
+        if config_modified() != self.modified {
+            *self = Self::load();
+        }
+        &self.config
+    }
+}
+
+/// The daemon state control requests act on.
+#[derive(Clone)]
+struct Control {
+    fps: Arc<Rate>,
+    ai_mode: Arc<AtomicBool>,
+    policy: Arc<Mutex<FpsPolicy>>,
+    caches: Arc<Mutex<HashMap<PathBuf, FrameCache>>>,
+    fetches: Arc<FetchLatency>,
+    #[cfg(feature = "ai")]
+    models: crate::ai::Models,
+    session: Arc<LockState>,
+    health: Arc<AiHealth>,
+    progress: Arc<Mutex<Progress>>,
+    events: Arc<EventBus>,
+    metrics: Arc<Metrics>,
+    saved: Arc<Mutex<SavedConfig>>,
+    started: Instant,
 }
 
-pub fn run_daemon(dir: Option<PathBuf>, process: String) {
+impl Control {
+    /// What the daemon is doing, including drift from `state.json`.
+    fn status(&self) -> Status {
+        let mut status = self.snapshot();
+        let dark_threshold = self.policy.lock().unwrap().dark_threshold;
+        status.drift = drift(
+            self.saved.lock().unwrap().current(),
+            status.fps,
+            status.mode == Mode::Ai,
+            dark_threshold,
+        );
+        status
+    }
+
+    /// What the daemon is doing, from memory alone.
+    fn snapshot(&self) -> Status {
+        let fps = self.fps.get();
+        let ai_mode = self.ai_mode.load(Ordering::Relaxed);
+        let dir = image_dir();
+        let frames = {
+            let mut caches = self.caches.lock().unwrap();
+            let cache = caches
+                .entry(dir.clone())
+                .or_insert_with(|| FrameCache::new(&dir));
+            FrameStatus {
+                count: cache.len(),
+                index: cache.index(),
+                current: cache.current().map(Path::to_path_buf),
+                dir,
+            }
+        };
+        let (targets, ticks) = {
+            let progress = self.progress.lock().unwrap();
+            (progress.targets.clone(), progress.ticks)
+        };
+        Status {
+            version: env!("CARGO_PKG_VERSION").into(),
+            pid: std::process::id(),
+            uptime_secs: self.started.elapsed().as_secs_f64(),
+            fps,
+            mode: if ai_mode { Mode::Ai } else { Mode::Manual },
+            locked: self.session.is_locked(),
+            ai: self.health.snapshot(),
+            frames,
+            targets,
+            ticks: TickStatus {
+                ticks: ticks.ticks,
+                missed: ticks.missed,
+                mean_jitter_ms: millis(ticks.mean_jitter()),
+                max_jitter_ms: millis(ticks.max_jitter),
+            },
+            fetches: FetchStatus {
+                fetches: self.fetches.fetches(),
+                latency_ms: self.fetches.latency().map(millis),
+                effective_fps: self
+                    .fetches
+                    .latency()
+                    .map(|_| self.fetches.effective_fps(fps)),
+            },
+            drift: Vec::new(),
+        }
+    }
+
+    /// Answers `msg` on `responder`. Model swaps can take a while and answer
+    /// from their own thread.
+    fn handle(&self, msg: ControlMessage, responder: Responder) {
+        let _span = info_span!("ipc_request", request = ?msg).entered();
+        debug!(?msg, "received message");
+        #[cfg(feature = "ai")]
+        if let Some((handle, spec)) = self.model_slot(&msg) {
+            spawn_model_swap(handle, spec, responder);
+            return;
+        }
+        responder.send(self.answer(msg));
+    }
+
+    /// The model a swap request targets, and the spec to load into it.
+    #[cfg(feature = "ai")]
+    fn model_slot(&self, msg: &ControlMessage) -> Option<(crate::ai::ModelHandle, String)> {
+        match msg {
+            ControlMessage::SetModel(spec) => Some((self.models.objects.clone(), spec.clone())),
+            ControlMessage::SetFaceModel(spec) => Some((self.models.faces.clone(), spec.clone())),
+            ControlMessage::SetPoseModel(spec) => Some((self.models.pose.clone(), spec.clone())),
+            _ => None,
+        }
+    }
+
+    fn set_manual(&self, fps: f32) {
+        let fps = fps.clamp(0.5, 30.0);
+        debug!(fps, "updating fps and disabling AI");
+        if self.ai_mode.swap(false, Ordering::Relaxed) {
+            self.events.publish(Event::Mode(Mode::Manual));
+        }
+        self.fps.set(fps);
+        self.events.publish(Event::Fps(fps));
+    }
+
+    fn enable_ai(&self) {
+        debug!("enabling AI mode");
+        if !self.ai_mode.swap(true, Ordering::Relaxed) {
+            self.events.publish(Event::Mode(Mode::Ai));
+        }
+    }
+
+    /// Carries out `msg` and returns the answer; shared by the socket and
+    /// the D-Bus interface.
+    fn answer(&self, msg: ControlMessage) -> Response {
+        #[cfg(feature = "ai")]
+        if let Some((handle, spec)) = self.model_slot(&msg) {
+            let result = handle.load(&spec);
+            return model_response(&spec, result);
+        }
+        match msg {
+            ControlMessage::Hello => Response::Hello {
+                protocol: PROTOCOL_VERSION,
+                daemon: env!("CARGO_PKG_VERSION").into(),
+            },
+            ControlMessage::SetFps(v) if !v.is_finite() => Response::Error(IpcError::new(
+                ErrorKind::BadRequest,
+                format!("invalid fps {v}"),
+            )),
+            ControlMessage::SetFps(v) => {
+                self.set_manual(v);
+                Response::Ok
+            }
+            ControlMessage::EnableAi => {
+                if cfg!(feature = "camera") {
+                    self.enable_ai();
+                    Response::Ok
+                } else {
+                    error!("cannot enable AI mode: built without the `camera` feature");
+                    Response::Error(IpcError::new(
+                        ErrorKind::Unsupported,
+                        "cannot enable AI mode: built without the `camera` feature",
+                    ))
+                }
+            }
+            ControlMessage::NextImage => {
+                trace!("next image requested");
+                let requested = Instant::now();
+                self.fetches.fetched(requested);
+                let dir = image_dir();
+                let frame = {
+                    let mut caches = self.caches.lock().unwrap();
+                    let cache = caches
+                        .entry(dir.clone())
+                        .or_insert_with(|| FrameCache::new(&dir));
+                    let index = cache.index();
+                    cache.next_frame().map(|path| (path, index))
+                };
+                self.metrics.next_image(requested.elapsed());
+                match frame {
+                    Some((path, index)) => {
+                        trace!(path = %path.display(), "sending frame path");
+                        self.events.publish(Event::Frame {
+                            path: path.clone(),
+                            index,
+                        });
+                        Response::Frame(path)
+                    }
+                    None => Response::Error(IpcError::new(
+                        ErrorKind::NoFrames,
+                        format!("no frames in {}", dir.display()),
+                    )),
+                }
+            }
+            ControlMessage::SetDarkThreshold(Some(threshold)) if !threshold.is_finite() => {
+                Response::Error(IpcError::new(
+                    ErrorKind::BadRequest,
+                    format!("invalid dark threshold {threshold}"),
+                ))
+            }
+            ControlMessage::SetDarkThreshold(threshold) => {
+                debug!(?threshold, "updating dark threshold");
+                self.policy.lock().unwrap().dark_threshold = threshold;
+                Response::Ok
+            }
+            ControlMessage::Reload => {
+                let cfg = {
+                    let mut saved = self.saved.lock().unwrap();
+                    *saved = SavedConfig::load();
+                    saved.config.clone()
+                };
+                info!("reloading state.json");
+                self.policy.lock().unwrap().dark_threshold = cfg.dark_threshold;
+                self.caches.lock().unwrap().clear();
+                if cfg.ai_mode && cfg!(feature = "camera") {
+                    self.enable_ai();
+                } else {
+                    if cfg.ai_mode {
+                        warn!("state.json enables AI mode, but built without the `camera` feature");
+                    }
+                    self.set_manual(cfg.fps);
+                }
+                Response::Ok
+            }
+            ControlMessage::Status => Response::Status(Box::new(self.status())),
+            ControlMessage::Subscribe(_) => Response::Error(IpcError::new(
+                ErrorKind::BadRequest,
+                "subscribing needs a versioned connection",
+            )),
+            // Only reached without the `ai` feature; see `model_slot`.
+            ControlMessage::SetModel(spec)
+            | ControlMessage::SetFaceModel(spec)
+            | ControlMessage::SetPoseModel(spec) => model_unsupported(&spec),
+        }
+    }
+
+    /// Streams events to a subscriber until it hangs up, stops taking them
+    /// or the daemon stops. Events it is too slow for are dropped by the
+    /// bus, so it never holds up the daemon.
+    fn stream_events(&self, conn: &mut Connection, responder: Responder, kinds: Vec<EventKind>) {
+        debug!(?kinds, "client subscribed");
+        let events = self.events.subscribe(kinds, SUBSCRIBER_BUFFER);
+        responder.send(Response::Ok);
+        loop {
+            match events.recv_timeout(HANGUP_POLL) {
+                Ok(event) => {
+                    if let Err(e) = responder.try_send(Response::Event(event)) {
+                        debug!("dropping subscriber: {e}");
+                        return;
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    if self.session.is_closed() || conn.hung_up() {
+                        debug!("subscriber left");
+                        return;
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+    }
+
+    /// Answers the requests on one connection until the client hangs up,
+    /// stalls or oversteps a limit. A legacy client gets a single answer,
+    /// after which the connection closes.
+    fn serve(&self, mut conn: Connection) {
+        let stream = match conn.writer() {
+            Ok(s) => Arc::new(Mutex::new(s)),
+            Err(e) => {
+                error!("failed to set up connection: {e}");
+                return;
+            }
+        };
+        let responder = |to| Responder {
+            stream: stream.clone(),
+            to,
+        };
+        loop {
+            let data = match conn.read_message() {
+                Ok(Some(data)) => data,
+                Ok(None) => return,
+                Err(ReadError::TooLarge(max)) => {
+                    warn!(max, "request too large; closing connection");
+                    let error = IpcError::new(
+                        ErrorKind::BadRequest,
+                        format!("request exceeds {max} bytes"),
+                    );
+                    responder(Addressee::Versioned(0)).send(Response::Error(error));
+                    return;
+                }
+                Err(ReadError::Io(e)) => {
+                    debug!("closing connection: {e}");
+                    return;
+                }
+            };
+            let parsed = serde_json::from_slice::<Value>(&data)
+                .map_err(|e| (0, IpcError::new(ErrorKind::BadRequest, e.to_string())))
+                .and_then(parse_request);
+            match parsed {
+                Ok(Incoming::Versioned {
+                    id,
+                    message: ControlMessage::Subscribe(kinds),
+                }) => {
+                    if let Err(busy) = conn.subscribe() {
+                        responder(Addressee::Versioned(id)).send(Response::Error(busy));
+                        continue;
+                    }
+                    self.stream_events(&mut conn, responder(Addressee::Versioned(id)), kinds);
+                    return;
+                }
+                Ok(Incoming::Versioned { id, message }) => {
+                    self.handle(message, responder(Addressee::Versioned(id)));
+                }
+                Ok(Incoming::Legacy(message)) => {
+                    self.handle(message.clone(), responder(Addressee::Legacy(message)));
+                    return;
+                }
+                Err((id, error)) => {
+                    warn!(id, "rejected request: {error}");
+                    responder(Addressee::Versioned(id)).send(Response::Error(error));
+                }
+            }
+        }
+    }
+}
+
+/// Builds the configured targets; with `fetches`, the `--process` signal
+/// target waits for Hyprlock to fetch each frame.
+fn build_targets(
+    specs: &[TargetSpec],
+    process: &str,
+    rules: &MatchRules,
+    fetches: Option<&Arc<FetchLatency>>,
+    hyprland: Option<&Arc<Hyprland>>,
+) -> Result<Vec<ScheduledTarget>, String> {
+    let mut targets = Vec::with_capacity(specs.len());
+    for spec in specs {
+        let mut target = spec.build(process, rules, hyprland)?;
+        // Only the `--process` consumer fetches frames over the socket.
+        if let Some(fetches) = fetches {
+            if matches!(spec.kind, TargetKind::Signal { process: None, .. }) {
+                target = Box::new(AdaptiveTarget {
+                    inner: target,
+                    fetches: fetches.clone(),
+                });
+            }
+        }
+        debug!(target = %target.describe(), divisor = spec.divisor, "target configured");
+        targets.push(ScheduledTarget::new(target, spec.divisor));
+    }
+    Ok(targets)
+}
+
+#[cfg(target_os = "linux")]
+fn follow_logind(session: Arc<LockState>) -> bool {
+    let watched = zbus::blocking::Connection::system()
+        .and_then(|conn| crate::session::watch_logind(conn, session));
+    if let Err(e) = watched {
+        error!("failed to follow the logind session: {e}");
+        return false;
+    }
+    true
+}
+
+#[cfg(not(target_os = "linux"))]
+fn follow_logind(_session: Arc<LockState>) -> bool {
+    error!("--logind is only supported on Linux");
+    false
+}
+
+/// Offers `control` as `org.bongo.Modulator` on the session bus for the
+/// rest of the daemon's life.
+#[cfg(target_os = "linux")]
+fn serve_dbus(control: Control, events: &EventBus) -> bool {
+    let served = zbus::blocking::Connection::session()
+        .and_then(|conn| crate::dbus::serve(&conn, move |msg| control.answer(msg), events));
+    if let Err(e) = served {
+        error!("failed to serve on the session bus: {e}");
+        return false;
+    }
+    true
+}
+
+#[cfg(not(target_os = "linux"))]
+fn serve_dbus(_control: Control, _events: &EventBus) -> bool {
+    error!("--dbus is only supported on Linux");
+    false
+}
+
+/// Serves `control` over HTTP on `bind`, to browsers on `origin` too, until
+/// the returned server drops. The token lives next to the control socket
+/// unless `BONGO_HTTP_TOKEN` is set.
+#[cfg(feature = "http")]
+fn serve_http(
+    bind: &str,
+    origin: Option<String>,
+    sock_path: &Path,
+    control: Control,
+    events: Arc<EventBus>,
+) -> Option<crate::http::HttpServer> {
+    let bind: Bind = match bind.parse() {
+        Ok(bind) => bind,
+        Err(e) => {
+            error!("invalid --http address: {e}");
+            return None;
+        }
+    };
+    let token_path = sock_path.with_file_name("bongo-http.token");
+    let token = match crate::http::token(&token_path) {
+        Ok(token) => token,
+        Err(e) => {
+            error!(path = %token_path.display(), "failed to read the HTTP token: {e}");
+            return None;
+        }
+    };
+    match crate::http::serve(
+        &bind,
+        token,
+        origin,
+        move |msg| control.answer(msg),
+        events,
+        &Limits::default(),
+    ) {
+        Ok(server) => {
+            info!(%bind, token = %token_path.display(), "serving the HTTP API");
+            Some(server)
+        }
+        Err(e) => {
+            error!(%bind, "failed to serve the HTTP API: {e}");
+            None
+        }
+    }
+}
+
+/// Answers Prometheus scrapes on `bind` until the returned server drops.
+fn serve_metrics(bind: &str, control: Control) -> Option<MetricsServer> {
+    let bind: Bind = match bind.parse() {
+        Ok(bind) => bind,
+        Err(e) => {
+            error!("invalid --metrics address: {e}");
+            return None;
+        }
+    };
+    let render = move || control.metrics.render(&control.snapshot());
+    match crate::metrics::serve(&bind, render, &Limits::default()) {
+        Ok(server) => {
+            info!(%bind, "serving metrics");
+            Some(server)
+        }
+        Err(e) => {
+            error!(%bind, "failed to serve metrics: {e}");
+            None
+        }
+    }
+}
+
+/// Everything the main loop and its threads block on, so a shutdown can
+/// wake them all.
+#[derive(Clone)]
+struct Stopper {
+    requested: Arc<AtomicBool>,
+    fps: Arc<Rate>,
+    session: Arc<LockState>,
+    socket: PathBuf,
+}
+
+impl Stopper {
+    fn stop(&self) {
+        if self.requested.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        self.fps.close();
+        self.session.close();
+        // The IPC thread sits in accept(); a connection lets it see the flag.
+        let _ = UnixStream::connect(&self.socket);
+    }
+}
+
+/// Stops the daemon on the first SIGTERM or SIGINT; a second one exits
+/// immediately.
+fn handle_signals(stopper: Stopper) -> std::io::Result<()> {
+    let mut signals = Signals::new([SIGTERM, SIGINT])?;
+    std::thread::spawn(move || {
+        for signal in signals.forever() {
+            if stopper.requested.load(Ordering::SeqCst) {
+                warn!(signal, "second signal; exiting immediately");
+                std::process::exit(128 + signal);
+            }
+            info!(signal, "shutting down");
+            stopper.stop();
+        }
+    });
+    Ok(())
+}
+
+/// Creates the socket's directory when missing and checks the fallback one is private to us.
+fn prepare_socket_dir(sock: &Path) -> std::io::Result<()> {
+    let Some(dir) = sock.parent().filter(|d| !d.as_os_str().is_empty()) else {
+        return Ok(());
+    };
+    if !dir.exists() {
+        fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(dir)?;
+        debug!(dir = %dir.display(), "created socket directory");
+    }
+    if dir != fallback_dir() {
+        return Ok(());
+    }
+    let meta = fs::symlink_metadata(dir)?;
+    // SAFETY: geteuid cannot fail and touches no memory of ours.
+    let uid = unsafe { libc::geteuid() };
+    if !meta.is_dir() || meta.uid() != uid || meta.mode() & 0o077 != 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            format!(
+                "{} must be a directory owned by uid {uid} with mode 0700",
+                dir.display()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Binds the control socket, replacing a stale one but never a live socket
+/// or an unrelated file. Returns the listener and the socket's identity.
+fn claim_socket(path: &Path) -> Option<(UnixListener, (u64, u64))> {
+    match fs::symlink_metadata(path) {
+        Ok(meta) if meta.file_type().is_socket() => {
+            if UnixStream::connect(path).is_ok() {
+                error!(path = %path.display(), "another daemon is listening on the socket");
+                return None;
+            }
+            if fs::remove_file(path).is_ok() {
+                trace!(path = %path.display(), "removed stale socket");
+            }
+        }
+        Ok(_) => {
+            error!(path = %path.display(), "refusing to replace a file that is not a socket");
+            return None;
+        }
+        Err(_) => {}
+    }
+    let listener = match UnixListener::bind(path) {
+        Ok(l) => {
+            debug!(path = %path.display(), "socket bound");
+            l
+        }
+        Err(e) => {
+            error!("failed to bind socket: {e}");
+            return None;
+        }
+    };
+    // Only our user may connect; the allow-list also checks each peer.
+    if let Err(e) = fs::set_permissions(path, fs::Permissions::from_mode(0o600)) {
+        error!(path = %path.display(), "failed to restrict the socket: {e}");
+        return None;
+    }
+    let id = fs::metadata(path)
+        .map(|m| (m.dev(), m.ino()))
+        .unwrap_or_default();
+    Some((listener, id))
+}
+
+/// Removes the socket unless something else has taken its place. Sockets
+/// passed by systemd have no identity here and belong to it.
+fn remove_socket(path: &Path, id: Option<(u64, u64)>) {
+    let Some(id) = id else {
+        return;
+    };
+    match fs::symlink_metadata(path) {
+        Ok(meta) if (meta.dev(), meta.ino()) == id => {
+            if let Err(e) = fs::remove_file(path) {
+                error!(path = %path.display(), "failed to remove socket: {e}");
+            } else {
+                debug!(path = %path.display(), "socket removed");
+            }
+        }
+        Ok(_) => debug!(path = %path.display(), "socket replaced; leaving it"),
+        Err(_) => {}
+    }
+}
+
+/// Saves the manual rate and mode the daemon ended with, so a restart
+/// resumes where it left off.
+fn persist_state(fps: &Rate, ai_mode: bool) {
+    let mut cfg = load_config();
+    // In AI mode the rate is computed; keep the last manual one.
+    if !ai_mode {
+        cfg.fps = fps.get();
+    }
+    // Builds without a camera turn AI mode off at start; keep the setting.
+    if cfg!(feature = "camera") {
+        cfg.ai_mode = ai_mode;
+    }
+    save_config(&cfg);
+}
+
+/// Daemon settings from the command line; switches left off fall back to
+/// `state.json`.
+pub struct DaemonOptions {
+    pub dir: Option<PathBuf>,
+    pub process: String,
+    pub rules: MatchRules,
+    pub targets: Vec<TargetSpec>,
+    pub adaptive: bool,
+    pub logind: bool,
+    pub hyprland: bool,
+    pub replace: bool,
+    pub allow_uids: Vec<u32>,
+    pub dbus: bool,
+    pub http: Option<String>,
+    pub http_origin: Option<String>,
+    pub metrics: Option<String>,
+}
+
+pub fn run_daemon(opts: DaemonOptions) -> ExitCode {
+    let DaemonOptions {
+        dir,
+        process,
+        rules,
+        targets,
+        adaptive,
+        logind,
+        hyprland,
+        replace,
+        allow_uids,
+        dbus,
+        http,
+        http_origin,
+        metrics: metrics_bind,
+    } = opts;
     if let Some(d) = dir {
         env::set_var("BONGO_IMAGE_DIR", &d);
         debug!(dir = %d.display(), "using custom image directory");
     }
     info!("daemon started");
 
-    let cfg = load_config();
-    debug!(fps = cfg.fps, ai_mode = cfg.ai_mode, "loaded configuration");
-    let fps = Arc::new(AtomicU32::new(cfg.fps.clamp(0.5, 30.0).to_bits()));
-    let ai_mode = Arc::new(AtomicBool::new(cfg.ai_mode));
-    spawn_ai_thread(fps.clone(), ai_mode.clone());
-
     let sock_path = crate::ipc::socket_path();
-    if fs::remove_file(&sock_path).is_ok() {
-        trace!(path = %sock_path.display(), "removed stale socket");
+    if let Err(e) = prepare_socket_dir(&sock_path) {
+        error!(path = %sock_path.display(), "unsafe socket directory: {e}");
+        return ExitCode::FAILURE;
     }
-    let listener = match UnixListener::bind(&sock_path) {
-        Ok(l) => {
-            debug!(path = %sock_path.display(), "socket bound");
-            l
+    let lock_path = pid_path(&sock_path);
+    let lock = if replace {
+        InstanceLock::replace(&lock_path, REPLACE_TIMEOUT)
+    } else {
+        InstanceLock::acquire(&lock_path)
+    };
+    let _lock = match lock {
+        Ok(lock) => lock,
+        Err(e @ LockError::Held(_)) => {
+            error!("{e}; stop it first or start with --replace");
+            return ExitCode::FAILURE;
         }
         Err(e) => {
-            error!("failed to bind socket: {e}");
-            return;
+            error!(path = %lock_path.display(), "failed to lock the pid file: {e}");
+            return ExitCode::FAILURE;
         }
     };
 
-    let fps_ctrl = fps.clone();
-    let ai_ctrl = ai_mode.clone();
+    let modified = config_modified();
+    let cfg = load_config();
+    debug!(fps = cfg.fps, ai_mode = cfg.ai_mode, "loaded configuration");
+    let rules = cfg.target.clone().merge(rules);
+    debug!(?rules, "process match rules");
+    let specs = if !targets.is_empty() {
+        targets
+    } else if !cfg.targets.is_empty() {
+        cfg.targets.clone()
+    } else {
+        vec![TargetSpec::hyprlock()]
+    };
+    let adaptive = adaptive || cfg.adaptive;
+    let allow = AllowList::same_user().with(cfg.allowed_uids.iter().copied().chain(allow_uids));
+    debug!(?allow, "control socket allow-list");
+    let fetches = Arc::new(FetchLatency::default());
+    let hyprland = if hyprland || cfg.hyprland {
+        let events = Hyprland::from_env();
+        if events.is_none() {
+            info!("Hyprland event socket not found; polling for processes");
+        }
+        events
+    } else {
+        None
+    };
+    let build = || {
+        build_targets(
+            &specs,
+            &process,
+            &rules,
+            adaptive.then_some(&fetches),
+            hyprland.as_ref(),
+        )
+    };
+    let mut targets = match build() {
+        Ok(t) => t,
+        Err(e) => {
+            error!("invalid target: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let started = Instant::now();
+    let session = Arc::new(LockState::new(true));
+    if (logind || cfg.logind) && !follow_logind(session.clone()) {
+        return ExitCode::FAILURE;
+    }
+    let (listener, sock_id) = match listen_fd() {
+        Some(listener) => {
+            info!("using the socket passed by systemd");
+            (listener, None)
+        }
+        None => match claim_socket(&sock_path) {
+            Some((listener, id)) => (listener, Some(id)),
+            None => return ExitCode::FAILURE,
+        },
+    };
+    // An activated socket may live elsewhere than BONGO_SOCKET says.
+    let listen_path = listener
+        .local_addr()
+        .ok()
+        .and_then(|addr| addr.as_pathname().map(Path::to_path_buf))
+        .unwrap_or_else(|| sock_path.clone());
+    let fps = Arc::new(Rate::new(cfg.fps.clamp(0.5, 30.0)));
+    let ai_mode = Arc::new(AtomicBool::new(cfg.ai_mode));
+    let policy = Arc::new(Mutex::new(FpsPolicy {
+        dark_threshold: cfg.dark_threshold,
+    }));
+    #[cfg(feature = "ai")]
+    let models = crate::ai::Models::default();
+    let health = Arc::new(AiHealth::new(if cfg!(feature = "camera") {
+        WorkerState::Waiting
+    } else {
+        WorkerState::Unavailable
+    }));
+    let stopper = Stopper {
+        requested: Arc::new(AtomicBool::new(false)),
+        fps: fps.clone(),
+        session: session.clone(),
+        socket: listen_path,
+    };
+    if let Err(e) = handle_signals(stopper.clone()) {
+        error!("failed to install signal handlers: {e}");
+        remove_socket(&sock_path, sock_id);
+        return ExitCode::FAILURE;
+    }
     let caches: Arc<Mutex<HashMap<PathBuf, FrameCache>>> = Arc::new(Mutex::new(HashMap::new()));
-    let cache_ctrl = caches.clone();
-    debug!("starting IPC thread");
-    std::thread::spawn(move || {
-        for stream in listener.incoming() {
-            match stream {
-                Ok(mut s) => {
-                    debug!("connection accepted");
-                    if let Ok(msg) = serde_json::from_reader::<_, ControlMessage>(&mut s) {
-                        debug!(?msg, "received message");
-                        match msg {
-                            ControlMessage::SetFps(v) => {
-                                let v = v.clamp(0.5, 30.0);
-                                debug!(fps = v, "updating fps and disabling AI");
-                                ai_ctrl.store(false, Ordering::Relaxed);
-                                fps_ctrl.store(v.to_bits(), Ordering::Relaxed)
-                            }
-                            ControlMessage::EnableAi => {
-                                debug!("enabling AI mode");
-                                ai_ctrl.store(true, Ordering::Relaxed)
-                            }
-                            ControlMessage::NextImage => {
-                                trace!("next image requested");
-                                let dir = image_dir();
-                                let reply = {
-                                    let mut caches = cache_ctrl.lock().unwrap();
-                                    let cache = caches
-                                        .entry(dir.clone())
-                                        .or_insert_with(|| FrameCache::new(&dir));
-                                    cache.next_frame()
-                                };
-                                if let Some(p) = &reply {
-                                    trace!(path = %p.display(), "sending frame path");
-                                    let _ = s.write_all(p.to_string_lossy().as_bytes());
-                                }
-                            }
-                        }
-                    }
-                }
-                Err(e) => error!("failed to accept connection: {e}"),
+    let progress = Arc::new(Mutex::new(Progress::default()));
+    let events = Arc::new(EventBus::default());
+    let metrics = Arc::new(Metrics::default());
+    let control = Control {
+        fps: fps.clone(),
+        ai_mode: ai_mode.clone(),
+        policy: policy.clone(),
+        caches: caches.clone(),
+        fetches: fetches.clone(),
+        #[cfg(feature = "ai")]
+        models: models.clone(),
+        session: session.clone(),
+        health: health.clone(),
+        progress: progress.clone(),
+        events: events.clone(),
+        metrics: metrics.clone(),
+        saved: Arc::new(Mutex::new(SavedConfig::new(cfg.clone(), modified))),
+        started,
+    };
+    if (dbus || cfg.dbus) && !serve_dbus(control.clone(), &events) {
+        remove_socket(&sock_path, sock_id);
+        return ExitCode::FAILURE;
+    }
+    let _metrics = match metrics_bind.or_else(|| cfg.metrics.clone()) {
+        Some(bind) => match serve_metrics(&bind, control.clone()) {
+            Some(server) => Some(server),
+            None => {
+                remove_socket(&sock_path, sock_id);
+                return ExitCode::FAILURE;
             }
-        }
+        },
+        None => None,
+    };
+    let http = http.or_else(|| cfg.http.clone());
+    let http_origin = http_origin.or_else(|| cfg.http_origin.clone());
+    #[cfg(feature = "http")]
+    let _http = match http {
+        Some(bind) => match serve_http(
+            &bind,
+            http_origin,
+            &sock_path,
+            control.clone(),
+            events.clone(),
+        ) {
+            Some(server) => Some(server),
+            None => {
+                remove_socket(&sock_path, sock_id);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+    #[cfg(not(feature = "http"))]
+    if http.is_some() || http_origin.is_some() {
+        error!("--http needs a build with the `http` feature");
+        remove_socket(&sock_path, sock_id);
+        return ExitCode::FAILURE;
+    }
+    #[cfg(feature = "camera")]
+    let ai_thread = spawn_ai_thread(Worker {
+        fps: fps.clone(),
+        enabled: ai_mode.clone(),
+        session: session.clone(),
+        models: models.clone(),
+        policy: policy.clone(),
+        health: health.clone(),
+        events: events.clone(),
+        metrics: metrics.clone(),
+        cfg: cfg.clone(),
     });
+    #[cfg(not(feature = "camera"))]
+    if ai_mode.swap(false, Ordering::Relaxed) {
+        tracing::warn!(
+            "AI mode is configured but this build lacks the `camera` feature; using manual fps"
+        );
+    }
 
-    let mut sys = System::new();
-    let mut pids = wait_for_process(&process, &mut sys);
+    let stop_ctrl = stopper.requested.clone();
+    debug!("starting IPC thread");
+    let ipc_thread = std::thread::spawn(move || {
+        serve(listener, Limits::default(), allow, stop_ctrl, move |conn| {
+            control.serve(conn)
+        });
+    });
 
+    let mut notifier = Notifier::from_env();
+    notifier.ready(if session.is_locked() {
+        "animating"
+    } else {
+        "idle"
+    });
+    let mut scheduler = Scheduler::new(SystemClock);
+    let mut count: u64 = 0;
+    let mut code = ExitCode::SUCCESS;
     loop {
-        sys.refresh_processes(ProcessesToUpdate::All, true);
-
-        pids.retain(|pid| {
-            if let Some(proc_) = sys.process(*pid) {
-                if proc_.name() != std::ffi::OsStr::new(&process) {
-                    return false;
-                }
-                trace!(pid = pid.as_u32(), "signalling");
-
-                match proc_.kill_with(Signal::User2) {
-                    Some(true) => true,
-                    Some(false) => {
-                        error!(pid = pid.as_u32(), "failed to send signal");
-                        false
-                    }
-                    None => {
-                        error!("signal not supported");
-                        false
+        if !session.is_locked() {
+            info!("session unlocked; idling");
+            notifier.status("idle");
+            match notifier.ping_interval() {
+                // Wake up to keep the watchdog fed while idling.
+                Some(interval) => {
+                    while !session.wait_for(true, interval) && !session.is_closed() {
+                        notifier.ping();
                     }
                 }
-            } else {
-                false
+                None => {
+                    session.wait_locked();
+                }
             }
-        });
-
-        if pids.is_empty() {
-            pids = wait_for_process(&process, &mut sys);
+            if session.is_closed() {
+                break;
+            }
+            info!("session locked; animating");
+            notifier.status("animating");
+            // Start the animation over with fresh targets and frame caches.
+            targets = match build() {
+                Ok(t) => t,
+                Err(e) => {
+                    error!("invalid target: {e}");
+                    code = ExitCode::FAILURE;
+                    break;
+                }
+            };
+            caches.lock().unwrap().clear();
+            scheduler = Scheduler::new(SystemClock);
+            count = 0;
+            progress
+                .lock()
+                .unwrap()
+                .publish(&targets, scheduler.stats(), &events);
         }
+        let Some(tick) = scheduler.wait(&fps) else {
+            break;
+        };
+        for target in &mut targets {
+            target.on_tick(count, &tick);
+        }
+        progress
+            .lock()
+            .unwrap()
+            .publish(&targets, scheduler.stats(), &events);
+        notifier.ping();
+        count = count.wrapping_add(1);
+    }
 
-        let delay = f32::from_bits(fps.load(Ordering::Relaxed));
-        trace!(fps = delay, "sleeping");
-        std::thread::sleep(Duration::from_secs_f64(1.0 / delay as f64));
+    notifier.stopping();
+    stopper.stop();
+    if ipc_thread.join().is_err() {
+        error!("IPC thread panicked");
+    }
+    #[cfg(feature = "camera")]
+    if ai_thread.join().is_err() {
+        error!("AI thread panicked");
     }
+    // Kills commands exec targets still have running.
+    drop(targets);
+    remove_socket(&sock_path, sock_id);
+    persist_state(&fps, ai_mode.load(Ordering::Relaxed));
+    info!("daemon stopped");
+    code
 }
@@ -0,0 +1,142 @@
+use crate::events::{Event, EventBus, EventKind, SUBSCRIBER_BUFFER};
+use crate::ipc::{ControlMessage, ErrorKind, IpcError, Response};
+use crate::status::{Mode, Status};
+use std::collections::HashMap;
+use tracing::{debug, error};
+use zbus::blocking::Connection;
+use zbus::fdo;
+use zbus::zvariant::Value;
+
+pub const BUS_NAME: &str = "org.bongo.Modulator";
+pub const OBJECT_PATH: &str = "/org/bongo/Modulator";
+pub const INTERFACE: &str = "org.bongo.Modulator";
+
+type Handler = Box<dyn Fn(ControlMessage) -> Response + Send + Sync>;
+
+/// The daemon on the bus, answering through the same handler as the control
+/// socket.
+struct Modulator {
+    handler: Handler,
+}
+
+impl Modulator {
+    fn call(&self, msg: ControlMessage) -> fdo::Result<Response> {
+        match (self.handler)(msg) {
+            Response::Error(e) => Err(to_fdo(e)),
+            response => Ok(response),
+        }
+    }
+
+    fn status(&self) -> fdo::Result<Status> {
+        match self.call(ControlMessage::Status)? {
+            Response::Status(status) => Ok(*status),
+            other => Err(unexpected(other)),
+        }
+    }
+}
+
+fn to_fdo(e: IpcError) -> fdo::Error {
+    match e.kind {
+        ErrorKind::BadRequest => fdo::Error::InvalidArgs(e.message),
+        ErrorKind::Unsupported => fdo::Error::NotSupported(e.message),
+        ErrorKind::PermissionDenied => fdo::Error::AccessDenied(e.message),
+        _ => fdo::Error::Failed(e.to_string()),
+    }
+}
+
+fn unexpected(response: Response) -> fdo::Error {
+    fdo::Error::Failed(format!("unexpected answer {response:?}"))
+}
+
+#[zbus::interface(name = "org.bongo.Modulator")]
+impl Modulator {
+    /// Switches to manual mode at `fps`.
+    fn set_fps(&self, fps: f64) -> fdo::Result<()> {
+        self.call(ControlMessage::SetFps(fps as f32)).map(drop)
+    }
+
+    fn enable_ai(&self) -> fdo::Result<()> {
+        self.call(ControlMessage::EnableAi).map(drop)
+    }
+
+    /// Hands out the next frame, as `next-image` does.
+    fn next_image(&self) -> fdo::Result<String> {
+        match self.call(ControlMessage::NextImage)? {
+            Response::Frame(path) => Ok(path.to_string_lossy().into_owned()),
+            other => Err(unexpected(other)),
+        }
+    }
+
+    /// Re-reads `state.json` and rescans the frame directory.
+    fn reload(&self) -> fdo::Result<()> {
+        self.call(ControlMessage::Reload).map(drop)
+    }
+
+    #[zbus(property)]
+    fn fps(&self) -> fdo::Result<f64> {
+        Ok(self.status()?.fps.into())
+    }
+
+    /// `manual` or `ai`.
+    #[zbus(property)]
+    fn mode(&self) -> fdo::Result<String> {
+        Ok(self.status()?.mode.to_string())
+    }
+
+    #[zbus(property)]
+    fn ai_enabled(&self) -> fdo::Result<bool> {
+        Ok(self.status()?.mode == Mode::Ai)
+    }
+
+    /// Never announced, so clients must read it afresh after a `Reload`.
+    #[zbus(property(emits_changed_signal = "false"))]
+    fn frame_dir(&self) -> fdo::Result<String> {
+        Ok(self.status()?.frames.dir.to_string_lossy().into_owned())
+    }
+}
+
+/// Serves `handler` as `org.bongo.Modulator` on `conn`, announcing FPS and
+/// mode changes from `events` with `PropertiesChanged`.
+pub fn serve<H>(conn: &Connection, handler: H, events: &EventBus) -> zbus::Result<()>
+where
+    H: Fn(ControlMessage) -> Response + Send + Sync + 'static,
+{
+    let modulator = Modulator {
+        handler: Box::new(handler),
+    };
+    conn.object_server().at(OBJECT_PATH, modulator)?;
+    conn.request_name(BUS_NAME)?;
+    debug!(name = BUS_NAME, "serving on the session bus");
+
+    let changes = events.subscribe(vec![EventKind::Fps, EventKind::Mode], SUBSCRIBER_BUFFER);
+    let conn = conn.clone();
+    std::thread::spawn(move || {
+        for event in changes {
+            let mut changed = HashMap::new();
+            let mut invalidated = Vec::new();
+            match event {
+                Event::Fps(fps) => {
+                    changed.insert("Fps", Value::from(f64::from(fps)));
+                }
+                Event::Mode(mode) => {
+                    changed.insert("Mode", Value::from(mode.to_string()));
+                    changed.insert("AiEnabled", Value::from(mode == Mode::Ai));
+                }
+                // Some changes went unannounced; have clients read afresh.
+                Event::Lagged(_) => invalidated = vec!["Fps", "Mode", "AiEnabled"],
+                _ => continue,
+            }
+            let emitted = conn.emit_signal(
+                None::<&str>,
+                OBJECT_PATH,
+                "org.freedesktop.DBus.Properties",
+                "PropertiesChanged",
+                &(INTERFACE, changed, invalidated),
+            );
+            if let Err(e) = emitted {
+                error!("failed to announce property changes: {e}");
+            }
+        }
+    });
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use crate::status::{Detection, Mode};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+use std::sync::Mutex;
+
+/// Events queued for a subscriber before further ones are dropped.
+pub const SUBSCRIBER_BUFFER: usize = 64;
+
+/// What a subscriber can ask to be told about.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum EventKind {
+    Fps,
+    Mode,
+    Detection,
+    Target,
+    Frame,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum Event {
+    Fps(f32),
+    Mode(Mode),
+    Detection(Detection),
+    /// A target started following a process.
+    TargetAppeared {
+        target: String,
+        pid: u32,
+    },
+    /// A process a target followed went away.
+    TargetVanished {
+        target: String,
+        pid: u32,
+    },
+    /// `next-image` handed out a frame.
+    Frame {
+        path: PathBuf,
+        index: usize,
+    },
+    /// Events dropped because the subscriber fell behind; sent to every
+    /// subscriber whatever its filter.
+    Lagged(u64),
+}
+
+impl Event {
+    pub fn kind(&self) -> Option<EventKind> {
+        match self {
+            Event::Fps(_) => Some(EventKind::Fps),
+            Event::Mode(_) => Some(EventKind::Mode),
+            Event::Detection(_) => Some(EventKind::Detection),
+            Event::TargetAppeared { .. } | Event::TargetVanished { .. } => Some(EventKind::Target),
+            Event::Frame { .. } => Some(EventKind::Frame),
+            Event::Lagged(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Fps(fps) => write!(f, "fps       {fps:.1}"),
+            Event::Mode(mode) => write!(f, "mode      {mode}"),
+            Event::Detection(d) => write!(f, "detection {d}"),
+            Event::TargetAppeared { target, pid } => {
+                write!(f, "target    {target} follows pid {pid}")
+            }
+            Event::TargetVanished { target, pid } => write!(f, "target    {target} lost pid {pid}"),
+            Event::Frame { path, index } => write!(f, "frame     #{index} {}", path.display()),
+            Event::Lagged(dropped) => write!(f, "lagged    {dropped} events dropped"),
+        }
+    }
+}
+
+struct Subscriber {
+    tx: SyncSender<Event>,
+    /// Kinds the subscriber wants; empty means all of them.
+    kinds: Vec<EventKind>,
+    dropped: u64,
+}
+
+impl Subscriber {
+    fn wants(&self, event: &Event) -> bool {
+        self.kinds.is_empty() || event.kind().is_none_or(|k| self.kinds.contains(&k))
+    }
+
+    /// Queues `event` without waiting, counting it as dropped when the queue
+    /// is full. Returns false once the subscriber is gone.
+    fn offer(&mut self, event: &Event) -> bool {
+        if !self.wants(event) {
+            return true;
+        }
+        if self.dropped > 0 {
+            match self.tx.try_send(Event::Lagged(self.dropped)) {
+                Ok(()) => self.dropped = 0,
+                Err(TrySendError::Full(_)) => {
+                    self.dropped += 1;
+                    return true;
+                }
+                Err(TrySendError::Disconnected(_)) => return false,
+            }
+        }
+        match self.tx.try_send(event.clone()) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                self.dropped += 1;
+                true
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        }
+    }
+}
+
+/// Fans events out to subscribers. Publishing never blocks: a subscriber
+/// that falls behind loses events and is told how many.
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: Mutex<Vec<Subscriber>>,
+}
+
+impl EventBus {
+    /// Subscribes to the `kinds` of events, or all of them when empty,
+    /// queueing at most `capacity`.
+    pub fn subscribe(&self, kinds: Vec<EventKind>, capacity: usize) -> Receiver<Event> {
+        let (tx, rx) = sync_channel(capacity);
+        self.subscribers.lock().unwrap().push(Subscriber {
+            tx,
+            kinds,
+            dropped: 0,
+        });
+        rx
+    }
+
+    pub fn publish(&self, event: Event) {
+        self.subscribers
+            .lock()
+            .unwrap()
+            .retain_mut(|s| s.offer(&event));
+    }
+
+    pub fn subscribers(&self) -> usize {
+        self.subscribers.lock().unwrap().len()
+    }
+}
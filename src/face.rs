@@ -0,0 +1,231 @@
+use candle_core::{DType, Tensor};
+use std::collections::HashMap;
+
+/// A face found by the face-detection backend.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Face {
+    /// Bounding box as `[x1, y1, x2, y2]` in model input pixels.
+    pub bbox: [f32; 4],
+    pub score: f32,
+    /// How squarely the face points at the camera, from 0 (profile) to 1.
+    pub frontal: f32,
+}
+
+const IOU_THRESHOLD: f32 = 0.45;
+/// Feature map strides of YuNet's outputs.
+const YUNET_STRIDES: [usize; 3] = [8, 16, 32];
+
+/// How a face model lays out its outputs, told apart by their names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FaceLayout {
+    /// A single `[1, C, N]` output of candidates, as YOLOv8-face exports.
+    Yolo,
+    /// `scores` `[1, N, 2]` and `boxes` `[1, N, 4]`, as UltraFace exports.
+    UltraFace,
+    /// `cls_S`, `obj_S`, `bbox_S` and `kps_S` for each stride `S` of 8, 16
+    /// and 32, as YuNet exports.
+    YuNet,
+}
+
+impl FaceLayout {
+    pub fn detect<'a>(outputs: impl IntoIterator<Item = &'a str>) -> Self {
+        let names: Vec<&str> = outputs.into_iter().collect();
+        if names.iter().any(|n| n.starts_with("cls_")) {
+            FaceLayout::YuNet
+        } else if names.contains(&"scores") && names.contains(&"boxes") {
+            FaceLayout::UltraFace
+        } else {
+            FaceLayout::Yolo
+        }
+    }
+}
+
+fn output<'a>(outputs: &'a HashMap<String, Tensor>, name: &str) -> candle_core::Result<&'a Tensor> {
+    match outputs.get(name) {
+        Some(tensor) => Ok(tensor),
+        None => candle_core::bail!("face model output {name} missing"),
+    }
+}
+
+/// Rows of a `[1, A, B]` output as `A` vectors of `B` values.
+fn rows(output: &Tensor) -> candle_core::Result<Vec<Vec<f32>>> {
+    output.squeeze(0)?.to_dtype(DType::F32)?.to_vec2::<f32>()
+}
+
+/// Decodes the outputs of a face model with the given layout, run on a
+/// `width` x `height` input. Boxes are in model input pixels.
+pub fn decode_face_outputs(
+    layout: FaceLayout,
+    outputs: &HashMap<String, Tensor>,
+    (width, height): (u32, u32),
+    confidence: f32,
+) -> candle_core::Result<Vec<Face>> {
+    match layout {
+        FaceLayout::Yolo => {
+            let mut values = outputs.values();
+            match (values.next(), values.next()) {
+                (Some(output), None) => decode_faces(output, confidence),
+                _ => candle_core::bail!("expected one face model output, got {}", outputs.len()),
+            }
+        }
+        FaceLayout::UltraFace => decode_ultraface(
+            output(outputs, "scores")?,
+            output(outputs, "boxes")?,
+            (width as f32, height as f32),
+            confidence,
+        ),
+        FaceLayout::YuNet => decode_yunet(outputs, width as usize, confidence),
+    }
+}
+
+/// Decodes a YOLO-style face model output of shape `[1, C, N]`.
+///
+/// Each of the `N` candidates holds `cx, cy, w, h, score` followed by five
+/// optional landmarks (left eye, right eye, nose, left and right mouth
+/// corner) as `x, y` or `x, y, confidence`. Models without landmarks are
+/// treated as fully frontal.
+pub fn decode_faces(output: &Tensor, confidence: f32) -> candle_core::Result<Vec<Face>> {
+    let rows = rows(output)?;
+    let stride = match rows.len() {
+        20 => Some(3),
+        15 => Some(2),
+        5 => None,
+        n => candle_core::bail!("unexpected face model output with {n} channels"),
+    };
+    let mut faces = Vec::new();
+    for (i, &score) in rows[4].iter().enumerate() {
+        if score < confidence {
+            continue;
+        }
+        let (cx, cy, w, h) = (rows[0][i], rows[1][i], rows[2][i], rows[3][i]);
+        let landmark = |n: usize| stride.map(|s| (rows[5 + n * s][i], rows[6 + n * s][i]));
+        let frontal = match (landmark(0), landmark(1), landmark(2)) {
+            (Some(left), Some(right), Some(nose)) => frontal_score(left, right, nose),
+            _ => 1.0,
+        };
+        faces.push(Face {
+            bbox: [cx - w / 2.0, cy - h / 2.0, cx + w / 2.0, cy + h / 2.0],
+            score,
+            frontal,
+        });
+    }
+    Ok(suppress_overlaps(faces))
+}
+
+/// Decodes UltraFace's background/face `scores` and `boxes`, whose corners
+/// are fractions of the input's `width` and `height`. UltraFace has no
+/// landmarks, so every face counts as frontal.
+pub fn decode_ultraface(
+    scores: &Tensor,
+    boxes: &Tensor,
+    (width, height): (f32, f32),
+    confidence: f32,
+) -> candle_core::Result<Vec<Face>> {
+    let (scores, boxes) = (rows(scores)?, rows(boxes)?);
+    if scores.len() != boxes.len() {
+        candle_core::bail!("{} face scores for {} boxes", scores.len(), boxes.len());
+    }
+    let mut faces = Vec::new();
+    for (score, b) in scores.iter().zip(&boxes) {
+        let (&[_, score], &[x1, y1, x2, y2]) = (&score[..], &b[..]) else {
+            candle_core::bail!("unexpected UltraFace output layout");
+        };
+        if score < confidence {
+            continue;
+        }
+        faces.push(Face {
+            bbox: [x1 * width, y1 * height, x2 * width, y2 * height],
+            score,
+            frontal: 1.0,
+        });
+    }
+    Ok(suppress_overlaps(faces))
+}
+
+/// Decodes YuNet's per-stride outputs for an input `width` pixels wide.
+///
+/// Each cell of a stride's grid predicts a box centre offset and log size
+/// in strides, five landmarks (eyes, nose, mouth corners) as offsets, and a
+/// class and objectness score whose geometric mean is the face score.
+pub fn decode_yunet(
+    outputs: &HashMap<String, Tensor>,
+    width: usize,
+    confidence: f32,
+) -> candle_core::Result<Vec<Face>> {
+    let mut faces = Vec::new();
+    for stride in YUNET_STRIDES {
+        let grid = |name: &str| rows(output(outputs, &format!("{name}_{stride}"))?);
+        let (cls, obj, bbox, kps) = (grid("cls")?, grid("obj")?, grid("bbox")?, grid("kps")?);
+        if [obj.len(), bbox.len(), kps.len()] != [cls.len(); 3] {
+            candle_core::bail!("YuNet outputs for stride {stride} differ in length");
+        }
+        let cols = (width / stride).max(1);
+        let s = stride as f32;
+        for i in 0..cls.len() {
+            let (&[cls], &[obj], &[dx, dy, dw, dh], Ok(kps)) = (
+                &cls[i][..],
+                &obj[i][..],
+                &bbox[i][..],
+                <&[f32; 10]>::try_from(&kps[i][..]),
+            ) else {
+                candle_core::bail!("unexpected YuNet output layout");
+            };
+            let score = (cls.clamp(0.0, 1.0) * obj.clamp(0.0, 1.0)).sqrt();
+            if score < confidence {
+                continue;
+            }
+            let (col, row) = ((i % cols) as f32, (i / cols) as f32);
+            let (cx, cy) = ((col + dx) * s, (row + dy) * s);
+            let (w, h) = (dw.exp() * s, dh.exp() * s);
+            let point = |n: usize| ((kps[2 * n] + col) * s, (kps[2 * n + 1] + row) * s);
+            faces.push(Face {
+                bbox: [cx - w / 2.0, cy - h / 2.0, cx + w / 2.0, cy + h / 2.0],
+                score,
+                frontal: frontal_score(point(0), point(1), point(2)),
+            });
+        }
+    }
+    Ok(suppress_overlaps(faces))
+}
+
+/// Scores yaw from the nose offset relative to the midpoint between the eyes.
+fn frontal_score(left_eye: (f32, f32), right_eye: (f32, f32), nose: (f32, f32)) -> f32 {
+    let half = (right_eye.0 - left_eye.0).abs() / 2.0;
+    if half <= f32::EPSILON {
+        return 0.0;
+    }
+    let mid = (left_eye.0 + right_eye.0) / 2.0;
+    (1.0 - (nose.0 - mid).abs() / half).clamp(0.0, 1.0)
+}
+
+fn iou(a: &[f32; 4], b: &[f32; 4]) -> f32 {
+    let w = (a[2].min(b[2]) - a[0].max(b[0])).max(0.0);
+    let h = (a[3].min(b[3]) - a[1].max(b[1])).max(0.0);
+    let inter = w * h;
+    let area = |r: &[f32; 4]| (r[2] - r[0]) * (r[3] - r[1]);
+    let union = area(a) + area(b) - inter;
+    if union <= 0.0 {
+        0.0
+    } else {
+        inter / union
+    }
+}
+
+fn suppress_overlaps(mut faces: Vec<Face>) -> Vec<Face> {
+    faces.sort_by(|a, b| b.score.total_cmp(&a.score));
+    let mut kept: Vec<Face> = Vec::new();
+    for face in faces {
+        if kept
+            .iter()
+            .all(|k| iou(&k.bbox, &face.bbox) < IOU_THRESHOLD)
+        {
+            kept.push(face);
+        }
+    }
+    kept
+}
+
+/// Counts faces whose frontal score reaches `threshold`.
+pub fn facing_count(faces: &[Face], threshold: f32) -> usize {
+    faces.iter().filter(|f| f.frontal >= threshold).count()
+}
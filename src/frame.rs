@@ -28,6 +28,7 @@ fn load_frames(dir: &Path) -> Vec<PathBuf> {
 pub struct FrameCache {
     frames: Vec<PathBuf>,
     index: usize,
+    current: Option<usize>,
 }
 
 impl FrameCache {
@@ -35,16 +36,38 @@ impl FrameCache {
         Self {
             frames: load_frames(dir),
             index: 0,
+            current: None,
         }
     }
 
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Index of the frame `next_frame` returns next.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The frame `next_frame` returned last, or the first one before that.
+    pub fn current(&self) -> Option<&Path> {
+        self.frames
+            .get(self.current.unwrap_or(0))
+            .map(PathBuf::as_path)
+    }
+
     pub fn next_frame(&mut self) -> Option<PathBuf> {
         if self.frames.is_empty() {
             None
         } else {
-            let path = self.frames[self.index % self.frames.len()].clone();
-            self.index = (self.index + 1) % self.frames.len();
-            Some(path)
+            let index = self.index % self.frames.len();
+            self.current = Some(index);
+            self.index = (index + 1) % self.frames.len();
+            Some(self.frames[index].clone())
         }
     }
 }
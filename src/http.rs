@@ -0,0 +1,468 @@
+use crate::events::{EventBus, EventKind, SUBSCRIBER_BUFFER};
+use crate::ipc::{ControlMessage, ErrorKind, IpcError, Response as Answer};
+use crate::listen::{bind_private, remove_stale_socket, Bind};
+use crate::server::{Limits, Pool, Slot};
+use crate::status::Mode;
+use clap::ValueEnum;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::net::SocketAddr;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Arc;
+use std::time::Duration;
+use tiny_http::{Header, Method, Request, Response, Server, StatusCode};
+use tracing::{debug, error, warn};
+use tungstenite::handshake::derive_accept_key;
+use tungstenite::protocol::Role;
+use tungstenite::{Message, WebSocket};
+
+/// The largest request body accepted, in bytes.
+const MAX_BODY: u64 = 64 * 1024;
+/// How often an idle event stream pings its client, which is also how a
+/// client that went away is noticed.
+const PING_INTERVAL: Duration = Duration::from_secs(15);
+
+/// The API token: `BONGO_HTTP_TOKEN` when set and not blank, else the one
+/// stored at `path`, created on first use and readable only by us.
+pub fn token(path: &Path) -> io::Result<String> {
+    if let Ok(token) = std::env::var("BONGO_HTTP_TOKEN") {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+        warn!("ignoring the blank BONGO_HTTP_TOKEN");
+    }
+    match fs::read_to_string(path) {
+        Ok(token) if !token.trim().is_empty() => return Ok(token.trim().to_string()),
+        Ok(_) => fs::remove_file(path)?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    let mut bytes = [0u8; 16];
+    File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+    let token: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)?
+        .write_all(token.as_bytes())?;
+    Ok(token)
+}
+
+type Handler = Box<dyn Fn(ControlMessage) -> Answer + Send + Sync>;
+
+struct Api {
+    handler: Handler,
+    events: Arc<EventBus>,
+    token: String,
+    /// The one origin whose pages may call the API from a browser.
+    origin: Option<String>,
+    subscribers: Pool,
+}
+
+/// The running HTTP API; it stops when dropped.
+pub struct HttpServer {
+    server: Arc<Server>,
+    addr: Option<SocketAddr>,
+    socket: Option<PathBuf>,
+}
+
+impl HttpServer {
+    /// The address the API listens on, unless it listens on a Unix socket.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.addr
+    }
+}
+
+impl Drop for HttpServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(path) = &self.socket {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Serves `handler` over HTTP on `bind`, requiring `token` on every request,
+/// with live events from `events` over a WebSocket. Browsers may call it
+/// from pages on `origin` as well as same-origin ones. Requests and
+/// WebSockets are capped like control connections and subscribers by
+/// `limits`.
+pub fn serve<H>(
+    bind: &Bind,
+    token: String,
+    origin: Option<String>,
+    handler: H,
+    events: Arc<EventBus>,
+    limits: &Limits,
+) -> io::Result<HttpServer>
+where
+    H: Fn(ControlMessage) -> Answer + Send + Sync + 'static,
+{
+    let (server, socket) = match bind {
+        Bind::Tcp(addr) => (Server::http(addr).map_err(io::Error::other)?, None),
+        Bind::Unix(path) => {
+            remove_stale_socket(path)?;
+            let server = bind_private(path, |staged| {
+                Server::http_unix(staged).map_err(io::Error::other)
+            })?;
+            (server, Some(path.clone()))
+        }
+    };
+    let server = Arc::new(server);
+    let addr = server.server_addr().to_ip();
+    let api = Arc::new(Api {
+        handler: Box::new(handler),
+        events,
+        token,
+        origin,
+        subscribers: Pool::new(limits.max_subscribers),
+    });
+    let connections = Pool::new(limits.max_connections);
+    let requests = server.clone();
+    std::thread::spawn(move || {
+        for request in requests.incoming_requests() {
+            let Some(slot) = connections.claim() else {
+                warn!(
+                    max = connections.max,
+                    "too many HTTP clients; refusing request"
+                );
+                let busy = format!("daemon is serving {} clients", connections.max);
+                reply(request, error(503, ErrorKind::Busy, busy));
+                continue;
+            };
+            let api = api.clone();
+            let spawned = std::thread::Builder::new()
+                .name("bongo-http".into())
+                .spawn(move || api.respond(request, slot));
+            if let Err(e) = spawned {
+                error!("failed to spawn HTTP thread: {e}");
+            }
+        }
+        debug!("HTTP API stopped");
+    });
+    Ok(HttpServer {
+        server,
+        addr,
+        socket,
+    })
+}
+
+/// The body of `POST /mode`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ModeRequest {
+    pub mode: Mode,
+    /// The rate to switch to in manual mode; the current one when absent.
+    #[serde(default)]
+    pub fps: Option<f32>,
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("valid header")
+}
+
+fn json<T: Serialize>(status: u16, body: &T) -> Response<io::Cursor<Vec<u8>>> {
+    Response::from_data(serde_json::to_vec(body).unwrap_or_default())
+        .with_status_code(status)
+        .with_header(header("Content-Type", "application/json"))
+}
+
+fn error(
+    status: u16,
+    kind: ErrorKind,
+    message: impl Into<String>,
+) -> Response<io::Cursor<Vec<u8>>> {
+    json(status, &IpcError::new(kind, message))
+}
+
+fn status_of(kind: ErrorKind) -> u16 {
+    match kind {
+        ErrorKind::VersionMismatch | ErrorKind::BadRequest => 400,
+        ErrorKind::PermissionDenied => 403,
+        ErrorKind::NoFrames => 404,
+        ErrorKind::ModelRejected => 422,
+        ErrorKind::Unsupported => 501,
+        ErrorKind::Busy => 503,
+    }
+}
+
+fn content_type(path: &Path) -> &'static str {
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    match ext.to_ascii_lowercase().as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Compares in constant time, so the token cannot be guessed byte by byte.
+fn same(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
+
+fn query_param<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
+
+fn request_header<'a>(request: &'a Request, name: &'static str) -> Option<&'a str> {
+    request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv(name))
+        .map(|h| h.value.as_str())
+}
+
+fn read_json<T: DeserializeOwned>(
+    request: &mut Request,
+) -> Result<T, Response<io::Cursor<Vec<u8>>>> {
+    let mut body = Vec::new();
+    let read = request
+        .as_reader()
+        .take(MAX_BODY + 1)
+        .read_to_end(&mut body);
+    if let Err(e) = read {
+        return Err(error(400, ErrorKind::BadRequest, e.to_string()));
+    }
+    if body.len() as u64 > MAX_BODY {
+        return Err(error(
+            413,
+            ErrorKind::BadRequest,
+            format!("body exceeds {MAX_BODY} bytes"),
+        ));
+    }
+    serde_json::from_slice(&body).map_err(|e| error(400, ErrorKind::BadRequest, e.to_string()))
+}
+
+impl Api {
+    fn authorized(&self, request: &Request, query: &str) -> bool {
+        let bearer =
+            request_header(request, "Authorization").and_then(|v| v.strip_prefix("Bearer "));
+        // Browsers cannot set headers on WebSockets or images.
+        let token = bearer.or_else(|| query_param(query, "token"));
+        token.is_some_and(|t| !t.trim().is_empty() && same(t.trim(), &self.token))
+    }
+
+    /// The configured origin, when `request` comes from a page on it.
+    fn allowed_origin(&self, request: &Request) -> Option<&str> {
+        let origin = self.origin.as_deref()?;
+        (request_header(request, "Origin") == Some(origin)).then_some(origin)
+    }
+
+    /// Answers `request`, letting the configured origin read the response.
+    fn send<R: Read>(&self, request: Request, response: Response<R>) {
+        let response = match self.allowed_origin(&request) {
+            Some(origin) => response
+                .with_header(header("Access-Control-Allow-Origin", origin))
+                .with_header(header("Vary", "Origin")),
+            None => response,
+        };
+        reply(request, response);
+    }
+
+    /// Answers a CORS preflight, which carries no token, for the configured
+    /// origin only.
+    fn preflight(&self, request: Request) {
+        let response = if self.allowed_origin(&request).is_some() {
+            Response::from_data(Vec::new())
+                .with_status_code(204)
+                .with_header(header("Access-Control-Allow-Methods", "GET, PUT, POST"))
+                .with_header(header(
+                    "Access-Control-Allow-Headers",
+                    "Authorization, Content-Type",
+                ))
+                .with_header(header("Access-Control-Max-Age", "600"))
+        } else {
+            error(
+                403,
+                ErrorKind::PermissionDenied,
+                "cross-origin requests are not allowed",
+            )
+        };
+        self.send(request, response);
+    }
+
+    /// Answers `request`, holding `slot` until done.
+    fn respond(&self, mut request: Request, slot: Slot) {
+        let url = request.url().to_string();
+        let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+        debug!(method = %request.method(), path, "HTTP request");
+        if *request.method() == Method::Options {
+            return self.preflight(request);
+        }
+        if !self.authorized(&request, query) {
+            warn!(path, remote = ?request.remote_addr(), "rejected HTTP request without a valid token");
+            let response = error(401, ErrorKind::PermissionDenied, "missing or invalid token")
+                .with_header(header("WWW-Authenticate", "Bearer"));
+            self.send(request, response);
+            return;
+        }
+        let method = request.method().clone();
+        let response = match (&method, path) {
+            (Method::Get, "/status") => self.answer(ControlMessage::Status),
+            (Method::Put, "/fps") => match read_json::<f32>(&mut request) {
+                Ok(fps) => self.answer(ControlMessage::SetFps(fps)),
+                Err(response) => response,
+            },
+            (Method::Post, "/mode") => match read_json::<ModeRequest>(&mut request) {
+                Ok(body) => self.set_mode(body),
+                Err(response) => response,
+            },
+            (Method::Put, "/dark-threshold") => match read_json::<Option<f32>>(&mut request) {
+                Ok(threshold) => self.answer(ControlMessage::SetDarkThreshold(threshold)),
+                Err(response) => response,
+            },
+            (Method::Post, "/reload") => self.answer(ControlMessage::Reload),
+            (Method::Get, "/frame") => return self.frame(request),
+            (Method::Get, "/events") => return self.stream_events(request, query, slot),
+            (
+                _,
+                "/status" | "/fps" | "/mode" | "/dark-threshold" | "/reload" | "/frame" | "/events",
+            ) => error(
+                405,
+                ErrorKind::BadRequest,
+                format!("{method} not allowed on {path}"),
+            ),
+            _ => error(
+                404,
+                ErrorKind::BadRequest,
+                format!("no such endpoint {path}"),
+            ),
+        };
+        self.send(request, response);
+    }
+
+    fn answer(&self, msg: ControlMessage) -> Response<io::Cursor<Vec<u8>>> {
+        match (self.handler)(msg) {
+            Answer::Ok => Response::from_data(Vec::new()).with_status_code(204),
+            Answer::Status(status) => json(200, &status),
+            Answer::Error(e) => json(status_of(e.kind), &e),
+            other => json(200, &other),
+        }
+    }
+
+    fn set_mode(&self, body: ModeRequest) -> Response<io::Cursor<Vec<u8>>> {
+        match (body.mode, body.fps) {
+            (Mode::Ai, _) => self.answer(ControlMessage::EnableAi),
+            (Mode::Manual, Some(fps)) => self.answer(ControlMessage::SetFps(fps)),
+            (Mode::Manual, None) => match (self.handler)(ControlMessage::Status) {
+                Answer::Status(status) => self.answer(ControlMessage::SetFps(status.fps)),
+                Answer::Error(e) => json(status_of(e.kind), &e),
+                other => error(
+                    500,
+                    ErrorKind::BadRequest,
+                    format!("unexpected answer {other:?}"),
+                ),
+            },
+        }
+    }
+
+    /// Serves the frame on screen, without advancing the animation.
+    fn frame(&self, request: Request) {
+        let current = match (self.handler)(ControlMessage::Status) {
+            Answer::Status(status) => status.frames.current,
+            _ => None,
+        };
+        let Some(path) = current else {
+            self.send(request, error(404, ErrorKind::NoFrames, "no frame to show"));
+            return;
+        };
+        match File::open(&path) {
+            Ok(file) => {
+                let response = Response::from_file(file)
+                    .with_header(header("Content-Type", content_type(&path)))
+                    .with_header(header("Cache-Control", "no-store"));
+                self.send(request, response);
+            }
+            Err(e) => {
+                error!(path = %path.display(), "failed to open frame: {e}");
+                self.send(request, error(404, ErrorKind::NoFrames, e.to_string()));
+            }
+        }
+    }
+
+    /// Upgrades to a WebSocket carrying one JSON event per text message.
+    /// `?only=fps,mode` picks the kinds. The socket moves from the request's
+    /// `slot` to a subscriber's, or is refused when those run out.
+    fn stream_events(&self, request: Request, query: &str, slot: Slot) {
+        let Some(key) = request_header(&request, "Sec-WebSocket-Key").map(str::to_string) else {
+            self.send(
+                request,
+                error(
+                    426,
+                    ErrorKind::BadRequest,
+                    "/events needs a WebSocket upgrade",
+                )
+                .with_header(header("Upgrade", "websocket")),
+            );
+            return;
+        };
+        let kinds: Result<Vec<EventKind>, String> = query_param(query, "only")
+            .map(|only| {
+                only.split(',')
+                    .map(|k| EventKind::from_str(k, true))
+                    .collect()
+            })
+            .unwrap_or(Ok(Vec::new()));
+        let kinds = match kinds {
+            Ok(kinds) => kinds,
+            Err(e) => {
+                self.send(request, error(400, ErrorKind::BadRequest, e));
+                return;
+            }
+        };
+        let Some(_subscriber) = self.subscribers.claim() else {
+            warn!(max = self.subscribers.max, "too many WebSocket subscribers");
+            let busy = format!("daemon is serving {} subscribers", self.subscribers.max);
+            self.send(request, error(503, ErrorKind::Busy, busy));
+            return;
+        };
+        drop(slot);
+        let events = self.events.subscribe(kinds, SUBSCRIBER_BUFFER);
+        let accept = Response::empty(StatusCode(101)).with_header(header(
+            "Sec-WebSocket-Accept",
+            &derive_accept_key(key.as_bytes()),
+        ));
+        let stream = request.upgrade("websocket", accept);
+        let mut ws = WebSocket::from_raw_socket(stream, Role::Server, None);
+        debug!("WebSocket subscribed");
+        loop {
+            let message = match events.recv_timeout(PING_INTERVAL) {
+                Ok(event) => match serde_json::to_string(&event) {
+                    Ok(text) => Message::Text(text),
+                    Err(e) => {
+                        error!("failed to encode event: {e}");
+                        continue;
+                    }
+                },
+                Err(RecvTimeoutError::Timeout) => Message::Ping(Vec::new()),
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
+            if let Err(e) = ws.send(message) {
+                debug!("WebSocket closed: {e}");
+                return;
+            }
+        }
+        let _ = ws.close(None);
+    }
+}
+
+fn reply<R: Read>(request: Request, response: Response<R>) {
+    if let Err(e) = request.respond(response) {
+        debug!("failed to answer HTTP request: {e}");
+    }
+}
@@ -0,0 +1,164 @@
+use serde::Deserialize;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info, trace, warn};
+
+/// Hyprland's runtime directory for the running instance, from
+/// `HYPRLAND_INSTANCE_SIGNATURE`.
+pub fn socket_dir() -> Option<PathBuf> {
+    let signature = std::env::var_os("HYPRLAND_INSTANCE_SIGNATURE")?;
+    let runtime = std::env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir);
+    let dir = runtime.join("hypr").join(&signature);
+    if dir.exists() {
+        return Some(dir);
+    }
+    // Hyprland before 0.40 kept its sockets under /tmp.
+    let legacy = PathBuf::from("/tmp/hypr").join(&signature);
+    legacy.exists().then_some(legacy)
+}
+
+/// An event line from `.socket2.sock`, `EVENT>>DATA`.
+///
+/// Hyprland does not announce session locks on the socket, but a lock screen
+/// takes keyboard focus from every window, which it does report.
+#[derive(Debug, PartialEq)]
+pub enum Event {
+    /// Keyboard focus left every window, as when a lock screen grabs it.
+    FocusCleared,
+    MonitorAdded(String),
+    MonitorRemoved(String),
+    Other(String),
+}
+
+impl Event {
+    pub fn parse(line: &str) -> Option<Self> {
+        let (name, data) = line.split_once(">>")?;
+        Some(match (name, data) {
+            // `activewindowv2` is sent alongside with the address alone.
+            ("activewindow", ",") => Event::FocusCleared,
+            // The v2 variants carry `ID,NAME,DESCRIPTION`; the plain ones are
+            // sent alongside, so only those update the list.
+            ("monitoradded", _) => Event::MonitorAdded(data.to_string()),
+            ("monitorremoved", _) => Event::MonitorRemoved(data.to_string()),
+            _ => Event::Other(name.to_string()),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct Monitor {
+    name: String,
+}
+
+/// Live view of Hyprland's event stream.
+///
+/// Events hinting that a lock screen appeared bump a generation counter, so
+/// the lock screen process is looked for then rather than on a timer. Its
+/// exit is noticed through its pidfd. The connected monitors are tracked
+/// from the same stream.
+pub struct Hyprland {
+    generation: AtomicU64,
+    connected: AtomicBool,
+    monitors: Mutex<Vec<String>>,
+}
+
+impl Hyprland {
+    /// Subscribes to the running instance's events, or returns `None` when
+    /// not running under Hyprland.
+    pub fn from_env() -> Option<Arc<Self>> {
+        let dir = socket_dir()?;
+        match Self::connect(&dir) {
+            Ok(h) => Some(h),
+            Err(e) => {
+                warn!(dir = %dir.display(), "cannot subscribe to Hyprland events: {e}");
+                None
+            }
+        }
+    }
+
+    /// Subscribes to `.socket2.sock` in `dir`, seeding the monitor list from
+    /// `.socket.sock` when it answers.
+    pub fn connect(dir: &Path) -> std::io::Result<Arc<Self>> {
+        let events = UnixStream::connect(dir.join(".socket2.sock"))?;
+        let monitors = query_monitors(&dir.join(".socket.sock")).unwrap_or_default();
+        debug!(?monitors, "subscribed to Hyprland events");
+        let hyprland = Arc::new(Self {
+            generation: AtomicU64::new(0),
+            connected: AtomicBool::new(true),
+            monitors: Mutex::new(monitors),
+        });
+        let reader = hyprland.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(events).lines() {
+                match line {
+                    Ok(line) => reader.handle(&line),
+                    Err(e) => {
+                        debug!("Hyprland event stream failed: {e}");
+                        break;
+                    }
+                }
+            }
+            info!("Hyprland event stream closed; polling for processes");
+            reader.connected.store(false, Ordering::Relaxed);
+        });
+        Ok(hyprland)
+    }
+
+    fn handle(&self, line: &str) {
+        let Some(event) = Event::parse(line) else {
+            return;
+        };
+        trace!(?event, "Hyprland event");
+        match event {
+            Event::FocusCleared => {
+                self.generation.fetch_add(1, Ordering::Release);
+            }
+            Event::MonitorAdded(name) => {
+                let mut monitors = self.monitors.lock().unwrap();
+                if !monitors.contains(&name) {
+                    info!(monitor = %name, "monitor added");
+                    monitors.push(name);
+                }
+            }
+            Event::MonitorRemoved(name) => {
+                info!(monitor = %name, "monitor removed");
+                self.monitors.lock().unwrap().retain(|m| *m != name);
+            }
+            Event::Other(_) => {}
+        }
+    }
+
+    /// Counts the events so far that may mean a lock screen appeared.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Whether the event stream is still open.
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    /// Names of the connected monitors, in the order they appeared.
+    pub fn monitors(&self) -> Vec<String> {
+        self.monitors.lock().unwrap().clone()
+    }
+}
+
+fn query_monitors(socket: &Path) -> Option<Vec<String>> {
+    let mut stream = UnixStream::connect(socket).ok()?;
+    stream.write_all(b"j/monitors").ok()?;
+    let mut reply = String::new();
+    stream.read_to_string(&mut reply).ok()?;
+    match serde_json::from_str::<Vec<Monitor>>(&reply) {
+        Ok(monitors) => Some(monitors.into_iter().map(|m| m.name).collect()),
+        Err(e) => {
+            debug!("unexpected monitors reply: {e}");
+            None
+        }
+    }
+}
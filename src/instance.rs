@@ -0,0 +1,125 @@
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, Write};
+use std::os::unix::fs::MetadataExt;
+use std::os::unix::io::AsRawFd;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tracing::{debug, info};
+
+/// How long `--replace` waits for the running daemon to exit.
+pub const REPLACE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The pid file guarding the daemon bound to `socket`, next to it.
+pub fn pid_path(socket: &Path) -> PathBuf {
+    socket.with_extension("pid")
+}
+
+#[derive(Debug)]
+pub enum LockError {
+    /// Another daemon holds the lock; its pid when the file names one.
+    Held(Option<u32>),
+    Io(io::Error),
+}
+
+impl fmt::Display for LockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LockError::Held(Some(pid)) => write!(f, "another daemon is running (pid {pid})"),
+            LockError::Held(None) => write!(f, "another daemon is running"),
+            LockError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for LockError {
+    fn from(e: io::Error) -> Self {
+        LockError::Io(e)
+    }
+}
+
+/// An exclusive `flock` on the pid file, held for the daemon's lifetime.
+/// The file is removed again on drop.
+pub struct InstanceLock {
+    file: File,
+    path: PathBuf,
+}
+
+impl InstanceLock {
+    pub fn acquire(path: &Path) -> Result<Self, LockError> {
+        loop {
+            let mut file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(path)?;
+            // SAFETY: flock only takes a descriptor, which `file` keeps open.
+            if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } != 0 {
+                let err = io::Error::last_os_error();
+                if err.raw_os_error() == Some(libc::EWOULDBLOCK) {
+                    return Err(LockError::Held(read_pid(&mut file)));
+                }
+                return Err(err.into());
+            }
+            // The previous holder may have removed the file between our open
+            // and flock; a lock on an unlinked file guards nothing.
+            let held = file.metadata()?;
+            match fs::metadata(path) {
+                Ok(current) if (current.dev(), current.ino()) == (held.dev(), held.ino()) => {}
+                _ => continue,
+            }
+            file.set_len(0)?;
+            write!(file, "{}", std::process::id())?;
+            file.flush()?;
+            debug!(path = %path.display(), "instance lock acquired");
+            return Ok(Self {
+                file,
+                path: path.to_path_buf(),
+            });
+        }
+    }
+
+    /// Takes the lock over from a running daemon, asking it to shut down
+    /// with `SIGTERM` and waiting up to `timeout` for it to exit.
+    pub fn replace(path: &Path, timeout: Duration) -> Result<Self, LockError> {
+        let pid = match Self::acquire(path) {
+            Err(LockError::Held(Some(pid))) => pid,
+            other => return other,
+        };
+        info!(pid, "asking the running daemon to exit");
+        // SAFETY: kill has no memory-safety preconditions.
+        if unsafe { libc::kill(pid as libc::pid_t, libc::SIGTERM) } != 0 {
+            let err = io::Error::last_os_error();
+            if err.raw_os_error() != Some(libc::ESRCH) {
+                return Err(err.into());
+            }
+        }
+        let deadline = Instant::now() + timeout;
+        loop {
+            match Self::acquire(path) {
+                Err(LockError::Held(holder)) if Instant::now() < deadline => {
+                    debug!(?holder, "waiting for the running daemon to exit");
+                    std::thread::sleep(Duration::from_millis(50));
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        // Unlink while still holding the lock, so nobody can lock the old
+        // file after we let go of it.
+        let _ = fs::remove_file(&self.path);
+        let _ = self.file.set_len(0);
+    }
+}
+
+fn read_pid(file: &mut File) -> Option<u32> {
+    let mut contents = String::new();
+    file.rewind().ok()?;
+    file.read_to_string(&mut contents).ok()?;
+    contents.trim().parse().ok()
+}
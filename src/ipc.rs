@@ -1,17 +1,203 @@
+use crate::events::{Event, EventKind};
+use crate::status::Status;
 use serde::{Deserialize, Serialize};
-use std::io::{Read, Write};
-use std::net::Shutdown;
+use serde_json::Value;
+use std::fmt;
+use std::io::{BufRead, BufReader, Write};
 use std::os::unix::net::UnixStream;
 use std::path::PathBuf;
 use std::thread::sleep;
 use std::time::{Duration, Instant};
 use std::{env, io};
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// Version of the request/response envelope. Bumped on incompatible changes;
+/// the daemon refuses requests carrying another version.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// How long the client waits on the daemon before giving up on a request.
+pub const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long the client waits for a model swap, which may download the model.
+pub const MODEL_TIMEOUT: Duration = Duration::from_secs(300);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum ControlMessage {
+    /// Opens a versioned session; answered with `Response::Hello` whatever
+    /// version the client speaks, so it can detect a mismatch.
+    Hello,
     SetFps(f32),
     EnableAi,
     NextImage,
+    /// Replace the detection model, answered once the model is loaded or
+    /// rejected.
+    SetModel(String),
+    /// Replace the face-detection model, answered like `SetModel`.
+    SetFaceModel(String),
+    /// Replace the pose-estimation model, answered like `SetModel`.
+    SetPoseModel(String),
+    /// Set or clear the luminance below which AI mode caps the FPS.
+    SetDarkThreshold(Option<f32>),
+    /// Re-read `state.json`: the FPS, mode and dark threshold, and rescan
+    /// the frame directory.
+    Reload,
+    /// Ask what the daemon is doing, answered with `Response::Status`.
+    Status,
+    /// Stream events of these kinds, or all of them when empty. Answered
+    /// with `Ok`, then a `Response::Event` per event under the same id
+    /// until the client hangs up.
+    Subscribe(Vec<EventKind>),
+}
+
+/// A request as sent on the wire, one JSON object per line.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct RequestEnvelope {
+    pub version: u32,
+    /// Echoed in the response, so answers can arrive out of order.
+    pub id: u64,
+    pub message: ControlMessage,
+}
+
+/// A response as sent on the wire, one JSON object per line.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ResponseEnvelope {
+    pub version: u32,
+    /// The request's id, or 0 for errors about the connection as a whole,
+    /// such as a busy daemon.
+    pub id: u64,
+    pub response: Response,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Response {
+    /// The handshake answer: the daemon's protocol and package versions.
+    Hello {
+        protocol: u32,
+        daemon: String,
+    },
+    /// The request was applied.
+    Ok,
+    /// The next frame to show.
+    Frame(PathBuf),
+    Status(Box<Status>),
+    Event(Event),
+    Error(IpcError),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Client and daemon speak different protocol versions.
+    VersionMismatch,
+    /// The request could not be parsed or had invalid arguments.
+    BadRequest,
+    /// The daemon was built without the feature the request needs.
+    Unsupported,
+    /// The image directory holds no frames.
+    NoFrames,
+    /// A model could not be loaded.
+    ModelRejected,
+    /// The daemon is serving as many clients as it allows.
+    Busy,
+    /// The client's user is not allowed to control the daemon.
+    PermissionDenied,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct IpcError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl IpcError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for IpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for IpcError {}
+
+/// A request read by the daemon.
+#[derive(Debug, PartialEq)]
+pub enum Incoming {
+    Versioned {
+        id: u64,
+        message: ControlMessage,
+    },
+    /// A bare message from a client predating the envelope. It gets the old
+    /// raw reply and the connection is closed after it.
+    Legacy(ControlMessage),
+}
+
+/// Interprets one JSON value read off a connection. Errors carry the request
+/// id to answer with, where one could be found.
+pub fn parse_request(value: Value) -> Result<Incoming, (u64, IpcError)> {
+    let Some(version) = value.get("version") else {
+        return serde_json::from_value(value)
+            .map(Incoming::Legacy)
+            .map_err(|e| (0, IpcError::new(ErrorKind::BadRequest, e.to_string())));
+    };
+    let id = value.get("id").and_then(Value::as_u64).unwrap_or(0);
+    let version = version.as_u64();
+    // A hello is answered whatever the version, so check it first: newer
+    // clients may send messages this daemon cannot even parse.
+    let hello = value.get("message") == Some(&Value::String("Hello".into()));
+    if version != Some(u64::from(PROTOCOL_VERSION)) && !hello {
+        let message = match version {
+            Some(v) => format!("client speaks protocol {v}, daemon {PROTOCOL_VERSION}"),
+            None => "missing protocol version".into(),
+        };
+        return Err((id, IpcError::new(ErrorKind::VersionMismatch, message)));
+    }
+    serde_json::from_value::<RequestEnvelope>(value)
+        .map(|env| Incoming::Versioned {
+            id: env.id,
+            message: env.message,
+        })
+        .map_err(|e| (id, IpcError::new(ErrorKind::BadRequest, e.to_string())))
+}
+
+/// Encodes `response` for the wire as a newline-terminated envelope.
+pub fn encode_response(id: u64, response: Response) -> Vec<u8> {
+    let mut line = serde_json::to_vec(&ResponseEnvelope {
+        version: PROTOCOL_VERSION,
+        id,
+        response,
+    })
+    .unwrap_or_default();
+    line.push(b'\n');
+    line
+}
+
+/// Encodes `response` the way daemons before the envelope answered `msg`:
+/// a raw path for `NextImage`, a JSON `Result<(), String>` for model swaps
+/// and nothing otherwise.
+pub fn encode_legacy(msg: &ControlMessage, response: &Response) -> Vec<u8> {
+    match (msg, response) {
+        (ControlMessage::NextImage, Response::Frame(path)) => {
+            path.to_string_lossy().into_owned().into_bytes()
+        }
+        (
+            ControlMessage::SetModel(_)
+            | ControlMessage::SetFaceModel(_)
+            | ControlMessage::SetPoseModel(_),
+            response,
+        ) => {
+            let result = match response {
+                Response::Error(e) => Err(e.message.clone()),
+                _ => Ok(()),
+            };
+            serde_json::to_vec(&result).unwrap_or_default()
+        }
+        _ => Vec::new(),
+    }
 }
 
 pub fn socket_path() -> PathBuf {
@@ -20,34 +206,208 @@ pub fn socket_path() -> PathBuf {
     } else if let Some(dir) = env::var_os("XDG_RUNTIME_DIR") {
         PathBuf::from(dir).join("bongo.sock")
     } else {
-        env::temp_dir().join("bongo.sock")
-    }
-}
-
-pub fn send_command(msg: ControlMessage) -> io::Result<Option<String>> {
-    let path = socket_path();
-    let deadline = Instant::now() + Duration::from_secs(1);
-    loop {
-        match UnixStream::connect(&path) {
-            Ok(mut stream) => {
-                serde_json::to_writer(&mut stream, &msg)?;
-                stream.flush()?;
-                let _ = stream.shutdown(Shutdown::Write);
-
-                if matches!(msg, ControlMessage::NextImage) {
-                    let mut buf = String::new();
-                    stream.read_to_string(&mut buf)?;
-                    return Ok(Some(buf));
-                } else {
-                    return Ok(None);
-                }
+        fallback_dir().join("bongo.sock")
+    }
+}
+
+/// The directory holding the socket without `XDG_RUNTIME_DIR`: one per user
+/// in the temporary directory, which the daemon keeps private.
+pub fn fallback_dir() -> PathBuf {
+    // SAFETY: geteuid cannot fail and touches no memory of ours.
+    env::temp_dir().join(format!("bongo-{}", unsafe { libc::geteuid() }))
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No daemon is listening on the socket.
+    Unreachable(io::Error),
+    /// The connection failed mid-conversation.
+    Io(io::Error),
+    /// The daemon answered with something that does not fit the protocol,
+    /// or nothing at all.
+    Protocol(String),
+    /// The daemon refused the request.
+    Daemon(IpcError),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Unreachable(e) => write!(f, "daemon unreachable: {e}"),
+            ClientError::Io(e) => write!(f, "{e}"),
+            ClientError::Protocol(msg) => write!(f, "protocol error: {msg}"),
+            ClientError::Daemon(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// A versioned session with the daemon.
+pub struct Client {
+    writer: UnixStream,
+    reader: BufReader<UnixStream>,
+    next_id: u64,
+    /// Package version the daemon reported in the handshake, empty when the
+    /// session was opened without one.
+    pub daemon_version: String,
+}
+
+impl Client {
+    /// Connects to the daemon, retrying for a second while it starts up, and
+    /// checks that it speaks our protocol version.
+    pub fn connect() -> Result<Self, ClientError> {
+        let mut client = Self::open()?;
+        match client.exchange(ControlMessage::Hello)? {
+            Response::Hello { protocol, daemon } if protocol == PROTOCOL_VERSION => {
+                client.daemon_version = daemon;
+                Ok(client)
             }
-            Err(e) => {
-                if Instant::now() >= deadline {
-                    return Err(e);
-                }
-                sleep(Duration::from_millis(10));
+            Response::Hello { protocol, daemon } => Err(ClientError::Daemon(IpcError::new(
+                ErrorKind::VersionMismatch,
+                format!(
+                    "daemon {daemon} speaks protocol {protocol}, this client {PROTOCOL_VERSION}; \
+                     restart the daemon"
+                ),
+            ))),
+            Response::Error(e) => Err(ClientError::Daemon(e)),
+            other => Err(ClientError::Protocol(format!(
+                "unexpected handshake answer {other:?}"
+            ))),
+        }
+    }
+
+    /// Connects without the handshake. Every request still carries our
+    /// protocol version, which the daemon refuses when it differs.
+    fn open() -> Result<Self, ClientError> {
+        let path = socket_path();
+        let deadline = Instant::now() + Duration::from_secs(1);
+        let stream = loop {
+            match UnixStream::connect(&path) {
+                Ok(stream) => break stream,
+                Err(e) if Instant::now() >= deadline => return Err(ClientError::Unreachable(e)),
+                Err(_) => sleep(Duration::from_millis(10)),
+            }
+        };
+        stream.set_write_timeout(Some(REPLY_TIMEOUT))?;
+        Ok(Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            next_id: 1,
+            daemon_version: String::new(),
+        })
+    }
+
+    /// Sends `msg` and waits for its answer; a `Response::Error` becomes
+    /// `ClientError::Daemon`.
+    pub fn request(&mut self, msg: ControlMessage) -> Result<Response, ClientError> {
+        match self.exchange(msg)? {
+            Response::Error(e) => Err(ClientError::Daemon(e)),
+            response => Ok(response),
+        }
+    }
+
+    /// Subscribes to the `kinds` of events, or all of them when empty,
+    /// turning the session into an event stream.
+    pub fn subscribe(mut self, kinds: Vec<EventKind>) -> Result<Subscription, ClientError> {
+        self.request(ControlMessage::Subscribe(kinds))?;
+        // Events may be minutes apart; only the requests are timed.
+        self.writer.set_read_timeout(None)?;
+        Ok(Subscription {
+            reader: self.reader,
+            id: self.next_id - 1,
+        })
+    }
+
+    fn exchange(&mut self, message: ControlMessage) -> Result<Response, ClientError> {
+        let timeout = match message {
+            ControlMessage::SetModel(_)
+            | ControlMessage::SetFaceModel(_)
+            | ControlMessage::SetPoseModel(_) => MODEL_TIMEOUT,
+            _ => REPLY_TIMEOUT,
+        };
+        // The reader shares the socket, so this times its reads too.
+        self.writer.set_read_timeout(Some(timeout))?;
+        let id = self.next_id;
+        self.next_id += 1;
+        let mut line = serde_json::to_vec(&RequestEnvelope {
+            version: PROTOCOL_VERSION,
+            id,
+            message,
+        })
+        .map_err(io::Error::from)?;
+        line.push(b'\n');
+        self.writer.write_all(&line)?;
+        self.writer.flush()?;
+
+        let mut buf = String::new();
+        loop {
+            buf.clear();
+            let read = self
+                .reader
+                .read_line(&mut buf)
+                .map_err(|e| match e.kind() {
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => ClientError::Protocol(
+                        format!("daemon did not answer within {}s", timeout.as_secs()),
+                    ),
+                    _ => ClientError::Io(e),
+                })?;
+            if read == 0 {
+                // Daemons predating the envelope hang up on it unanswered.
+                return Err(ClientError::Protocol(
+                    "daemon closed the connection without answering; it may be too old \
+                     for this client"
+                        .into(),
+                ));
+            }
+            let env: ResponseEnvelope = serde_json::from_str(&buf)
+                .map_err(|e| ClientError::Protocol(format!("invalid response: {e}")))?;
+            let connection_error = env.id == 0 && matches!(env.response, Response::Error(_));
+            if env.id == id || connection_error {
+                return Ok(env.response);
             }
         }
     }
 }
+
+/// Events streamed by the daemon; ends when the daemon closes the stream.
+pub struct Subscription {
+    reader: BufReader<UnixStream>,
+    id: u64,
+}
+
+impl Iterator for Subscription {
+    type Item = Result<Event, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = String::new();
+        match self.reader.read_line(&mut buf) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(e) => return Some(Err(ClientError::Io(e))),
+        }
+        let env: ResponseEnvelope = match serde_json::from_str(&buf) {
+            Ok(env) => env,
+            Err(e) => return Some(Err(ClientError::Protocol(format!("invalid event: {e}")))),
+        };
+        Some(match env.response {
+            Response::Event(event) if env.id == self.id => Ok(event),
+            Response::Error(e) => Err(ClientError::Daemon(e)),
+            other => Err(ClientError::Protocol(format!(
+                "unexpected answer {other:?}"
+            ))),
+        })
+    }
+}
+
+/// Sends a single request over a fresh connection, skipping the handshake so
+/// frequent requests such as `NextImage` cost one round trip.
+pub fn send_command(msg: ControlMessage) -> Result<Response, ClientError> {
+    Client::open()?.request(msg)
+}
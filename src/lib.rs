@@ -1,9 +1,37 @@
+#[cfg(feature = "ai")]
 pub mod ai;
+pub mod backpressure;
+#[cfg(feature = "ai")]
+pub mod capture;
 pub mod cli;
 pub mod config;
 pub mod daemon;
+#[cfg(target_os = "linux")]
+pub mod dbus;
+pub mod events;
+#[cfg(feature = "ai")]
+pub mod face;
 pub mod frame;
+#[cfg(feature = "http")]
+pub mod http;
+pub mod hyprland;
+pub mod instance;
 pub mod ipc;
+pub mod listen;
+pub mod logfile;
+pub mod metrics;
+pub mod peer;
+pub mod policy;
+#[cfg(feature = "ai")]
+pub mod pose;
+pub mod process;
+pub mod scheduler;
+pub mod server;
+pub mod session;
+pub mod status;
+pub mod systemd;
+pub mod target;
+pub mod telemetry;
 
 pub use cli::{execute, next_image_path, run_cli, Cli, Commands, ModeSubcommand};
 pub use config::current_fps;
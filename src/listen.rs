@@ -0,0 +1,70 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Where a local endpoint listens: a loopback address or a Unix socket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Bind {
+    Tcp(SocketAddr),
+    Unix(PathBuf),
+}
+
+impl FromStr for Bind {
+    type Err = String;
+
+    /// Parses `unix:PATH`, or `HOST:PORT` resolving to a loopback address.
+    fn from_str(s: &str) -> Result<Self, String> {
+        if let Some(path) = s.strip_prefix("unix:") {
+            return Ok(Bind::Unix(path.into()));
+        }
+        let addrs: Vec<SocketAddr> = s
+            .to_socket_addrs()
+            .map_err(|e| format!("invalid address {s}: {e}"))?
+            .collect();
+        match addrs.first() {
+            Some(addr) if addrs.iter().all(|a| a.ip().is_loopback()) => Ok(Bind::Tcp(*addr)),
+            Some(_) => Err(format!(
+                "{s} is not a loopback address; only local addresses are served"
+            )),
+            None => Err(format!("{s} resolves to no address")),
+        }
+    }
+}
+
+impl fmt::Display for Bind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Bind::Tcp(addr) => write!(f, "http://{addr}"),
+            Bind::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+/// Removes a socket left at `path` by a daemon that did not exit cleanly;
+/// anything else there is left alone for the bind to fail on.
+pub fn remove_stale_socket(path: &Path) -> io::Result<()> {
+    if fs::symlink_metadata(path).is_ok_and(|m| m.file_type().is_socket()) {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Binds a Unix socket with `bind` inside a private directory beside `path`
+/// and moves it into place once restricted, so others never get to connect.
+pub fn bind_private<T>(path: &Path, bind: impl FnOnce(&Path) -> io::Result<T>) -> io::Result<T> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let dir = path.with_file_name(format!(".{name}.{}", std::process::id()));
+    fs::DirBuilder::new().mode(0o700).create(&dir)?;
+    let staged = dir.join("sock");
+    let bound = bind(&staged).and_then(|listener| {
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o600))?;
+        fs::rename(&staged, path)?;
+        Ok(listener)
+    });
+    let _ = fs::remove_dir_all(&dir);
+    bound
+}
@@ -0,0 +1,95 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Rotated files kept besides the one being written.
+pub const KEPT_LOGS: usize = 3;
+
+/// A log file rotated by size: once the next line would take it past the
+/// limit, `LOG` becomes `LOG.1`, `LOG.1` becomes `LOG.2` and so on, up to
+/// [`KEPT_LOGS`] old files.
+pub struct RotatingFile {
+    inner: Mutex<Rotation>,
+}
+
+struct Rotation {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+}
+
+fn open(path: &Path) -> io::Result<(File, u64)> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let size = file.metadata()?.len();
+    Ok((file, size))
+}
+
+/// `LOG.n`, the `n`-th most recently rotated file.
+pub fn rotated(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{n}"));
+    PathBuf::from(name)
+}
+
+impl RotatingFile {
+    /// Appends to `path`, creating it and its directory as needed.
+    pub fn open(path: &Path, max_bytes: u64) -> io::Result<Self> {
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let (file, size) = open(path)?;
+        Ok(Self {
+            inner: Mutex::new(Rotation {
+                path: path.to_path_buf(),
+                file,
+                size,
+                max_bytes,
+            }),
+        })
+    }
+}
+
+impl Rotation {
+    fn rotate(&mut self) -> io::Result<()> {
+        for n in (1..KEPT_LOGS).rev() {
+            let from = rotated(&self.path, n);
+            if from.exists() {
+                fs::rename(&from, rotated(&self.path, n + 1))?;
+            }
+        }
+        fs::rename(&self.path, rotated(&self.path, 1))?;
+        (self.file, self.size) = open(&self.path)?;
+        Ok(())
+    }
+
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Lines longer than the limit still go somewhere: alone in a file.
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+}
+
+impl Write for &RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.lock().unwrap().file.flush()
+    }
+}
+
+impl<'a> MakeWriter<'a> for RotatingFile {
+    type Writer = &'a RotatingFile;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self
+    }
+}
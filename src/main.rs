@@ -1,3 +1,3 @@
-fn main() {
-    bongo_modulator::run_cli();
+fn main() -> std::process::ExitCode {
+    bongo_modulator::run_cli()
 }
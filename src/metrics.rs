@@ -0,0 +1,447 @@
+use crate::listen::{bind_private, remove_stale_socket, Bind};
+use crate::policy::Signals;
+use crate::server::{Limits, Pool};
+use crate::status::{Mode, Status};
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener};
+use std::os::unix::net::UnixListener;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{debug, error, warn};
+
+/// Bucket bounds for AI inference, in seconds.
+const INFERENCE_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0];
+/// Bucket bounds for answering `next-image`, in seconds.
+const NEXT_IMAGE_BUCKETS: &[f64] = &[
+    0.0001, 0.00025, 0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1,
+];
+/// How long a scraper may take to send its request or read the answer.
+const SCRAPE_TIMEOUT: Duration = Duration::from_secs(5);
+/// The longest request head read from a scraper.
+const MAX_HEAD: u64 = 8 * 1024;
+
+/// The steps of an AI pass, each timed on its own. Preprocessing,
+/// evaluation and postprocessing happen once per model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Capture,
+    Preprocess,
+    Eval,
+    Postprocess,
+}
+
+impl Stage {
+    const ALL: [Stage; 4] = [
+        Stage::Capture,
+        Stage::Preprocess,
+        Stage::Eval,
+        Stage::Postprocess,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Stage::Capture => "capture",
+            Stage::Preprocess => "preprocess",
+            Stage::Eval => "eval",
+            Stage::Postprocess => "postprocess",
+        }
+    }
+}
+
+/// A Prometheus histogram with fixed bucket bounds.
+#[derive(Clone, Debug)]
+pub struct Histogram {
+    bounds: &'static [f64],
+    /// Observations per bucket, not yet cumulative.
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    pub fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            counts: vec![0; bounds.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    pub fn observe(&mut self, secs: f64) {
+        if let Some(i) = self.bounds.iter().position(|&b| secs <= b) {
+            self.counts[i] += 1;
+        }
+        self.sum += secs;
+        self.count += 1;
+    }
+
+    fn render(&self, out: &mut String, name: &str, labels: &str) {
+        let sep = if labels.is_empty() { "" } else { "," };
+        let mut cumulative = 0;
+        for (bound, count) in self.bounds.iter().zip(&self.counts) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{name}_bucket{{{labels}{sep}le=\"{bound}\"}} {cumulative}"
+            );
+        }
+        let _ = writeln!(
+            out,
+            "{name}_bucket{{{labels}{sep}le=\"+Inf\"}} {}",
+            self.count
+        );
+        let labels = if labels.is_empty() {
+            String::new()
+        } else {
+            format!("{{{labels}}}")
+        };
+        let _ = writeln!(out, "{name}_sum{labels} {}", self.sum);
+        let _ = writeln!(out, "{name}_count{labels} {}", self.count);
+    }
+}
+
+/// Counters the daemon and the AI worker keep for scraping. Everything
+/// `status` already reports is read from there instead.
+pub struct Metrics {
+    next_image: Mutex<Histogram>,
+    inference: Mutex<[Histogram; 4]>,
+    detections: AtomicU64,
+    objects: AtomicU64,
+    restarts: AtomicU64,
+    camera_open: AtomicBool,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            next_image: Mutex::new(Histogram::new(NEXT_IMAGE_BUCKETS)),
+            inference: Mutex::new(Stage::ALL.map(|_| Histogram::new(INFERENCE_BUCKETS))),
+            detections: AtomicU64::new(0),
+            objects: AtomicU64::new(0),
+            restarts: AtomicU64::new(0),
+            camera_open: AtomicBool::new(false),
+        }
+    }
+}
+
+impl Metrics {
+    /// Records a `next-image` request answered in `took`.
+    pub fn next_image(&self, took: Duration) {
+        self.next_image.lock().unwrap().observe(took.as_secs_f64());
+    }
+
+    pub fn inference(&self, stage: Stage, took: Duration) {
+        self.inference.lock().unwrap()[stage as usize].observe(took.as_secs_f64());
+    }
+
+    /// Records a frame the AI worker analysed.
+    pub fn detected(&self, signals: &Signals) {
+        self.detections.fetch_add(1, Ordering::Relaxed);
+        self.objects
+            .fetch_add(signals.objects as u64, Ordering::Relaxed);
+    }
+
+    /// Frames the AI worker analysed so far.
+    pub fn detections(&self) -> u64 {
+        self.detections.load(Ordering::Relaxed)
+    }
+
+    /// Records the AI worker being started again after it panicked.
+    pub fn worker_restarted(&self) {
+        self.restarts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Times the AI worker was started again so far.
+    pub fn restarts(&self) -> u64 {
+        self.restarts.load(Ordering::Relaxed)
+    }
+
+    pub fn set_camera_open(&self, open: bool) {
+        self.camera_open.store(open, Ordering::Relaxed);
+    }
+
+    /// Renders these counters and `status` in the Prometheus text format.
+    pub fn render(&self, status: &Status) -> String {
+        let mut out = String::new();
+        header(
+            &mut out,
+            "bongo_fps",
+            "gauge",
+            "Frame rate the daemon runs at.",
+        );
+        let _ = writeln!(out, "bongo_fps {}", status.fps);
+        header(
+            &mut out,
+            "bongo_ai_mode",
+            "gauge",
+            "1 when AI mode sets the rate.",
+        );
+        let _ = writeln!(out, "bongo_ai_mode {}", u8::from(status.mode == Mode::Ai));
+        header(
+            &mut out,
+            "bongo_session_locked",
+            "gauge",
+            "1 while the session is locked.",
+        );
+        let _ = writeln!(out, "bongo_session_locked {}", u8::from(status.locked));
+        header(
+            &mut out,
+            "bongo_uptime_seconds",
+            "gauge",
+            "Seconds since the daemon started.",
+        );
+        let _ = writeln!(out, "bongo_uptime_seconds {}", status.uptime_secs);
+
+        header(
+            &mut out,
+            "bongo_signals_total",
+            "counter",
+            "Signals sent per target.",
+        );
+        for t in &status.targets {
+            let _ = writeln!(
+                out,
+                "bongo_signals_total{{target=\"{}\"}} {}",
+                escape(&t.target),
+                t.fired
+            );
+        }
+        header(
+            &mut out,
+            "bongo_signal_failures_total",
+            "counter",
+            "Signals that failed per target.",
+        );
+        for t in &status.targets {
+            let _ = writeln!(
+                out,
+                "bongo_signal_failures_total{{target=\"{}\"}} {}",
+                escape(&t.target),
+                t.failed
+            );
+        }
+        header(&mut out, "bongo_ticks_total", "counter", "Scheduler ticks.");
+        let _ = writeln!(out, "bongo_ticks_total {}", status.ticks.ticks);
+        header(
+            &mut out,
+            "bongo_ticks_missed_total",
+            "counter",
+            "Scheduler ticks missed.",
+        );
+        let _ = writeln!(out, "bongo_ticks_missed_total {}", status.ticks.missed);
+
+        header(
+            &mut out,
+            "bongo_next_image_seconds",
+            "histogram",
+            "Time taken to answer next-image requests.",
+        );
+        self.next_image
+            .lock()
+            .unwrap()
+            .render(&mut out, "bongo_next_image_seconds", "");
+        header(
+            &mut out,
+            "bongo_inference_seconds",
+            "histogram",
+            "Time taken by each stage of an AI pass.",
+        );
+        let inference = self.inference.lock().unwrap().clone();
+        for (stage, histogram) in Stage::ALL.iter().zip(&inference) {
+            let labels = format!("stage=\"{}\"", stage.label());
+            histogram.render(&mut out, "bongo_inference_seconds", &labels);
+        }
+
+        header(
+            &mut out,
+            "bongo_detections_total",
+            "counter",
+            "Frames the AI worker analysed.",
+        );
+        let _ = writeln!(out, "bongo_detections_total {}", self.detections());
+        header(
+            &mut out,
+            "bongo_detected_objects_total",
+            "counter",
+            "Objects found in analysed frames.",
+        );
+        let _ = writeln!(
+            out,
+            "bongo_detected_objects_total {}",
+            self.objects.load(Ordering::Relaxed)
+        );
+        header(
+            &mut out,
+            "bongo_ai_errors_total",
+            "counter",
+            "Errors the AI worker ran into.",
+        );
+        let _ = writeln!(out, "bongo_ai_errors_total {}", status.ai.errors);
+        header(
+            &mut out,
+            "bongo_ai_worker_restarts_total",
+            "counter",
+            "Times the AI worker was restarted after a panic.",
+        );
+        let _ = writeln!(out, "bongo_ai_worker_restarts_total {}", self.restarts());
+        header(
+            &mut out,
+            "bongo_camera_open",
+            "gauge",
+            "1 while the AI worker holds the camera.",
+        );
+        let _ = writeln!(
+            out,
+            "bongo_camera_open {}",
+            u8::from(self.camera_open.load(Ordering::Relaxed))
+        );
+        out
+    }
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// The scrape endpoint; a Unix socket it listens on is removed on drop.
+pub struct MetricsServer {
+    addr: Option<SocketAddr>,
+    socket: Option<PathBuf>,
+}
+
+impl MetricsServer {
+    /// The address scrapes go to, unless it listens on a Unix socket.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.addr
+    }
+}
+
+impl Drop for MetricsServer {
+    fn drop(&mut self) {
+        if let Some(path) = &self.socket {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Answers scrapes of `/metrics` on `bind` with what `render` returns, each
+/// on its own thread, with as many at once as `limits` allows connections.
+pub fn serve<F>(bind: &Bind, render: F, limits: &Limits) -> io::Result<MetricsServer>
+where
+    F: Fn() -> String + Send + Sync + 'static,
+{
+    let render: Arc<dyn Fn() -> String + Send + Sync> = Arc::new(render);
+    let pool = Pool::new(limits.max_connections);
+    match bind {
+        Bind::Tcp(addr) => {
+            let listener = TcpListener::bind(addr)?;
+            let addr = listener.local_addr()?;
+            std::thread::spawn(move || {
+                let streams = listener.incoming().map(|stream| {
+                    let stream = stream?;
+                    stream.set_read_timeout(Some(SCRAPE_TIMEOUT))?;
+                    stream.set_write_timeout(Some(SCRAPE_TIMEOUT))?;
+                    Ok(stream)
+                });
+                scrape_all(streams, &render, &pool);
+            });
+            Ok(MetricsServer {
+                addr: Some(addr),
+                socket: None,
+            })
+        }
+        Bind::Unix(path) => {
+            remove_stale_socket(path)?;
+            let listener = bind_private(path, |staged| UnixListener::bind(staged))?;
+            std::thread::spawn(move || {
+                let streams = listener.incoming().map(|stream| {
+                    let stream = stream?;
+                    stream.set_read_timeout(Some(SCRAPE_TIMEOUT))?;
+                    stream.set_write_timeout(Some(SCRAPE_TIMEOUT))?;
+                    Ok(stream)
+                });
+                scrape_all(streams, &render, &pool);
+            });
+            Ok(MetricsServer {
+                addr: None,
+                socket: Some(path.clone()),
+            })
+        }
+    }
+}
+
+fn scrape_all<S: Read + Write + Send + 'static>(
+    streams: impl Iterator<Item = io::Result<S>>,
+    render: &Arc<dyn Fn() -> String + Send + Sync>,
+    pool: &Pool,
+) {
+    for stream in streams {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                error!("failed to accept scrape: {e}");
+                continue;
+            }
+        };
+        let Some(slot) = pool.claim() else {
+            warn!(max = pool.max, "too many scrapes; refusing one");
+            answer(stream, "503 Service Unavailable", "too many scrapes\n");
+            continue;
+        };
+        let render = render.clone();
+        let spawned = std::thread::Builder::new()
+            .name("bongo-metrics".into())
+            .spawn(move || {
+                scrape(stream, &*render);
+                drop(slot);
+            });
+        if let Err(e) = spawned {
+            error!("failed to spawn scrape thread: {e}");
+        }
+    }
+}
+
+/// Answers one HTTP request, closing the connection after it.
+fn scrape<S: Read + Write>(mut stream: S, render: &dyn Fn() -> String) {
+    let mut head = BufReader::new((&mut stream).take(MAX_HEAD));
+    let mut request_line = String::new();
+    if let Err(e) = head.read_line(&mut request_line) {
+        debug!("failed to read scrape: {e}");
+        return;
+    }
+    // Drain the headers; nothing in them matters.
+    let mut line = String::new();
+    while head.read_line(&mut line).is_ok_and(|n| n > 0) && line.trim_end() != "" {
+        line.clear();
+    }
+    drop(head);
+    let mut parts = request_line.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some("GET"), Some("/metrics" | "/")) => answer(stream, "200 OK", &render()),
+        (Some("GET"), Some(_)) => answer(stream, "404 Not Found", "not found\n"),
+        _ => answer(stream, "405 Method Not Allowed", "only GET is served\n"),
+    }
+}
+
+fn answer<S: Write>(mut stream: S, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    if let Err(e) = stream.write_all(response.as_bytes()) {
+        debug!("failed to answer scrape: {e}");
+    }
+}
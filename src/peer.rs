@@ -0,0 +1,94 @@
+use std::fmt;
+use std::io;
+use std::os::fd::AsRawFd;
+use std::os::unix::net::UnixStream;
+
+/// Who is on the other end of a connection, as reported by the kernel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Peer {
+    pub uid: u32,
+    /// Only known on Linux.
+    pub pid: Option<u32>,
+}
+
+impl fmt::Display for Peer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "uid {}", self.uid)?;
+        if let Some(pid) = self.pid {
+            write!(f, " (pid {pid})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the peer's credentials with `SO_PEERCRED`.
+#[cfg(target_os = "linux")]
+pub fn peer_of(stream: &UnixStream) -> io::Result<Peer> {
+    let mut cred = libc::ucred {
+        pid: 0,
+        uid: 0,
+        gid: 0,
+    };
+    let mut len = std::mem::size_of::<libc::ucred>() as libc::socklen_t;
+    // SAFETY: getsockopt writes at most `len` bytes into `cred`, which is a
+    // ucred of exactly that size and outlives the call.
+    let rc = unsafe {
+        libc::getsockopt(
+            stream.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_PEERCRED,
+            &mut cred as *mut libc::ucred as *mut libc::c_void,
+            &mut len,
+        )
+    };
+    if rc != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(Peer {
+        uid: cred.uid,
+        pid: u32::try_from(cred.pid).ok().filter(|&pid| pid != 0),
+    })
+}
+
+/// Reads the peer's credentials with `getpeereid`.
+#[cfg(not(target_os = "linux"))]
+pub fn peer_of(stream: &UnixStream) -> io::Result<Peer> {
+    let mut uid = 0;
+    let mut gid = 0;
+    // SAFETY: getpeereid only writes the two ids, both live locals.
+    if unsafe { libc::getpeereid(stream.as_raw_fd(), &mut uid, &mut gid) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(Peer { uid, pid: None })
+}
+
+/// The users allowed to control the daemon.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AllowList {
+    uids: Vec<u32>,
+}
+
+impl AllowList {
+    /// Only the user the daemon runs as.
+    pub fn same_user() -> Self {
+        // SAFETY: geteuid cannot fail and touches no memory of ours.
+        Self::uids([unsafe { libc::geteuid() }])
+    }
+
+    pub fn uids(uids: impl IntoIterator<Item = u32>) -> Self {
+        let mut uids: Vec<u32> = uids.into_iter().collect();
+        uids.sort_unstable();
+        uids.dedup();
+        Self { uids }
+    }
+
+    /// These users plus `extra`.
+    pub fn with(mut self, extra: impl IntoIterator<Item = u32>) -> Self {
+        self.uids.extend(extra);
+        Self::uids(self.uids)
+    }
+
+    pub fn allows(&self, peer: &Peer) -> bool {
+        self.uids.contains(&peer.uid)
+    }
+}
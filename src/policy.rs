@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Signals gathered by the AI worker from a single frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Signals {
+    /// Raw detection count reported by the object model.
+    pub objects: usize,
+    /// Faces looking at the screen, present when the face backend runs.
+    pub facing: Option<usize>,
+    /// Drumming intensity from 0 to 1, present when the pose backend runs and
+    /// sees someone's forearms.
+    pub drumming: Option<f32>,
+    /// Mean frame luminance from 0 to 1, present when a dark threshold is set.
+    pub brightness: Option<f32>,
+}
+
+const BASE_FPS: f32 = 5.0;
+const WEIGHT: f32 = 20.0;
+/// FPS cap applied while the room is dark.
+pub const SLEEPY_FPS: f32 = 1.0;
+
+/// Tunables for turning signals into an FPS value.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FpsPolicy {
+    /// Luminance below which the cat gets sleepy and the FPS is capped.
+    pub dark_threshold: Option<f32>,
+}
+
+impl FpsPolicy {
+    /// Maps the signals of one frame to a target FPS.
+    ///
+    /// Drumming intensity from the pose backend drives the FPS directly so the
+    /// cat keeps time with the typing. Otherwise faces looking at the screen
+    /// take precedence over the object count when the face backend is
+    /// enabled, so people walking past do not speed up the cat. A dark room
+    /// caps the result at [`SLEEPY_FPS`].
+    pub fn fps(&self, signals: &Signals, ratio: f32) -> f32 {
+        let fps = match signals.drumming {
+            Some(drumming) => BASE_FPS + (30.0 - BASE_FPS) * drumming,
+            None => {
+                let count = signals.facing.unwrap_or(signals.objects);
+                BASE_FPS + WEIGHT * ratio * count as f32
+            }
+        };
+        let fps = match (signals.brightness, self.dark_threshold) {
+            (Some(brightness), Some(threshold)) if brightness < threshold => fps.min(SLEEPY_FPS),
+            _ => fps,
+        };
+        fps.clamp(0.5, 30.0)
+    }
+}
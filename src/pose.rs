@@ -0,0 +1,137 @@
+use candle_core::{DType, Tensor};
+
+/// COCO keypoint indices used for the arms.
+const LEFT_ELBOW: usize = 7;
+const RIGHT_ELBOW: usize = 8;
+const LEFT_WRIST: usize = 9;
+const RIGHT_WRIST: usize = 10;
+const KEYPOINTS: usize = 17;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Keypoint {
+    pub x: f32,
+    pub y: f32,
+    pub confidence: f32,
+}
+
+/// A person found by the pose backend.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pose {
+    pub score: f32,
+    pub keypoints: [Keypoint; KEYPOINTS],
+}
+
+/// Elbow and wrist positions of one person, left arm first.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Arms {
+    pub elbows: [Option<(f32, f32)>; 2],
+    pub wrists: [Option<(f32, f32)>; 2],
+}
+
+/// Decodes a YOLOv8-pose output of shape `[1, 56, N]` into the poses scoring
+/// at least `confidence`, best first.
+///
+/// Each candidate holds `cx, cy, w, h, score` followed by 17 COCO keypoints
+/// as `x, y, confidence`.
+pub fn decode_poses(output: &Tensor, confidence: f32) -> candle_core::Result<Vec<Pose>> {
+    let rows = output.squeeze(0)?.to_dtype(DType::F32)?.to_vec2::<f32>()?;
+    if rows.len() != 5 + KEYPOINTS * 3 {
+        candle_core::bail!("unexpected pose model output with {} channels", rows.len());
+    }
+    let mut poses = Vec::new();
+    for (i, &score) in rows[4].iter().enumerate() {
+        if score < confidence {
+            continue;
+        }
+        let keypoints = std::array::from_fn(|k| Keypoint {
+            x: rows[5 + k * 3][i],
+            y: rows[6 + k * 3][i],
+            confidence: rows[7 + k * 3][i],
+        });
+        poses.push(Pose { score, keypoints });
+    }
+    poses.sort_by(|a, b| b.score.total_cmp(&a.score));
+    Ok(poses)
+}
+
+impl Arms {
+    /// Whether an elbow and wrist of the same arm were both detected.
+    pub fn any_forearm(&self) -> bool {
+        (0..2).any(|side| self.elbows[side].is_some() && self.wrists[side].is_some())
+    }
+}
+
+impl Pose {
+    /// Returns the arm keypoints detected with at least `confidence`.
+    pub fn arms(&self, confidence: f32) -> Arms {
+        let point = |k: usize| {
+            let kp = self.keypoints[k];
+            (kp.confidence >= confidence).then_some((kp.x, kp.y))
+        };
+        Arms {
+            elbows: [point(LEFT_ELBOW), point(RIGHT_ELBOW)],
+            wrists: [point(LEFT_WRIST), point(RIGHT_WRIST)],
+        }
+    }
+}
+
+/// Forearm movement per frame, in forearm lengths, that counts as full
+/// drumming intensity.
+const FULL_MOTION: f32 = 0.5;
+/// Weight of the newest frame in the smoothed intensity.
+const SMOOTHING: f32 = 0.5;
+
+/// Turns hand motion between frames into a smoothed drumming intensity.
+#[derive(Debug, Default)]
+pub struct DrummingTracker {
+    previous: Option<Arms>,
+    intensity: f32,
+}
+
+impl DrummingTracker {
+    /// Feeds the arms seen in the latest frame and returns the drumming
+    /// intensity between 0 (still) and 1, or `None` when no forearm is in
+    /// view, so the policy can fall back on the other signals.
+    ///
+    /// Wrists are measured relative to their elbows and scaled by forearm
+    /// length, so leaning towards the camera or shifting in the chair does
+    /// not register as typing.
+    pub fn update(&mut self, arms: Option<Arms>) -> Option<f32> {
+        let motion = match (self.previous, arms) {
+            (Some(prev), Some(cur)) => forearm_motion(&prev, &cur),
+            _ => None,
+        };
+        self.previous = arms;
+        let target = motion.map_or(0.0, |m| (m / FULL_MOTION).min(1.0));
+        self.intensity += SMOOTHING * (target - self.intensity);
+        arms.filter(Arms::any_forearm).map(|_| self.intensity)
+    }
+
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+}
+
+fn forearm_motion(prev: &Arms, cur: &Arms) -> Option<f32> {
+    let mut total = 0.0;
+    let mut arms = 0;
+    for side in 0..2 {
+        let (Some(pe), Some(pw), Some(ce), Some(cw)) = (
+            prev.elbows[side],
+            prev.wrists[side],
+            cur.elbows[side],
+            cur.wrists[side],
+        ) else {
+            continue;
+        };
+        let length = ((cw.0 - ce.0).hypot(cw.1 - ce.1) + (pw.0 - pe.0).hypot(pw.1 - pe.1)) / 2.0;
+        if length <= f32::EPSILON {
+            continue;
+        }
+        let dx = (cw.0 - ce.0) - (pw.0 - pe.0);
+        let dy = (cw.1 - ce.1) - (pw.1 - pe.1);
+        total += dx.hypot(dy) / length;
+        arms += 1;
+    }
+    (arms > 0).then(|| total / arms as f32)
+}
@@ -0,0 +1,403 @@
+use clap::Args;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::io;
+#[cfg(target_os = "linux")]
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::{debug, error, info, trace};
+
+/// Extra conditions a target process must meet besides its name.
+#[derive(Args, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MatchRules {
+    /// Only match processes running this executable
+    #[arg(long)]
+    pub exe: Option<PathBuf>,
+    /// Only match processes whose command line matches this regex
+    #[arg(long, allow_hyphen_values = true)]
+    pub cmdline: Option<String>,
+    /// Only match processes owned by this UID (default: the daemon's own)
+    #[arg(long, conflicts_with = "any_uid")]
+    pub uid: Option<u32>,
+    /// Match processes of every user
+    #[arg(long)]
+    pub any_uid: bool,
+    /// Only match processes in this cgroup path or systemd unit
+    #[arg(long)]
+    pub cgroup: Option<String>,
+}
+
+impl MatchRules {
+    /// Combines two rule sets, with `overrides` taking precedence. A UID in
+    /// `overrides` also lifts an `any_uid` from `self`.
+    pub fn merge(self, overrides: MatchRules) -> MatchRules {
+        MatchRules {
+            exe: overrides.exe.or(self.exe),
+            cmdline: overrides.cmdline.or(self.cmdline),
+            uid: overrides.uid.or(self.uid),
+            any_uid: overrides.any_uid || (self.any_uid && overrides.uid.is_none()),
+            cgroup: overrides.cgroup.or(self.cgroup),
+        }
+    }
+}
+
+/// Decides which processes count as targets.
+#[derive(Clone, Debug)]
+pub struct ProcessMatcher {
+    name: String,
+    exe: Option<PathBuf>,
+    cmdline: Option<Regex>,
+    uid: Option<u32>,
+    cgroup: Option<String>,
+}
+
+impl ProcessMatcher {
+    pub fn new(name: impl Into<String>, rules: &MatchRules) -> Result<Self, regex::Error> {
+        let cmdline = rules.cmdline.as_deref().map(Regex::new).transpose()?;
+        let uid = if rules.any_uid {
+            None
+        } else {
+            // SAFETY: getuid has no preconditions and cannot fail.
+            Some(rules.uid.unwrap_or_else(|| unsafe { libc::getuid() }))
+        };
+        Ok(Self {
+            name: name.into(),
+            exe: rules.exe.clone(),
+            cmdline,
+            uid,
+            cgroup: rules.cgroup.clone(),
+        })
+    }
+
+    /// Matches by name only, limited to the daemon's own UID.
+    pub fn by_name(name: impl Into<String>) -> Self {
+        Self::new(name, &MatchRules::default()).expect("no regex to compile")
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// A target process, pinned by a pidfd where the kernel supports it so a
+/// recycled PID is never signalled by mistake.
+pub struct TrackedProcess {
+    pid: u32,
+    #[cfg(target_os = "linux")]
+    pidfd: Option<OwnedFd>,
+}
+
+impl TrackedProcess {
+    pub fn open(pid: u32) -> io::Result<Self> {
+        #[cfg(target_os = "linux")]
+        {
+            // SAFETY: pidfd_open takes a pid and flags and returns a new fd or -1.
+            let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid as libc::pid_t, 0) };
+            if fd >= 0 {
+                // SAFETY: the syscall returned a fresh descriptor we now own.
+                let pidfd = unsafe { OwnedFd::from_raw_fd(fd as i32) };
+                return Ok(Self {
+                    pid,
+                    pidfd: Some(pidfd),
+                });
+            }
+            let err = io::Error::last_os_error();
+            if err.raw_os_error() != Some(libc::ENOSYS) {
+                return Err(err);
+            }
+            debug!(pid, "pidfd_open unsupported; falling back to kill");
+            Ok(Self { pid, pidfd: None })
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            Ok(Self { pid })
+        }
+    }
+
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Returns `false` once the process has exited, even if it has not been
+    /// reaped yet.
+    pub fn is_alive(&self) -> bool {
+        #[cfg(target_os = "linux")]
+        if let Some(fd) = &self.pidfd {
+            // A pidfd becomes readable when its process terminates.
+            let mut pfd = libc::pollfd {
+                fd: fd.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            // SAFETY: pfd is a valid pollfd for the duration of the call.
+            let ready = unsafe { libc::poll(&mut pfd, 1, 0) };
+            return ready == 0;
+        }
+        // SAFETY: signal 0 only checks that the pid exists.
+        unsafe { libc::kill(self.pid as libc::pid_t, 0) == 0 }
+    }
+
+    pub fn signal(&self, signal: i32) -> io::Result<()> {
+        #[cfg(target_os = "linux")]
+        if let Some(fd) = &self.pidfd {
+            // SAFETY: pidfd_send_signal with a null siginfo behaves like kill.
+            let res = unsafe {
+                libc::syscall(
+                    libc::SYS_pidfd_send_signal,
+                    fd.as_raw_fd(),
+                    signal,
+                    std::ptr::null::<libc::siginfo_t>(),
+                    0,
+                )
+            };
+            return if res == 0 {
+                Ok(())
+            } else {
+                Err(io::Error::last_os_error())
+            };
+        }
+        // SAFETY: kill has no memory-safety preconditions.
+        if unsafe { libc::kill(self.pid as libc::pid_t, signal) } == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+}
+
+/// What [`ProcessTracker::signal_all`] managed to deliver.
+#[derive(Debug, Default)]
+pub struct Signalled {
+    /// Processes the signal reached.
+    pub sent: usize,
+    /// Processes the signal could not be delivered to, and why.
+    pub failed: Vec<(u32, io::Error)>,
+}
+
+/// Tracks every running process accepted by a [`ProcessMatcher`].
+///
+/// Discovery scans the process table; once found, processes are followed
+/// through their pidfds without touching the table again.
+pub struct ProcessTracker {
+    matcher: ProcessMatcher,
+    targets: Vec<TrackedProcess>,
+}
+
+impl ProcessTracker {
+    pub fn new(matcher: ProcessMatcher) -> Self {
+        Self {
+            matcher,
+            targets: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.matcher.name()
+    }
+
+    pub fn pids(&self) -> Vec<u32> {
+        self.targets.iter().map(TrackedProcess::pid).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Scans for matching processes and starts tracking new ones. Returns the
+    /// number of processes added.
+    pub fn discover(&mut self) -> usize {
+        let mut added = 0;
+        for pid in find_processes(&self.matcher) {
+            if self.targets.iter().any(|t| t.pid == pid) {
+                continue;
+            }
+            match TrackedProcess::open(pid) {
+                // The pid may have been recycled between the scan and
+                // pidfd_open; check again now that it is pinned.
+                Ok(target) if process_matches(pid, &self.matcher) => {
+                    debug!(pid, proc = %self.matcher.name, "tracking process");
+                    self.targets.push(target);
+                    added += 1;
+                }
+                Ok(_) => trace!(pid, "process changed before it could be tracked"),
+                Err(e) => debug!(pid, "failed to open process: {e}"),
+            }
+        }
+        added
+    }
+
+    /// Blocks until at least one matching process is tracked.
+    pub fn wait_for_process(&mut self, interval: Duration) {
+        while self.discover() == 0 && self.is_empty() {
+            info!(proc = %self.matcher.name, "process not found; waiting");
+            std::thread::sleep(interval);
+        }
+    }
+
+    /// Sends `signal` to every live target and forgets the ones that exited
+    /// or could not be signalled. A process that exits before the signal
+    /// reaches it is not a failure.
+    pub fn signal_all(&mut self, signal: i32) -> Signalled {
+        let mut signalled = Signalled::default();
+        self.targets.retain(|target| {
+            if !target.is_alive() {
+                debug!(pid = target.pid, "process exited");
+                return false;
+            }
+            trace!(pid = target.pid, "signalling");
+            match target.signal(signal) {
+                Ok(()) => {
+                    signalled.sent += 1;
+                    true
+                }
+                Err(e) if e.raw_os_error() == Some(libc::ESRCH) => {
+                    debug!(pid = target.pid, "process exited");
+                    false
+                }
+                Err(e) => {
+                    error!(pid = target.pid, "failed to send signal: {e}");
+                    signalled.failed.push((target.pid, e));
+                    false
+                }
+            }
+        });
+        signalled
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn process_name_matches(pid: u32, name: &str) -> bool {
+    let Ok(comm) = std::fs::read_to_string(format!("/proc/{pid}/comm")) else {
+        return false;
+    };
+    let comm = comm.trim_end_matches('\n');
+    if comm == name {
+        return true;
+    }
+    // The kernel truncates comm to 15 bytes; fall back to argv[0] for
+    // longer names.
+    if name.len() > comm.len() && name.starts_with(comm) {
+        if let Some(argv0) = read_cmdline(pid).and_then(|args| args.into_iter().next()) {
+            return argv0.rsplit('/').next() == Some(name);
+        }
+    }
+    false
+}
+
+#[cfg(target_os = "linux")]
+fn read_cmdline(pid: u32) -> Option<Vec<String>> {
+    let raw = std::fs::read(format!("/proc/{pid}/cmdline")).ok()?;
+    Some(
+        raw.split(|&b| b == 0)
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| String::from_utf8_lossy(arg).into_owned())
+            .collect(),
+    )
+}
+
+#[cfg(target_os = "linux")]
+fn read_uid(pid: u32) -> Option<u32> {
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    let line = status.lines().find(|l| l.starts_with("Uid:"))?;
+    line.split_whitespace().nth(1)?.parse().ok()
+}
+
+#[cfg(target_os = "linux")]
+fn in_cgroup(pid: u32, wanted: &str) -> bool {
+    let Ok(cgroups) = std::fs::read_to_string(format!("/proc/{pid}/cgroup")) else {
+        return false;
+    };
+    // Lines look like `0::/user.slice/user-1000.slice/app.slice/foo.service`.
+    cgroups
+        .lines()
+        .filter_map(|l| l.splitn(3, ':').nth(2))
+        .any(|path| path == wanted || path.split('/').any(|unit| unit == wanted))
+}
+
+/// Checks the cheap name first and reads the other attributes only when a
+/// rule needs them.
+#[cfg(target_os = "linux")]
+fn process_matches(pid: u32, matcher: &ProcessMatcher) -> bool {
+    if !process_name_matches(pid, &matcher.name) {
+        return false;
+    }
+    if let Some(uid) = matcher.uid {
+        if read_uid(pid) != Some(uid) {
+            return false;
+        }
+    }
+    if let Some(exe) = &matcher.exe {
+        match std::fs::read_link(format!("/proc/{pid}/exe")) {
+            Ok(path) if &path == exe => {}
+            _ => return false,
+        }
+    }
+    if let Some(re) = &matcher.cmdline {
+        match read_cmdline(pid) {
+            Some(args) if re.is_match(&args.join(" ")) => {}
+            _ => return false,
+        }
+    }
+    if let Some(cgroup) = &matcher.cgroup {
+        if !in_cgroup(pid, cgroup) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Lists the pids accepted by `matcher`, reading `/proc/<pid>/comm` first.
+#[cfg(target_os = "linux")]
+pub fn find_processes(matcher: &ProcessMatcher) -> Vec<u32> {
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.file_name().to_str()?.parse::<u32>().ok())
+        .filter(|&pid| process_matches(pid, matcher))
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn process_matches(pid: u32, matcher: &ProcessMatcher) -> bool {
+    find_processes(matcher).contains(&pid)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn find_processes(matcher: &ProcessMatcher) -> Vec<u32> {
+    use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+    if matcher.cgroup.is_some() {
+        tracing::warn!("cgroup matching is only supported on Linux");
+        return Vec::new();
+    }
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_exe(UpdateKind::OnlyIfNotSet)
+            .with_cmd(UpdateKind::OnlyIfNotSet)
+            .with_user(UpdateKind::OnlyIfNotSet),
+    );
+    sys.processes_by_exact_name(std::ffi::OsStr::new(&matcher.name))
+        .filter(|p| match matcher.uid {
+            Some(uid) => p.user_id().map(|u| **u) == Some(uid),
+            None => true,
+        })
+        .filter(|p| match &matcher.exe {
+            Some(exe) => p.exe() == Some(exe.as_path()),
+            None => true,
+        })
+        .filter(|p| match &matcher.cmdline {
+            Some(re) => {
+                let args: Vec<_> = p.cmd().iter().map(|a| a.to_string_lossy()).collect();
+                re.is_match(&args.join(" "))
+            }
+            None => true,
+        })
+        .map(|p| p.pid().as_u32())
+        .collect()
+}
@@ -0,0 +1,182 @@
+use crate::target::Tick;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, trace};
+
+/// The frame rate shared between the IPC thread, the AI worker and the
+/// scheduler. Changing it wakes a scheduler that is waiting for its next tick,
+/// and closing it on shutdown makes the scheduler stop.
+pub struct Rate {
+    state: Mutex<RateState>,
+    changed: Condvar,
+}
+
+struct RateState {
+    fps: f32,
+    generation: u64,
+    closed: bool,
+}
+
+impl Rate {
+    pub fn new(fps: f32) -> Self {
+        Self {
+            state: Mutex::new(RateState {
+                fps,
+                generation: 0,
+                closed: false,
+            }),
+            changed: Condvar::new(),
+        }
+    }
+
+    pub fn get(&self) -> f32 {
+        self.state.lock().unwrap().fps
+    }
+
+    pub fn set(&self, fps: f32) {
+        let mut state = self.state.lock().unwrap();
+        if state.fps != fps {
+            state.fps = fps;
+            state.generation += 1;
+            self.changed.notify_all();
+        }
+    }
+
+    /// Wakes every waiter for good; the scheduler returns no more ticks.
+    pub fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        self.changed.notify_all();
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.state.lock().unwrap().closed
+    }
+
+    /// Bumped on every change; lets waiters tell whether they missed one.
+    pub fn generation(&self) -> u64 {
+        self.state.lock().unwrap().generation
+    }
+
+    /// Waits up to `timeout` for the rate to move past `seen` or close.
+    /// Returns whether it did.
+    pub fn wait_changed(&self, seen: u64, timeout: Duration) -> bool {
+        let state = self.state.lock().unwrap();
+        let (state, _) = self
+            .changed
+            .wait_timeout_while(state, timeout, |s| s.generation == seen && !s.closed)
+            .unwrap();
+        state.generation != seen || state.closed
+    }
+}
+
+/// Monotonic time source for the scheduler, swappable in tests.
+pub trait Clock {
+    fn now(&self) -> Instant;
+    /// Blocks until `deadline` passes, `rate` moves past generation `seen` or
+    /// it is closed.
+    fn wait_until(&self, rate: &Rate, seen: u64, deadline: Instant);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn wait_until(&self, rate: &Rate, seen: u64, deadline: Instant) {
+        let now = Instant::now();
+        if deadline > now {
+            rate.wait_changed(seen, deadline - now);
+        }
+    }
+}
+
+/// How far the ticks strayed from their deadlines.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TickStats {
+    pub ticks: u64,
+    /// Deadlines that passed without a tick because the loop fell behind.
+    pub missed: u64,
+    pub last_jitter: Duration,
+    pub max_jitter: Duration,
+    pub total_jitter: Duration,
+}
+
+impl TickStats {
+    pub fn mean_jitter(&self) -> Duration {
+        if self.ticks == 0 {
+            Duration::ZERO
+        } else {
+            self.total_jitter.div_f64(self.ticks as f64)
+        }
+    }
+}
+
+/// Paces the daemon's ticks against absolute deadlines, so time spent
+/// firing targets does not stretch the period. Ticks the loop is too late
+/// for are coalesced into one and counted as missed.
+pub struct Scheduler<C: Clock> {
+    clock: C,
+    /// Deadline of the tick that fired last.
+    last: Option<Instant>,
+    next: Option<Instant>,
+    seen: u64,
+    stats: TickStats,
+}
+
+impl<C: Clock> Scheduler<C> {
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            last: None,
+            next: None,
+            seen: 0,
+            stats: TickStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> TickStats {
+        self.stats
+    }
+
+    /// Blocks until the next tick is due, or returns `None` once `rate` is
+    /// closed. The first tick fires immediately.
+    pub fn wait(&mut self, rate: &Rate) -> Option<Tick> {
+        loop {
+            if rate.is_closed() {
+                return None;
+            }
+            let generation = rate.generation();
+            let fps = rate.get();
+            let period = Duration::from_secs_f64(1.0 / f64::from(fps));
+            let now = self.clock.now();
+            if generation != self.seen {
+                // Re-phase from the last tick so a faster rate applies now
+                // rather than after the old period runs out.
+                self.seen = generation;
+                self.next = self.last.map(|last| (last + period).max(now));
+                trace!(fps, "rate changed; rescheduling");
+            }
+            let deadline = *self.next.get_or_insert(now);
+            if deadline > now {
+                self.clock.wait_until(rate, generation, deadline);
+                continue;
+            }
+
+            let late = now - deadline;
+            let missed = (late.as_secs_f64() / period.as_secs_f64()) as u64;
+            if missed > 0 {
+                debug!(missed, ?late, "fell behind; coalescing ticks");
+            }
+            self.stats.ticks += 1;
+            self.stats.missed += missed;
+            self.stats.last_jitter = late;
+            self.stats.max_jitter = self.stats.max_jitter.max(late);
+            self.stats.total_jitter += late;
+            self.last = Some(deadline);
+            self.next = Some(deadline + period.mul_f64((missed + 1) as f64));
+            return Some(Tick { fps });
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use crate::ipc::{encode_response, ErrorKind, IpcError, Response};
+use crate::peer::{peer_of, AllowList};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, error, trace, warn};
+
+/// How long a client may take to send its next request, in total.
+pub const READ_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long a client may take to accept an answer.
+pub const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+/// The largest request accepted, in bytes.
+pub const MAX_MESSAGE: usize = 64 * 1024;
+/// Connections served at once; further clients are refused as busy.
+pub const MAX_CONNECTIONS: usize = 32;
+/// Event subscribers served at once, apart from the other connections.
+pub const MAX_SUBSCRIBERS: usize = 16;
+
+/// Bounds on what a single client can cost the daemon.
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    pub read_timeout: Duration,
+    pub write_timeout: Duration,
+    pub max_message: usize,
+    pub max_connections: usize,
+    pub max_subscribers: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            read_timeout: READ_TIMEOUT,
+            write_timeout: WRITE_TIMEOUT,
+            max_message: MAX_MESSAGE,
+            max_connections: MAX_CONNECTIONS,
+            max_subscribers: MAX_SUBSCRIBERS,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ReadError {
+    /// The request grew past the size limit; the rest of it is unread.
+    TooLarge(usize),
+    /// The client hung up mid-request, stalled past the deadline or the
+    /// socket failed.
+    Io(io::Error),
+}
+
+/// Reads a socket until a deadline, however slowly the bytes trickle in.
+struct DeadlineReader {
+    stream: UnixStream,
+    deadline: Instant,
+}
+
+impl Read for DeadlineReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "request deadline passed",
+            ));
+        }
+        self.stream.set_read_timeout(Some(left))?;
+        self.stream.read(buf)
+    }
+}
+
+/// A client connection with deadlines set.
+pub struct Connection {
+    reader: BufReader<DeadlineReader>,
+    stream: UnixStream,
+    read_timeout: Duration,
+    max_message: usize,
+    slot: Slot,
+    subscribers: Pool,
+}
+
+impl Connection {
+    fn new(stream: UnixStream, limits: &Limits, slot: Slot, subscribers: Pool) -> io::Result<Self> {
+        stream.set_write_timeout(Some(limits.write_timeout))?;
+        let reader = DeadlineReader {
+            stream: stream.try_clone()?,
+            deadline: Instant::now(),
+        };
+        Ok(Self {
+            reader: BufReader::new(reader),
+            stream,
+            read_timeout: limits.read_timeout,
+            max_message: limits.max_message,
+            slot,
+            subscribers,
+        })
+    }
+
+    /// Reads the next request: a line, or everything up to EOF from clients
+    /// that send a single message and shut down their side. Returns `None`
+    /// once the client is done.
+    pub fn read_message(&mut self) -> Result<Option<Vec<u8>>, ReadError> {
+        self.read_within(self.read_timeout)
+    }
+
+    fn read_within(&mut self, timeout: Duration) -> Result<Option<Vec<u8>>, ReadError> {
+        self.reader.get_mut().deadline = Instant::now() + timeout;
+        loop {
+            let mut buf = Vec::new();
+            let limit = self.max_message as u64 + 1;
+            let n = (&mut self.reader)
+                .take(limit)
+                .read_until(b'\n', &mut buf)
+                .map_err(ReadError::Io)?;
+            if n == 0 {
+                return Ok(None);
+            }
+            if buf.last() != Some(&b'\n') && buf.len() > self.max_message {
+                return Err(ReadError::TooLarge(self.max_message));
+            }
+            if buf.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            return Ok(Some(buf));
+        }
+    }
+
+    /// Whether the client has hung up, checked without waiting for a
+    /// request. Anything it sent meanwhile is discarded; for connections
+    /// that only stream to the client.
+    pub fn hung_up(&mut self) -> bool {
+        loop {
+            match self.read_within(Duration::from_millis(1)) {
+                Ok(Some(_)) => continue,
+                Ok(None) | Err(ReadError::TooLarge(_)) => return true,
+                Err(ReadError::Io(e)) => {
+                    return !matches!(
+                        e.kind(),
+                        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                    )
+                }
+            }
+        }
+    }
+
+    /// A handle for answering, shareable with threads that answer later.
+    pub fn writer(&self) -> io::Result<UnixStream> {
+        self.stream.try_clone()
+    }
+
+    /// Moves the connection to the subscribers' pool, freeing its slot for
+    /// other clients; refused as busy when that pool is full.
+    pub fn subscribe(&mut self) -> Result<(), IpcError> {
+        self.slot = self.subscribers.claim().ok_or_else(|| {
+            warn!(max = self.subscribers.max, "too many subscribers");
+            IpcError::new(
+                ErrorKind::Busy,
+                format!("daemon is serving {} subscribers", self.subscribers.max),
+            )
+        })?;
+        Ok(())
+    }
+}
+
+/// A bounded number of slots shared by connections.
+#[derive(Clone)]
+pub struct Pool {
+    active: Arc<AtomicUsize>,
+    pub max: usize,
+}
+
+impl Pool {
+    pub fn new(max: usize) -> Self {
+        Self {
+            active: Arc::new(AtomicUsize::new(0)),
+            max,
+        }
+    }
+
+    /// Takes a slot, or `None` when all are taken.
+    pub fn claim(&self) -> Option<Slot> {
+        if self.active.fetch_add(1, Ordering::SeqCst) >= self.max {
+            self.active.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        Some(Slot(self.active.clone()))
+    }
+}
+
+/// Releases a slot when the connection holding it ends.
+pub struct Slot(Arc<AtomicUsize>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn refuse(mut stream: UnixStream, limits: &Limits, error: IpcError) {
+    let _ = stream.set_write_timeout(Some(limits.write_timeout));
+    let _ = stream.write_all(&encode_response(0, Response::Error(error)));
+}
+
+/// Whether the client may talk to the daemon, judged by its credentials.
+fn authorize(stream: &UnixStream, allow: &AllowList) -> Result<(), IpcError> {
+    let peer = peer_of(stream).map_err(|e| {
+        error!("failed to read peer credentials: {e}");
+        IpcError::new(ErrorKind::PermissionDenied, "peer credentials unavailable")
+    })?;
+    if !allow.allows(&peer) {
+        warn!(%peer, "refusing connection from a user not on the allow-list");
+        return Err(IpcError::new(
+            ErrorKind::PermissionDenied,
+            format!("uid {} may not control this daemon", peer.uid),
+        ));
+    }
+    trace!(%peer, "peer authorized");
+    Ok(())
+}
+
+/// Accepts connections from the `allow`ed users until `stop` is set, serving
+/// each on its own thread so a stalled client cannot hold up the others.
+pub fn serve<H>(
+    listener: UnixListener,
+    limits: Limits,
+    allow: AllowList,
+    stop: Arc<AtomicBool>,
+    handler: H,
+) where
+    H: Fn(Connection) + Send + Sync + 'static,
+{
+    let handler = Arc::new(handler);
+    let connections = Pool::new(limits.max_connections);
+    let subscribers = Pool::new(limits.max_subscribers);
+    for stream in listener.incoming() {
+        if stop.load(Ordering::SeqCst) {
+            debug!("IPC server stopped");
+            break;
+        }
+        let stream = match stream {
+            Ok(s) => s,
+            Err(e) => {
+                error!("failed to accept connection: {e}");
+                continue;
+            }
+        };
+        if let Err(denied) = authorize(&stream, &allow) {
+            refuse(stream, &limits, denied);
+            continue;
+        }
+        let Some(slot) = connections.claim() else {
+            warn!(
+                max = limits.max_connections,
+                "too many clients; refusing connection"
+            );
+            let busy = IpcError::new(
+                ErrorKind::Busy,
+                format!("daemon is serving {} clients", limits.max_connections),
+            );
+            refuse(stream, &limits, busy);
+            continue;
+        };
+        debug!("connection accepted");
+        let conn = match Connection::new(stream, &limits, slot, subscribers.clone()) {
+            Ok(c) => c,
+            Err(e) => {
+                error!("failed to set up connection: {e}");
+                continue;
+            }
+        };
+        let handler = handler.clone();
+        let spawned = std::thread::Builder::new()
+            .name("bongo-ipc".into())
+            .spawn(move || handler(conn));
+        if let Err(e) = spawned {
+            error!("failed to spawn connection thread: {e}");
+        }
+    }
+}
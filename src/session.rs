@@ -0,0 +1,166 @@
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+/// Whether the session is locked, i.e. whether there is anything to animate.
+///
+/// Without logind the daemon treats the session as always locked and relies
+/// on finding the lockscreen process instead. Closing it on shutdown releases
+/// everything waiting for a lock.
+pub struct LockState {
+    state: Mutex<State>,
+    changed: Condvar,
+}
+
+struct State {
+    locked: bool,
+    closed: bool,
+}
+
+impl LockState {
+    pub fn new(locked: bool) -> Self {
+        Self {
+            state: Mutex::new(State {
+                locked,
+                closed: false,
+            }),
+            changed: Condvar::new(),
+        }
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.state.lock().unwrap().locked
+    }
+
+    /// Updates the state, returning whether it changed.
+    pub fn set(&self, locked: bool) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.locked == locked {
+            return false;
+        }
+        state.locked = locked;
+        self.changed.notify_all();
+        true
+    }
+
+    pub fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        self.changed.notify_all();
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.state.lock().unwrap().closed
+    }
+
+    /// Blocks until the session is locked, returning `false` instead when
+    /// the state is closed first.
+    pub fn wait_locked(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        let state = self
+            .changed
+            .wait_while(state, |s| !s.locked && !s.closed)
+            .unwrap();
+        !state.closed
+    }
+
+    /// Waits up to `timeout` for the state to become `locked`, returning
+    /// whether it did. Closing the state ends the wait early.
+    pub fn wait_for(&self, locked: bool, timeout: Duration) -> bool {
+        let state = self.state.lock().unwrap();
+        let (state, _) = self
+            .changed
+            .wait_timeout_while(state, timeout, |s| s.locked != locked && !s.closed)
+            .unwrap();
+        state.locked == locked
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub use logind::watch_logind;
+
+#[cfg(target_os = "linux")]
+mod logind {
+    use super::LockState;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tracing::{debug, error, info, trace};
+    use zbus::blocking::{Connection, MessageIterator, Proxy};
+    use zbus::zvariant::{OwnedObjectPath, OwnedValue};
+    use zbus::{message::Type, MatchRule};
+
+    const LOGIND: &str = "org.freedesktop.login1";
+    const SESSION_IFACE: &str = "org.freedesktop.login1.Session";
+
+    /// Looks up our session: `XDG_SESSION_ID` when set, else logind's `auto`
+    /// session, which also covers a daemon started from a user service.
+    fn session_path(conn: &Connection) -> zbus::Result<OwnedObjectPath> {
+        let id = std::env::var("XDG_SESSION_ID").unwrap_or_else(|_| "auto".to_string());
+        let reply = conn.call_method(
+            Some(LOGIND),
+            "/org/freedesktop/login1",
+            Some("org.freedesktop.login1.Manager"),
+            "GetSession",
+            &(id.as_str(),),
+        )?;
+        reply.body().deserialize()
+    }
+
+    /// Follows the session's lock state on `conn`, normally the system bus,
+    /// updating `state` from `LockedHint` and the `Lock`/`Unlock` signals.
+    ///
+    /// The initial state is read before returning; updates arrive on a
+    /// background thread.
+    pub fn watch_logind(conn: Connection, state: Arc<LockState>) -> zbus::Result<()> {
+        let path = session_path(&conn)?;
+        debug!(session = %path.as_str(), "following logind session");
+        let rule = MatchRule::builder()
+            .msg_type(Type::Signal)
+            .sender(LOGIND)?
+            .path(path.clone())?
+            .build();
+        // Subscribe before reading the hint so no change slips in between.
+        let signals = MessageIterator::for_match_rule(rule, &conn, None)?;
+        let session = Proxy::new(&conn, LOGIND, path, SESSION_IFACE)?;
+        state.set(session.get_property::<bool>("LockedHint")?);
+        info!(locked = state.is_locked(), "logind session state");
+
+        std::thread::spawn(move || {
+            for msg in signals {
+                let msg = match msg {
+                    Ok(m) => m,
+                    Err(e) => {
+                        error!("logind signal stream failed: {e}");
+                        break;
+                    }
+                };
+                let header = msg.header();
+                let locked = match header.member().map(|m| m.as_str()) {
+                    Some("Lock") => Some(true),
+                    Some("Unlock") => Some(false),
+                    Some("PropertiesChanged") => {
+                        type Changed = (String, HashMap<String, OwnedValue>, Vec<String>);
+                        match msg.body().deserialize::<Changed>() {
+                            Ok((iface, changed, invalidated)) if iface == SESSION_IFACE => {
+                                if let Some(v) = changed.get("LockedHint") {
+                                    bool::try_from(v).ok()
+                                } else if invalidated.iter().any(|p| p == "LockedHint") {
+                                    session.get_property::<bool>("LockedHint").ok()
+                                } else {
+                                    None
+                                }
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                trace!(member = ?header.member(), ?locked, "logind signal");
+                if let Some(locked) = locked {
+                    if state.set(locked) {
+                        info!(locked, "session lock state changed");
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,289 @@
+use crate::policy::Signals;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// What the AI worker is doing.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkerState {
+    /// Built without the `camera` feature.
+    Unavailable,
+    /// Waiting for the session to lock before opening the camera.
+    Waiting,
+    /// Loading models.
+    Starting,
+    /// AI mode is off.
+    Paused,
+    /// The session is unlocked and the camera released.
+    Idle,
+    Running,
+    /// Neither a camera nor a replay directory could be opened.
+    NoSource,
+    Stopped,
+}
+
+/// The most recent frame the AI worker analysed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Detection {
+    /// Seconds since the frame was captured.
+    pub age_secs: f64,
+    pub objects: usize,
+    pub facing: Option<usize>,
+    pub drumming: Option<f32>,
+    pub brightness: Option<f32>,
+    /// The rate the policy computed from the frame.
+    pub fps: f32,
+}
+
+impl Detection {
+    pub fn new(signals: &Signals, fps: f32, age: Duration) -> Self {
+        Self {
+            age_secs: age.as_secs_f64(),
+            objects: signals.objects,
+            facing: signals.facing,
+            drumming: signals.drumming,
+            brightness: signals.brightness,
+            fps,
+        }
+    }
+}
+
+impl fmt::Display for Detection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} objects", self.objects)?;
+        if let Some(facing) = self.facing {
+            write!(f, ", {facing} facing")?;
+        }
+        if let Some(drumming) = self.drumming {
+            write!(f, ", drumming {drumming:.2}")?;
+        }
+        if let Some(brightness) = self.brightness {
+            write!(f, ", brightness {brightness:.2}")?;
+        }
+        write!(f, " -> {:.1} fps", self.fps)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AiStatus {
+    pub state: WorkerState,
+    pub errors: u64,
+    pub last_error: Option<String>,
+    pub last_detection: Option<Detection>,
+}
+
+/// Health of the AI worker, reported by the worker and read by `status`.
+pub struct AiHealth {
+    inner: Mutex<Health>,
+}
+
+struct Health {
+    state: WorkerState,
+    errors: u64,
+    last_error: Option<String>,
+    last: Option<(Instant, Signals, f32)>,
+}
+
+impl AiHealth {
+    pub fn new(state: WorkerState) -> Self {
+        Self {
+            inner: Mutex::new(Health {
+                state,
+                errors: 0,
+                last_error: None,
+                last: None,
+            }),
+        }
+    }
+
+    pub fn set(&self, state: WorkerState) {
+        self.inner.lock().unwrap().state = state;
+    }
+
+    pub fn error(&self, message: impl Into<String>) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.errors += 1;
+        inner.last_error = Some(message.into());
+    }
+
+    /// Records the outcome of a frame captured at `captured`.
+    pub fn detected(&self, signals: &Signals, fps: f32, captured: Instant) {
+        self.inner.lock().unwrap().last = Some((captured, signals.clone(), fps));
+    }
+
+    pub fn snapshot(&self) -> AiStatus {
+        let inner = self.inner.lock().unwrap();
+        AiStatus {
+            state: inner.state,
+            errors: inner.errors,
+            last_error: inner.last_error.clone(),
+            last_detection: inner
+                .last
+                .as_ref()
+                .map(|(at, signals, fps)| Detection::new(signals, *fps, at.elapsed())),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    #[serde(alias = "manual")]
+    Manual,
+    #[serde(alias = "ai")]
+    Ai,
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Mode::Manual => "manual",
+            Mode::Ai => "ai",
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TargetStatus {
+    pub target: String,
+    pub divisor: u32,
+    /// Processes a signal target currently follows.
+    pub pids: Vec<u32>,
+    pub fired: u64,
+    pub failed: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FrameStatus {
+    pub dir: PathBuf,
+    pub count: usize,
+    /// Index of the frame `next-image` returns next.
+    pub index: usize,
+    /// The frame on screen: the one `next-image` returned last.
+    pub current: Option<PathBuf>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct TickStatus {
+    pub ticks: u64,
+    pub missed: u64,
+    pub mean_jitter_ms: f64,
+    pub max_jitter_ms: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FetchStatus {
+    /// `next-image` requests served.
+    pub fetches: u64,
+    /// Smoothed signal-to-fetch latency, measured in adaptive mode.
+    pub latency_ms: Option<f64>,
+    /// The rate Hyprlock keeps up with: the FPS capped by the latency.
+    pub effective_fps: Option<f32>,
+}
+
+/// A setting whose value in `state.json` differs from what the daemon runs.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Drift {
+    pub setting: String,
+    pub config: String,
+    pub daemon: String,
+}
+
+/// Everything `bongo-modulator status` reports.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Status {
+    pub version: String,
+    pub pid: u32,
+    pub uptime_secs: f64,
+    pub fps: f32,
+    pub mode: Mode,
+    pub locked: bool,
+    pub ai: AiStatus,
+    pub frames: FrameStatus,
+    pub targets: Vec<TargetStatus>,
+    pub ticks: TickStatus,
+    pub fetches: FetchStatus,
+    pub drift: Vec<Drift>,
+}
+
+pub fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+fn uptime(secs: f64) -> String {
+    let secs = secs as u64;
+    match (secs / 3600, secs / 60 % 60, secs % 60) {
+        (0, 0, s) => format!("{s}s"),
+        (0, m, s) => format!("{m}m {s}s"),
+        (h, m, s) => format!("{h}h {m}m {s}s"),
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "daemon    {} (pid {}), up {}",
+            self.version,
+            self.pid,
+            uptime(self.uptime_secs)
+        )?;
+        writeln!(f, "mode      {} at {:.1} fps", self.mode, self.fps)?;
+        let session = if self.locked { "locked" } else { "unlocked" };
+        writeln!(f, "session   {session}")?;
+        write!(f, "ai        {:?}", self.ai.state)?;
+        if self.ai.errors > 0 {
+            write!(f, ", {} errors", self.ai.errors)?;
+            if let Some(e) = &self.ai.last_error {
+                write!(f, " (last: {e})")?;
+            }
+        }
+        writeln!(f)?;
+        if let Some(d) = &self.ai.last_detection {
+            writeln!(f, "detection {:.1}s ago: {d}", d.age_secs)?;
+        }
+        writeln!(
+            f,
+            "frames    {} in {} (next #{})",
+            self.frames.count,
+            self.frames.dir.display(),
+            self.frames.index
+        )?;
+        for t in &self.targets {
+            write!(f, "target    {}", t.target)?;
+            if t.divisor > 1 {
+                write!(f, " every {} ticks", t.divisor)?;
+            }
+            if !t.pids.is_empty() {
+                let pids: Vec<_> = t.pids.iter().map(u32::to_string).collect();
+                write!(f, ", pids {}", pids.join(" "))?;
+            }
+            writeln!(f, ": {} fired, {} failed", t.fired, t.failed)?;
+        }
+        writeln!(
+            f,
+            "ticks     {} ({} missed), jitter mean {:.1} ms, max {:.1} ms",
+            self.ticks.ticks,
+            self.ticks.missed,
+            self.ticks.mean_jitter_ms,
+            self.ticks.max_jitter_ms
+        )?;
+        write!(f, "fetches   {}", self.fetches.fetches)?;
+        if let Some(latency) = self.fetches.latency_ms {
+            write!(f, ", latency {latency:.1} ms")?;
+        }
+        if let Some(effective) = self.fetches.effective_fps {
+            write!(f, ", keeping up with {effective:.1} fps")?;
+        }
+        writeln!(f)?;
+        for d in &self.drift {
+            writeln!(
+                f,
+                "drift     {}: state.json has {}, daemon runs {}",
+                d.setting, d.config, d.daemon
+            )?;
+        }
+        Ok(())
+    }
+}
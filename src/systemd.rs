@@ -0,0 +1,227 @@
+use std::io;
+use std::os::unix::io::{FromRawFd, RawFd};
+use std::os::unix::net::{UnixDatagram, UnixListener};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::{debug, trace, warn};
+
+/// First file descriptor passed by socket activation.
+const LISTEN_FDS_START: RawFd = 3;
+
+fn for_us(pid_var: &str) -> bool {
+    match std::env::var(pid_var) {
+        Ok(pid) => pid.parse::<u32>().ok() == Some(std::process::id()),
+        // WATCHDOG_PID is optional; LISTEN_PID is not.
+        Err(_) => pid_var == "WATCHDOG_PID",
+    }
+}
+
+/// What systemd passed us through the environment.
+struct Passed {
+    /// How many sockets were activated for us, until one is taken.
+    listen_fds: Mutex<Option<i32>>,
+    notify_socket: Option<String>,
+    watchdog: Option<Duration>,
+}
+
+static PASSED: OnceLock<Passed> = OnceLock::new();
+
+fn take_environment() -> Passed {
+    let listen_fds = std::env::var("LISTEN_FDS")
+        .ok()
+        .and_then(|count| count.parse::<i32>().ok())
+        .filter(|_| for_us("LISTEN_PID"));
+    let notify_socket = std::env::var("NOTIFY_SOCKET").ok();
+    let watchdog = std::env::var("WATCHDOG_USEC")
+        .ok()
+        .and_then(|usec| usec.parse::<u64>().ok())
+        .filter(|&usec| usec > 0 && for_us("WATCHDOG_PID"))
+        .map(Duration::from_micros);
+    // Cleared so child processes, such as exec targets, don't think the
+    // socket or the service manager's attention is theirs.
+    for var in [
+        "LISTEN_PID",
+        "LISTEN_FDS",
+        "LISTEN_FDNAMES",
+        "NOTIFY_SOCKET",
+        "WATCHDOG_USEC",
+        "WATCHDOG_PID",
+    ] {
+        std::env::remove_var(var);
+    }
+    Passed {
+        listen_fds: Mutex::new(listen_fds),
+        notify_socket,
+        watchdog,
+    }
+}
+
+/// Takes the socket activation and notification variables out of the
+/// environment. Call it before starting any thread, since changing the
+/// environment while another thread reads it is undefined behaviour.
+pub fn claim_environment() {
+    PASSED.get_or_init(take_environment);
+}
+
+fn passed() -> &'static Passed {
+    PASSED.get_or_init(take_environment)
+}
+
+/// Takes the listening socket passed by systemd socket activation, if any;
+/// only the first call gets it.
+pub fn listen_fd() -> Option<UnixListener> {
+    let count = passed().listen_fds.lock().unwrap().take()?;
+    if count < 1 {
+        return None;
+    }
+    if count > 1 {
+        warn!(count, "more than one socket passed; using the first");
+    }
+    let fd = LISTEN_FDS_START;
+    // SAFETY: stat is plain old data, for which all zeroes is a valid value.
+    let mut stat: libc::stat = unsafe { std::mem::zeroed() };
+    // SAFETY: fstat writes into `stat`, which outlives the call; a bad fd
+    // only makes it fail.
+    if unsafe { libc::fstat(fd, &mut stat) } != 0 || stat.st_mode & libc::S_IFMT != libc::S_IFSOCK {
+        warn!(fd, "passed file descriptor is not a socket");
+        return None;
+    }
+    // SAFETY: fcntl on an open descriptor has no memory-safety preconditions.
+    unsafe { libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) };
+    debug!(fd, "using socket from systemd");
+    // SAFETY: LISTEN_PID named us, so systemd handed this open socket to
+    // this process, and nothing else in it refers to the descriptor. The
+    // count was taken above, so ownership is taken only once.
+    Some(unsafe { UnixListener::from_raw_fd(fd) })
+}
+
+/// Sends state updates to the service manager over `NOTIFY_SOCKET`; a no-op
+/// when not started by systemd.
+pub struct Notifier {
+    socket: Option<(UnixDatagram, String)>,
+    watchdog: Option<Duration>,
+    last_ping: Instant,
+}
+
+impl Notifier {
+    /// Uses the variables taken by [`claim_environment`].
+    pub fn from_env() -> Self {
+        let passed = passed();
+        let socket = passed
+            .notify_socket
+            .clone()
+            .and_then(|addr| match UnixDatagram::unbound() {
+                Ok(sock) => Some((sock, addr)),
+                Err(e) => {
+                    warn!("cannot create notify socket: {e}");
+                    None
+                }
+            });
+        let watchdog = passed.watchdog;
+        if let Some(interval) = watchdog {
+            debug!(?interval, "systemd watchdog enabled");
+        }
+        Self {
+            socket,
+            watchdog,
+            last_ping: Instant::now(),
+        }
+    }
+
+    /// Sends a raw `KEY=VALUE` state, e.g. `READY=1`.
+    pub fn notify(&self, state: &str) {
+        let Some((sock, addr)) = &self.socket else {
+            return;
+        };
+        trace!(state, "notifying systemd");
+        if let Err(e) = send_to(sock, addr, state.as_bytes()) {
+            debug!(addr, "failed to notify systemd: {e}");
+        }
+    }
+
+    pub fn ready(&self, status: &str) {
+        self.notify(&format!("READY=1\nSTATUS={status}"));
+    }
+
+    pub fn status(&self, status: &str) {
+        self.notify(&format!("STATUS={status}"));
+    }
+
+    pub fn stopping(&self) {
+        self.notify("STOPPING=1");
+    }
+
+    /// How often the watchdog wants to hear from us, if it is enabled.
+    pub fn ping_interval(&self) -> Option<Duration> {
+        self.watchdog.map(|interval| interval / 2)
+    }
+
+    /// Pets the watchdog when half its interval has passed.
+    pub fn ping(&mut self) {
+        if let Some(interval) = self.ping_interval() {
+            if self.last_ping.elapsed() >= interval {
+                self.notify("WATCHDOG=1");
+                self.last_ping = Instant::now();
+            }
+        }
+    }
+}
+
+fn send_to(sock: &UnixDatagram, addr: &str, data: &[u8]) -> io::Result<usize> {
+    // A leading `@` names a socket in the abstract namespace.
+    #[cfg(target_os = "linux")]
+    if let Some(name) = addr.strip_prefix('@') {
+        use std::os::linux::net::SocketAddrExt;
+        let addr = std::os::unix::net::SocketAddr::from_abstract_name(name)?;
+        return sock.send_to_addr(data, &addr);
+    }
+    sock.send_to(data, addr)
+}
+
+/// Where user units live: `$XDG_CONFIG_HOME/systemd/user`.
+pub fn user_unit_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+        .map(|config| config.join("systemd").join("user"))
+}
+
+/// The user service running `exe daemon`, started through its socket.
+pub fn service_unit(exe: &Path) -> String {
+    format!(
+        "[Unit]
+Description=Bongo cat modulator for Hyprlock
+PartOf=graphical-session.target
+After=graphical-session.target
+Requires=bongo-modulator.socket
+
+[Service]
+Type=notify
+NotifyAccess=main
+ExecStart={} daemon
+Restart=on-failure
+WatchdogSec=30
+
+[Install]
+WantedBy=graphical-session.target
+",
+        exe.display()
+    )
+}
+
+/// The control socket, at the path the CLI looks for by default.
+pub fn socket_unit() -> String {
+    "[Unit]
+Description=Bongo cat modulator control socket
+PartOf=graphical-session.target
+
+[Socket]
+ListenStream=%t/bongo.sock
+SocketMode=0600
+
+[Install]
+WantedBy=sockets.target
+"
+    .to_string()
+}
@@ -0,0 +1,463 @@
+use crate::frame::{image_dir, FrameCache};
+use crate::hyprland::Hyprland;
+use crate::process::{MatchRules, ProcessMatcher, ProcessTracker};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::os::unix::fs::{FileTypeExt, OpenOptionsExt};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, trace};
+
+/// State handed to every target when it fires.
+#[derive(Clone, Copy, Debug)]
+pub struct Tick {
+    pub fps: f32,
+}
+
+/// What a target did with a tick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The target acted: a signal went out, a command ran, a file was
+    /// written.
+    Fired,
+    /// There was nothing to act on, such as no process to signal, a command
+    /// still busy or a FIFO without a reader.
+    Skipped,
+}
+
+/// Something the daemon drives on every tick.
+pub trait Target: Send {
+    /// Short description used in logs.
+    fn describe(&self) -> String;
+    fn fire(&mut self, tick: &Tick) -> io::Result<Outcome>;
+    /// Processes the target currently follows, for targets that do.
+    fn pids(&self) -> Vec<u32> {
+        Vec::new()
+    }
+}
+
+/// A configured target and how often it fires.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TargetSpec {
+    #[serde(flatten)]
+    pub kind: TargetKind,
+    /// Fire on every n-th tick only.
+    #[serde(default = "default_divisor")]
+    pub divisor: u32,
+}
+
+fn default_divisor() -> u32 {
+    1
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TargetKind {
+    /// Send a signal to the matching processes, `--process` by default.
+    Signal {
+        signal: String,
+        #[serde(default)]
+        process: Option<String>,
+    },
+    /// Run a command; `{frame}` and `{fps}` in its arguments are expanded,
+    /// all `{frame}`s to the same frame.
+    Exec { command: Vec<String> },
+    /// Write the rendered template to a file or FIFO.
+    File {
+        path: PathBuf,
+        #[serde(default = "default_template")]
+        template: String,
+    },
+}
+
+fn default_template() -> String {
+    "{frame}\n".to_string()
+}
+
+impl TargetSpec {
+    /// The classic behaviour: SIGUSR2 to the `--process` matches.
+    pub fn hyprlock() -> Self {
+        Self {
+            kind: TargetKind::Signal {
+                signal: "SIGUSR2".to_string(),
+                process: None,
+            },
+            divisor: 1,
+        }
+    }
+
+    /// Builds the target; signal targets match `process` unless they name
+    /// their own, and rescan on `hyprland` events when given.
+    pub fn build(
+        &self,
+        process: &str,
+        rules: &MatchRules,
+        hyprland: Option<&Arc<Hyprland>>,
+    ) -> Result<Box<dyn Target>, String> {
+        if self.divisor == 0 {
+            return Err("target divisor must be at least 1".to_string());
+        }
+        Ok(match &self.kind {
+            TargetKind::Signal {
+                signal,
+                process: own,
+            } => {
+                let matcher = ProcessMatcher::new(own.as_deref().unwrap_or(process), rules)
+                    .map_err(|e| format!("invalid --cmdline pattern: {e}"))?;
+                let mut target = SignalTarget::new(parse_signal(signal)?, matcher);
+                target.hyprland = hyprland.cloned();
+                Box::new(target)
+            }
+            TargetKind::Exec { command } => {
+                if command.is_empty() {
+                    return Err("exec target needs a command".to_string());
+                }
+                Box::new(ExecTarget::new(command.clone()))
+            }
+            TargetKind::File { path, template } => {
+                Box::new(FileTarget::new(path.clone(), template.clone()))
+            }
+        })
+    }
+}
+
+/// Parses `[N:]kind:arg`, e.g. `signal:SIGRTMIN+8@waybar`,
+/// `2:exec:eww update bongo={frame}` or `file:/run/user/1000/bongo.fifo`.
+/// Exec commands are split like a shell would, quotes included.
+impl FromStr for TargetSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (divisor, rest) = match s.split_once(':') {
+            Some((n, rest)) if n.parse::<u32>().is_ok() => (n.parse::<u32>().unwrap(), rest),
+            _ => (1, s),
+        };
+        if divisor == 0 {
+            return Err("target divisor must be at least 1".to_string());
+        }
+        let (kind, arg) = rest
+            .split_once(':')
+            .ok_or_else(|| format!("expected KIND:ARG, got {s:?}"))?;
+        let kind = match kind {
+            "signal" => {
+                let (signal, process) = match arg.split_once('@') {
+                    Some((sig, proc_)) => (sig, Some(proc_.to_string())),
+                    None => (arg, None),
+                };
+                parse_signal(signal)?;
+                TargetKind::Signal {
+                    signal: signal.to_string(),
+                    process,
+                }
+            }
+            "exec" => TargetKind::Exec {
+                command: shlex::split(arg)
+                    .ok_or_else(|| format!("unbalanced quotes in exec command {arg:?}"))?,
+            },
+            "file" => TargetKind::File {
+                path: PathBuf::from(arg),
+                template: default_template(),
+            },
+            other => return Err(format!("unknown target kind {other:?}")),
+        };
+        Ok(Self { kind, divisor })
+    }
+}
+
+/// Parses signal names like `SIGUSR2`, `usr2`, `SIGRTMIN+8` or a number.
+pub fn parse_signal(name: &str) -> Result<i32, String> {
+    if let Ok(n) = name.parse::<i32>() {
+        return Ok(n);
+    }
+    let upper = name.to_ascii_uppercase();
+    let bare = upper.strip_prefix("SIG").unwrap_or(&upper);
+    #[cfg(target_os = "linux")]
+    {
+        let offset = |s: &str| {
+            s.parse::<i32>()
+                .map_err(|_| format!("invalid signal {name}"))
+        };
+        if let Some(n) = bare.strip_prefix("RTMIN+") {
+            return Ok(libc::SIGRTMIN() + offset(n)?);
+        }
+        if let Some(n) = bare.strip_prefix("RTMAX-") {
+            return Ok(libc::SIGRTMAX() - offset(n)?);
+        }
+        match bare {
+            "RTMIN" => return Ok(libc::SIGRTMIN()),
+            "RTMAX" => return Ok(libc::SIGRTMAX()),
+            _ => {}
+        }
+    }
+    Ok(match bare {
+        "HUP" => libc::SIGHUP,
+        "INT" => libc::SIGINT,
+        "QUIT" => libc::SIGQUIT,
+        "TERM" => libc::SIGTERM,
+        "USR1" => libc::SIGUSR1,
+        "USR2" => libc::SIGUSR2,
+        "CONT" => libc::SIGCONT,
+        "WINCH" => libc::SIGWINCH,
+        "ALRM" => libc::SIGALRM,
+        _ => return Err(format!("unknown signal {name}")),
+    })
+}
+
+/// Expands `{fps}` and `{frame}` in each template. The frame is taken from
+/// the cache once per call, so every `{frame}` names the same one.
+fn render(templates: &[String], frames: &mut Option<FrameCache>, tick: &Tick) -> Vec<String> {
+    let frame = templates.iter().any(|t| t.contains("{frame}")).then(|| {
+        frames
+            .get_or_insert_with(|| FrameCache::new(&image_dir()))
+            .next_frame()
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    });
+    let fps = format!("{:.2}", tick.fps);
+    templates
+        .iter()
+        .map(|t| {
+            let out = t.replace("{fps}", &fps);
+            match &frame {
+                Some(frame) => out.replace("{frame}", frame),
+                None => out,
+            }
+        })
+        .collect()
+}
+
+/// Signals every process accepted by a matcher.
+pub struct SignalTarget {
+    signal: i32,
+    tracker: ProcessTracker,
+    last_scan: Option<Instant>,
+    /// Event source prompting rescans; polls on a timer without one.
+    pub hyprland: Option<Arc<Hyprland>>,
+    seen_events: u64,
+}
+
+/// How often an idle signal target looks for new processes.
+const RESCAN_INTERVAL: Duration = Duration::from_secs(1);
+/// Safety net while following Hyprland events, in case one is missed.
+const EVENT_RESCAN_INTERVAL: Duration = Duration::from_secs(10);
+
+impl SignalTarget {
+    pub fn new(signal: i32, matcher: ProcessMatcher) -> Self {
+        Self {
+            signal,
+            tracker: ProcessTracker::new(matcher),
+            last_scan: None,
+            hyprland: None,
+            seen_events: 0,
+        }
+    }
+
+    pub fn pids(&self) -> Vec<u32> {
+        self.tracker.pids()
+    }
+}
+
+impl Target for SignalTarget {
+    fn describe(&self) -> String {
+        format!("signal {} to {}", self.signal, self.tracker.name())
+    }
+
+    fn pids(&self) -> Vec<u32> {
+        self.tracker.pids()
+    }
+
+    fn fire(&mut self, _tick: &Tick) -> io::Result<Outcome> {
+        if self.tracker.is_empty() {
+            let due = match &self.hyprland {
+                Some(h) if h.is_connected() => {
+                    let events = h.generation();
+                    let fresh = events != self.seen_events;
+                    self.seen_events = events;
+                    fresh
+                        || self
+                            .last_scan
+                            .is_none_or(|t| t.elapsed() >= EVENT_RESCAN_INTERVAL)
+                }
+                _ => self
+                    .last_scan
+                    .is_none_or(|t| t.elapsed() >= RESCAN_INTERVAL),
+            };
+            if !due {
+                return Ok(Outcome::Skipped);
+            }
+            let first_miss = self.last_scan.is_none();
+            self.last_scan = Some(Instant::now());
+            if self.tracker.discover() == 0 {
+                if first_miss {
+                    info!(proc = %self.tracker.name(), "process not found; waiting");
+                }
+                return Ok(Outcome::Skipped);
+            }
+            self.last_scan = None;
+        }
+        let signalled = self.tracker.signal_all(self.signal);
+        if let Some((pid, e)) = signalled.failed.into_iter().next() {
+            return Err(io::Error::new(e.kind(), format!("pid {pid}: {e}")));
+        }
+        Ok(if signalled.sent > 0 {
+            Outcome::Fired
+        } else {
+            Outcome::Skipped
+        })
+    }
+}
+
+/// Runs a command per tick, skipping ticks while the previous run is busy.
+/// A run still going when the target is dropped is killed.
+pub struct ExecTarget {
+    command: Vec<String>,
+    running: Option<Child>,
+    frames: Option<FrameCache>,
+}
+
+impl ExecTarget {
+    pub fn new(command: Vec<String>) -> Self {
+        Self {
+            command,
+            running: None,
+            frames: None,
+        }
+    }
+}
+
+impl Target for ExecTarget {
+    fn describe(&self) -> String {
+        format!("exec {}", self.command.join(" "))
+    }
+
+    fn fire(&mut self, tick: &Tick) -> io::Result<Outcome> {
+        if let Some(child) = &mut self.running {
+            match child.try_wait()? {
+                Some(status) if !status.success() => {
+                    debug!(command = %self.command[0], %status, "command failed")
+                }
+                Some(_) => {}
+                None => {
+                    trace!(command = %self.command[0], "previous run still busy; skipping");
+                    return Ok(Outcome::Skipped);
+                }
+            }
+            self.running = None;
+        }
+        let args = render(&self.command[1..], &mut self.frames, tick);
+        let child = Command::new(&self.command[0])
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .spawn()?;
+        self.running = Some(child);
+        Ok(Outcome::Fired)
+    }
+}
+
+impl Drop for ExecTarget {
+    fn drop(&mut self) {
+        let Some(mut child) = self.running.take() else {
+            return;
+        };
+        if let Ok(None) = child.try_wait() {
+            debug!(command = %self.command[0], pid = child.id(), "killing unfinished run");
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// Writes the rendered template to a file, replacing its contents, or to a
+/// FIFO. A FIFO nobody reads from is skipped instead of blocking the daemon.
+pub struct FileTarget {
+    path: PathBuf,
+    template: String,
+    frames: Option<FrameCache>,
+}
+
+impl FileTarget {
+    pub fn new(path: PathBuf, template: String) -> Self {
+        Self {
+            path,
+            template,
+            frames: None,
+        }
+    }
+}
+
+impl Target for FileTarget {
+    fn describe(&self) -> String {
+        format!("file {}", self.path.display())
+    }
+
+    fn fire(&mut self, tick: &Tick) -> io::Result<Outcome> {
+        let is_fifo = std::fs::metadata(&self.path)
+            .map(|m| m.file_type().is_fifo())
+            .unwrap_or(false);
+        let file = OpenOptions::new()
+            .write(true)
+            .create(!is_fifo)
+            .truncate(!is_fifo)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(&self.path);
+        let mut file = match file {
+            Ok(f) => f,
+            Err(e) if is_fifo && e.raw_os_error() == Some(libc::ENXIO) => {
+                trace!(path = %self.path.display(), "no reader on fifo");
+                return Ok(Outcome::Skipped);
+            }
+            Err(e) => return Err(e),
+        };
+        let data = render(std::slice::from_ref(&self.template), &mut self.frames, tick);
+        match file.write_all(data[0].as_bytes()) {
+            Ok(()) => Ok(Outcome::Fired),
+            Err(e) if is_fifo && e.kind() == io::ErrorKind::WouldBlock => {
+                trace!(path = %self.path.display(), "fifo full; dropping update");
+                Ok(Outcome::Skipped)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// A built target with its rate divisor.
+pub struct ScheduledTarget {
+    pub target: Box<dyn Target>,
+    pub divisor: u32,
+    /// Ticks the target acted on.
+    pub fired: u64,
+    /// Ticks on which firing failed, such as a signal that could not be
+    /// delivered.
+    pub failed: u64,
+}
+
+impl ScheduledTarget {
+    pub fn new(target: Box<dyn Target>, divisor: u32) -> Self {
+        Self {
+            target,
+            divisor,
+            fired: 0,
+            failed: 0,
+        }
+    }
+
+    /// Fires the target when `tick` falls on its divisor.
+    pub fn on_tick(&mut self, count: u64, tick: &Tick) {
+        if !count.is_multiple_of(u64::from(self.divisor.max(1))) {
+            return;
+        }
+        match self.target.fire(tick) {
+            Ok(Outcome::Fired) => self.fired += 1,
+            Ok(Outcome::Skipped) => {}
+            Err(e) => {
+                self.failed += 1;
+                tracing::error!(target = %self.target.describe(), "target failed: {e}");
+            }
+        }
+    }
+}
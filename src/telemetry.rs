@@ -0,0 +1,239 @@
+use crate::logfile::RotatingFile;
+use clap::{Args, ValueEnum};
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter, Layer, Registry};
+
+#[cfg(feature = "otel")]
+use opentelemetry::trace::TracerProvider as _;
+#[cfg(feature = "otel")]
+use opentelemetry_sdk::trace::SdkTracerProvider;
+#[cfg(feature = "otel")]
+use opentelemetry_sdk::Resource;
+
+/// Log file size at which it is rotated, in MiB, unless `--log-max-size`
+/// says otherwise.
+pub const DEFAULT_LOG_MAX_MIB: u64 = 10;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// One human-readable line per event
+    #[default]
+    Pretty,
+    /// One JSON object per line
+    Json,
+}
+
+/// Where log lines go and which ones are kept.
+#[derive(Args, Clone, Debug, Default, PartialEq)]
+pub struct LogOptions {
+    /// Log filter: `error`, `warn`, `info`, `debug`, `trace` or `off`, or
+    /// `RUST_LOG`-style directives; overrides `RUST_LOG`
+    #[arg(long, global = true, value_name = "FILTER", value_parser = parse_filter)]
+    pub log_level: Option<String>,
+    /// How log lines are written to the terminal or the log file
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub log_format: LogFormat,
+    /// Append logs to this file instead of the terminal or the journal,
+    /// rotating it by size
+    #[arg(long, global = true, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+    /// Rotate the log file once it grows past this many MiB [default: 10]
+    #[arg(long, global = true, value_name = "MIB")]
+    pub log_max_size: Option<u64>,
+}
+
+fn parse_filter(s: &str) -> Result<String, String> {
+    EnvFilter::try_new(s)
+        .map(|_| s.to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// Installed tracing outputs; spans still queued for export are flushed by
+/// [`Telemetry::shutdown`].
+#[derive(Default)]
+pub struct Telemetry {
+    #[cfg(feature = "otel")]
+    provider: Option<SdkTracerProvider>,
+}
+
+impl Telemetry {
+    pub fn shutdown(self) {
+        #[cfg(feature = "otel")]
+        if let Some(provider) = self.provider {
+            if let Err(e) = provider.shutdown() {
+                eprintln!("failed to flush traces: {e}");
+            }
+        }
+    }
+}
+
+/// Whether an OTLP endpoint is configured through the standard
+/// `OTEL_EXPORTER_OTLP_*` variables.
+pub fn otlp_configured() -> bool {
+    [
+        "OTEL_EXPORTER_OTLP_ENDPOINT",
+        "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+    ]
+    .iter()
+    .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
+}
+
+/// The filter from `--log-level`, else `RUST_LOG`, else `default`.
+fn filter(opts: &LogOptions, default: &str) -> EnvFilter {
+    let directives = opts
+        .log_level
+        .clone()
+        .or_else(|| std::env::var("RUST_LOG").ok().filter(|v| !v.is_empty()))
+        .unwrap_or_else(|| default.to_string());
+    EnvFilter::try_new(&directives).unwrap_or_else(|e| {
+        eprintln!("ignoring invalid RUST_LOG {directives:?}: {e}");
+        EnvFilter::new(default)
+    })
+}
+
+type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+fn formatted<W>(format: LogFormat, writer: W, ansi: bool) -> BoxedLayer
+where
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    let layer = fmt::layer().with_writer(writer).with_ansi(ansi);
+    match format {
+        LogFormat::Pretty => layer.boxed(),
+        LogFormat::Json => layer.json().boxed(),
+    }
+}
+
+/// Whether stderr is connected to the journal, as systemd announces in
+/// `JOURNAL_STREAM` for the services it starts.
+#[cfg(target_os = "linux")]
+fn stderr_is_journal() -> bool {
+    use std::os::fd::AsFd;
+    use std::os::unix::fs::MetadataExt;
+
+    let Some(stream) = std::env::var_os("JOURNAL_STREAM") else {
+        return false;
+    };
+    let Some((dev, ino)) = stream.to_str().and_then(|s| s.split_once(':')) else {
+        return false;
+    };
+    let stderr = std::io::stderr();
+    let Ok(fd) = stderr.as_fd().try_clone_to_owned() else {
+        return false;
+    };
+    std::fs::File::from(fd)
+        .metadata()
+        .is_ok_and(|m| dev.parse() == Ok(m.dev()) && ino.parse() == Ok(m.ino()))
+}
+
+/// Where log lines go: the log file if one is given, else the journal when
+/// systemd connected us to it, else stderr. Stdout stays clean for what
+/// commands print, such as the frame path `next-image` hands to Hyprlock.
+fn log_layer(opts: &LogOptions) -> (BoxedLayer, Option<String>) {
+    if let Some(path) = &opts.log_file {
+        let max_mib = opts.log_max_size.unwrap_or(DEFAULT_LOG_MAX_MIB);
+        match RotatingFile::open(path, max_mib.saturating_mul(1024 * 1024)) {
+            Ok(file) => return (formatted(opts.log_format, file, false), None),
+            Err(e) => {
+                let error = format!("failed to open log file {}: {e}", path.display());
+                return (stderr_layer(opts), Some(error));
+            }
+        }
+    }
+    #[cfg(target_os = "linux")]
+    if stderr_is_journal() {
+        match tracing_journald::layer() {
+            Ok(journald) => {
+                return (
+                    journald
+                        .with_syslog_identifier(env!("CARGO_PKG_NAME").into())
+                        .boxed(),
+                    None,
+                )
+            }
+            Err(e) => {
+                return (
+                    stderr_layer(opts),
+                    Some(format!("failed to reach the journal: {e}")),
+                )
+            }
+        }
+    }
+    (stderr_layer(opts), None)
+}
+
+fn stderr_layer(opts: &LogOptions) -> BoxedLayer {
+    formatted(
+        opts.log_format,
+        std::io::stderr,
+        std::io::stderr().is_terminal(),
+    )
+}
+
+/// Installs the global subscriber: log lines as `opts` asks, filtered by
+/// `--log-level`, `RUST_LOG` or else `default_level`, plus, with the `otel`
+/// feature and an OTLP endpoint configured, the daemon's spans exported over
+/// OTLP/HTTP.
+pub fn init(opts: &LogOptions, default_level: &str) -> Telemetry {
+    let (logs, log_error) = log_layer(opts);
+    let logs = logs.with_filter(filter(opts, default_level));
+    #[cfg(feature = "otel")]
+    {
+        let (provider, error) = if otlp_configured() {
+            match tracer_provider() {
+                Ok(provider) => (Some(provider), None),
+                Err(e) => (None, Some(e)),
+            }
+        } else {
+            (None, None)
+        };
+        // Only this crate's spans, and the events within them.
+        let spans = provider.as_ref().map(|provider| {
+            tracing_opentelemetry::layer()
+                .with_tracer(provider.tracer(env!("CARGO_PKG_NAME")))
+                .with_filter(
+                    tracing_subscriber::filter::Targets::new()
+                        .with_target(env!("CARGO_CRATE_NAME"), tracing::Level::INFO),
+                )
+        });
+        tracing_subscriber::registry().with(logs).with(spans).init();
+        if let Some(e) = log_error {
+            tracing::error!("{e}");
+        }
+        if let Some(e) = error {
+            tracing::error!("failed to set up OTLP export: {e}");
+        }
+        Telemetry { provider }
+    }
+    #[cfg(not(feature = "otel"))]
+    {
+        tracing_subscriber::registry().with(logs).init();
+        if let Some(e) = log_error {
+            tracing::error!("{e}");
+        }
+        if otlp_configured() {
+            tracing::warn!("OTLP endpoint set, but this build lacks the `otel` feature");
+        }
+        Telemetry::default()
+    }
+}
+
+/// Batches spans to the endpoint from the `OTEL_EXPORTER_OTLP_*` variables.
+#[cfg(feature = "otel")]
+pub fn tracer_provider() -> Result<SdkTracerProvider, opentelemetry_otlp::ExporterBuildError> {
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .build()?;
+    let mut resource = Resource::builder();
+    if std::env::var_os("OTEL_SERVICE_NAME").is_none() {
+        resource = resource.with_service_name(env!("CARGO_PKG_NAME"));
+    }
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource.build())
+        .build())
+}
@@ -0,0 +1,269 @@
+#![cfg(feature = "ai")]
+
+use bongo_modulator::ai::{
+    infer, input_size, load_model, resolve_model_spec, spawn_worker, ModelHandle, Models, Worker,
+};
+use bongo_modulator::capture::{mean_luminance, FrameSource, ReplaySource};
+use bongo_modulator::config::Config;
+use bongo_modulator::events::{Event, EventBus, EventKind};
+use bongo_modulator::metrics::Metrics;
+use bongo_modulator::policy::{FpsPolicy, SLEEPY_FPS};
+use bongo_modulator::scheduler::Rate;
+use bongo_modulator::session::LockState;
+use bongo_modulator::status::{AiHealth, WorkerState};
+use candle_core::Device;
+use candle_onnx::onnx;
+use candle_onnx::onnx::tensor_shape_proto::{dimension, Dimension};
+use image::{DynamicImage, Rgb, RgbImage};
+use prost::Message;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tempfile::tempdir;
+
+fn write_identity_model(path: &Path) {
+    let value_info = |name: &str| onnx::ValueInfoProto {
+        name: name.to_string(),
+        ..Default::default()
+    };
+    let model = onnx::ModelProto {
+        graph: Some(onnx::GraphProto {
+            node: vec![onnx::NodeProto {
+                input: vec!["images".to_string()],
+                output: vec!["output0".to_string()],
+                op_type: "Identity".to_string(),
+                ..Default::default()
+            }],
+            input: vec![value_info("images")],
+            output: vec![value_info("output0")],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    std::fs::write(path, model.encode_to_vec()).unwrap();
+}
+
+/// An identity model whose input declares `dims`, zero meaning dynamic.
+fn shaped_identity_model(dims: &[i64]) -> onnx::ModelProto {
+    let shape = onnx::TensorShapeProto {
+        dim: dims
+            .iter()
+            .map(|&d| Dimension {
+                value: Some(match d {
+                    0 => dimension::Value::DimParam("batch".into()),
+                    d => dimension::Value::DimValue(d),
+                }),
+                ..Default::default()
+            })
+            .collect(),
+    };
+    let input = onnx::ValueInfoProto {
+        name: "images".to_string(),
+        r#type: Some(onnx::TypeProto {
+            value: Some(onnx::type_proto::Value::TensorType(
+                onnx::type_proto::Tensor {
+                    elem_type: onnx::tensor_proto::DataType::Float as i32,
+                    shape: Some(shape),
+                },
+            )),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    onnx::ModelProto {
+        graph: Some(onnx::GraphProto {
+            node: vec![onnx::NodeProto {
+                input: vec!["images".to_string()],
+                output: vec!["output0".to_string()],
+                op_type: "Identity".to_string(),
+                ..Default::default()
+            }],
+            input: vec![input],
+            output: vec![onnx::ValueInfoProto {
+                name: "output0".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn frames_are_resized_to_the_model_input() {
+    let model = shaped_identity_model(&[1, 3, 240, 320]);
+    assert_eq!(input_size(&model), (320, 240));
+    let output = infer(&model, solid(128), &Device::Cpu).unwrap();
+    assert_eq!(output.dims(), [1, 3, 240, 320]);
+
+    let dynamic = shaped_identity_model(&[0, 3, 0, 0]);
+    assert_eq!(input_size(&dynamic), (640, 640));
+}
+
+#[test]
+fn load_model_rejects_garbage() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("garbage.onnx");
+    std::fs::write(&path, b"not a model").unwrap();
+    assert!(load_model(path.to_str().unwrap()).is_err());
+}
+
+#[test]
+fn local_path_spec_resolves_to_itself() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("model.onnx");
+    write_identity_model(&path);
+    let spec = path.to_str().unwrap();
+    assert_eq!(resolve_model_spec(spec).unwrap(), spec);
+}
+
+#[test]
+fn failed_swap_keeps_active_model() {
+    let dir = tempdir().unwrap();
+    let good = dir.path().join("good.onnx");
+    write_identity_model(&good);
+    let bad = dir.path().join("bad.onnx");
+    std::fs::write(&bad, b"garbage").unwrap();
+
+    let models = ModelHandle::default();
+    models.load(good.to_str().unwrap()).unwrap();
+    let active = models.current().unwrap();
+
+    assert!(models.load(bad.to_str().unwrap()).is_err());
+    assert!(std::sync::Arc::ptr_eq(&active, &models.current().unwrap()));
+}
+
+fn solid(value: u8) -> DynamicImage {
+    DynamicImage::ImageRgb8(RgbImage::from_pixel(32, 32, Rgb([value; 3])))
+}
+
+#[test]
+fn luminance_spans_black_to_white() {
+    assert_eq!(mean_luminance(&solid(0)), 0.0);
+    assert!((mean_luminance(&solid(255)) - 1.0).abs() < 1e-4);
+    let green = DynamicImage::ImageRgb8(RgbImage::from_pixel(8, 8, Rgb([0, 255, 0])));
+    assert!((mean_luminance(&green) - 0.7152).abs() < 1e-3);
+}
+
+#[test]
+fn replay_loops_over_directory() {
+    let dir = tempdir().unwrap();
+    solid(0).save(dir.path().join("0.png")).unwrap();
+    solid(255).save(dir.path().join("1.png")).unwrap();
+
+    let mut source = ReplaySource::new(dir.path());
+    let seen: Vec<f32> = (0..3)
+        .map(|_| mean_luminance(&source.next_frame().unwrap()))
+        .collect();
+    assert!(seen[0] < 0.01 && seen[1] > 0.99 && seen[2] < 0.01);
+}
+
+#[test]
+fn dark_replay_makes_worker_sleepy() {
+    let dir = tempdir().unwrap();
+    let frames = dir.path().join("frames");
+    std::fs::create_dir(&frames).unwrap();
+    solid(5).save(frames.join("dark.png")).unwrap();
+    let model = dir.path().join("model.onnx");
+    write_identity_model(&model);
+
+    let models = Models::default();
+    models.objects.load(model.to_str().unwrap()).unwrap();
+    let fps = Arc::new(Rate::new(20.0));
+    let policy = Arc::new(Mutex::new(FpsPolicy {
+        dark_threshold: Some(0.2),
+    }));
+    let health = Arc::new(AiHealth::new(WorkerState::Starting));
+    let events = Arc::new(EventBus::default());
+    let detections = events.subscribe(vec![EventKind::Detection], 4);
+    let metrics = Arc::new(Metrics::default());
+    spawn_worker(
+        ReplaySource::new(&frames),
+        Worker {
+            fps: fps.clone(),
+            enabled: Arc::new(AtomicBool::new(true)),
+            session: Arc::new(LockState::new(true)),
+            models,
+            policy,
+            health: health.clone(),
+            events,
+            metrics: metrics.clone(),
+            cfg: Config::default(),
+        },
+    );
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while fps.get() > SLEEPY_FPS {
+        assert!(Instant::now() < deadline, "worker never capped the fps");
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    let status = health.snapshot();
+    assert_eq!(status.state, WorkerState::Running);
+    let detection = status.last_detection.expect("no detection recorded");
+    assert_eq!(detection.fps, SLEEPY_FPS);
+    assert!(detection.brightness.is_some_and(|b| b < 0.2));
+    match detections.recv_timeout(Duration::from_secs(1)) {
+        Ok(Event::Detection(d)) => {
+            assert_eq!(d.objects, detection.objects);
+            // Inference takes time, so the frame is older than the event.
+            assert!(d.age_secs > 0.0);
+        }
+        other => panic!("unexpected event {other:?}"),
+    }
+    assert!(metrics.detections() >= 1);
+}
+
+/// Panics on its first frame, then serves `frame` like a healthy camera.
+struct FlakySource {
+    panicked: bool,
+    frame: DynamicImage,
+}
+
+impl FrameSource for FlakySource {
+    fn next_frame(&mut self) -> Option<DynamicImage> {
+        if !self.panicked {
+            self.panicked = true;
+            panic!("camera driver fell over");
+        }
+        Some(self.frame.clone())
+    }
+}
+
+#[test]
+fn panicking_worker_is_restarted() {
+    let dir = tempdir().unwrap();
+    let model = dir.path().join("model.onnx");
+    write_identity_model(&model);
+    let models = Models::default();
+    models.objects.load(model.to_str().unwrap()).unwrap();
+    let health = Arc::new(AiHealth::new(WorkerState::Starting));
+    let metrics = Arc::new(Metrics::default());
+    spawn_worker(
+        FlakySource {
+            panicked: false,
+            frame: solid(128),
+        },
+        Worker {
+            fps: Arc::new(Rate::new(5.0)),
+            enabled: Arc::new(AtomicBool::new(true)),
+            session: Arc::new(LockState::new(true)),
+            models,
+            policy: Arc::new(Mutex::new(FpsPolicy::default())),
+            health: health.clone(),
+            events: Arc::new(EventBus::default()),
+            metrics: metrics.clone(),
+            cfg: Config::default(),
+        },
+    );
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while metrics.detections() == 0 {
+        assert!(Instant::now() < deadline, "worker never came back");
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert_eq!(metrics.restarts(), 1);
+    let status = health.snapshot();
+    assert_eq!(status.state, WorkerState::Running);
+    assert!(status.errors >= 1);
+}
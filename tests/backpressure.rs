@@ -0,0 +1,105 @@
+use bongo_modulator::backpressure::{AdaptiveTarget, FetchLatency, FETCH_TIMEOUT};
+use bongo_modulator::target::{Outcome, Target, Tick};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const MS: Duration = Duration::from_millis(1);
+
+#[test]
+fn measures_signal_to_fetch_latency() {
+    let fetches = FetchLatency::default();
+    let t0 = Instant::now();
+    assert!(fetches.latency().is_none());
+
+    fetches.signalled(t0);
+    assert!(!fetches.ready(t0 + 10 * MS));
+    fetches.fetched(t0 + 100 * MS);
+    assert!(fetches.ready(t0 + 100 * MS));
+    assert_eq!(fetches.latency(), Some(100 * MS));
+    assert_eq!(fetches.effective_fps(30.0), 10.0);
+    assert_eq!(fetches.effective_fps(5.0), 5.0);
+
+    // Later samples are smoothed in rather than replacing the average.
+    fetches.signalled(t0 + 200 * MS);
+    fetches.fetched(t0 + 250 * MS);
+    assert_eq!(fetches.latency(), Some(90 * MS));
+}
+
+#[test]
+fn unrequested_fetches_are_ignored() {
+    let fetches = FetchLatency::default();
+    fetches.fetched(Instant::now());
+    assert!(fetches.latency().is_none());
+}
+
+#[test]
+fn stalled_consumer_is_signalled_after_timeout() {
+    let fetches = FetchLatency::default();
+    let t0 = Instant::now();
+    fetches.signalled(t0);
+    // A second signal while waiting keeps the original timestamp.
+    fetches.signalled(t0 + 500 * MS);
+    assert!(!fetches.ready(t0 + FETCH_TIMEOUT - MS));
+    assert!(fetches.ready(t0 + FETCH_TIMEOUT));
+}
+
+struct Counter(Arc<AtomicUsize>);
+
+impl Target for Counter {
+    fn describe(&self) -> String {
+        "counter".into()
+    }
+
+    fn fire(&mut self, _tick: &Tick) -> std::io::Result<Outcome> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        Ok(Outcome::Fired)
+    }
+}
+
+#[test]
+fn adaptive_target_waits_for_fetch() {
+    let fired = Arc::new(AtomicUsize::new(0));
+    let fetches = Arc::new(FetchLatency::default());
+    let mut target = AdaptiveTarget {
+        inner: Box::new(Counter(fired.clone())),
+        fetches: fetches.clone(),
+    };
+    let tick = Tick { fps: 30.0 };
+
+    target.fire(&tick).unwrap();
+    target.fire(&tick).unwrap();
+    assert_eq!(fired.load(Ordering::Relaxed), 1);
+
+    fetches.fetched(Instant::now());
+    target.fire(&tick).unwrap();
+    assert_eq!(fired.load(Ordering::Relaxed), 2);
+    assert!(fetches.latency().is_some());
+}
+
+/// A signal target with no process to signal.
+struct Nobody;
+
+impl Target for Nobody {
+    fn describe(&self) -> String {
+        "nobody".into()
+    }
+
+    fn fire(&mut self, _tick: &Tick) -> std::io::Result<Outcome> {
+        Ok(Outcome::Skipped)
+    }
+}
+
+#[test]
+fn skipped_signals_are_not_measured() {
+    let fetches = Arc::new(FetchLatency::default());
+    let mut target = AdaptiveTarget {
+        inner: Box::new(Nobody),
+        fetches: fetches.clone(),
+    };
+    let now = Instant::now();
+    assert_eq!(target.fire(&Tick { fps: 30.0 }).unwrap(), Outcome::Skipped);
+    assert!(fetches.ready(now));
+    fetches.fetched(now + 10 * MS);
+    assert!(fetches.latency().is_none());
+}
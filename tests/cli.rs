@@ -1,24 +1,52 @@
 use bongo_modulator::config::load_config;
+use bongo_modulator::ipc::{
+    encode_response, ControlMessage, ErrorKind, IpcError, RequestEnvelope, Response,
+};
+use bongo_modulator::telemetry::LogOptions;
 use bongo_modulator::{current_fps, execute, pick_frame, Cli, Commands, ModeSubcommand};
 use clap::Parser;
 use proptest::prelude::*;
 use serial_test::serial;
-use std::io::Write;
+use std::io::{BufRead, BufReader, Write};
 use std::os::unix::net::UnixListener;
+use std::process::ExitCode;
+use std::thread::JoinHandle;
 use tempfile::tempdir;
 
-#[derive(serde::Deserialize, Debug, PartialEq)]
-enum ControlMessage {
-    SetFps(f32),
-    EnableAi,
-    NextImage,
+/// Plays the daemon for one connection: answers the handshake, then answers
+/// the first request with `response` and returns it.
+fn fake_daemon(listener: UnixListener, response: Response) -> JoinHandle<ControlMessage> {
+    std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut writer = stream;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let request: RequestEnvelope = serde_json::from_str(&line).unwrap();
+            if request.message == ControlMessage::Hello {
+                let hello = Response::Hello {
+                    protocol: request.version,
+                    daemon: "test".into(),
+                };
+                writer
+                    .write_all(&encode_response(request.id, hello))
+                    .unwrap();
+                continue;
+            }
+            writer
+                .write_all(&encode_response(request.id, response))
+                .unwrap();
+            return request.message;
+        }
+    })
 }
 
 proptest! {
     #[test]
     fn parse_fps(value in 0.5f32..30.0f32) {
         let args = ["bongo-modulator", "mode", "fps", &value.to_string()];
-        let cli = Cli::parse_from(&args);
+        let cli = Cli::parse_from(args);
         match cli.command {
             Commands::Mode { mode: ModeSubcommand::Fps { fps } } => {
                 prop_assert!((fps - value).abs() < f32::EPSILON)
@@ -31,9 +59,9 @@ proptest! {
     #[test]
     fn parse_daemon_dir(path in "[a-zA-Z0-9][a-zA-Z0-9/_\\.-]*") {
         let args = ["bongo-modulator", "daemon", "--dir", &path];
-        let cli = Cli::parse_from(&args);
+        let cli = Cli::parse_from(args);
         match cli.command {
-            Commands::Daemon { dir, process } => {
+            Commands::Daemon { dir, process, .. } => {
                 prop_assert_eq!(dir, Some(std::path::PathBuf::from(path)));
                 prop_assert_eq!(process, String::from("hyprlock"));
             }
@@ -44,9 +72,9 @@ proptest! {
     #[test]
     fn parse_daemon_process(name in "[a-zA-Z0-9][a-zA-Z0-9_-]*") {
         let args = ["bongo-modulator", "daemon", "--process", &name];
-        let cli = Cli::parse_from(&args);
+        let cli = Cli::parse_from(args);
         match cli.command {
-            Commands::Daemon { dir, process } => {
+            Commands::Daemon { dir, process, .. } => {
                 prop_assert!(dir.is_none());
                 prop_assert_eq!(process, name);
             }
@@ -63,15 +91,13 @@ proptest! {
         std::env::set_var("BONGO_SOCKET", &socket);
 
         let listener = UnixListener::bind(&socket).unwrap();
-        let handle = std::thread::spawn(move || {
-            let (mut stream, _) = listener.accept().unwrap();
-            serde_json::from_reader::<_, ControlMessage>(&mut stream).unwrap()
-        });
+        let handle = fake_daemon(listener, Response::Ok);
 
         let cli = Cli {
             command: Commands::Mode {
                 mode: ModeSubcommand::Fps { fps: value },
             },
+            log: LogOptions::default(),
         };
         execute(cli);
 
@@ -84,20 +110,17 @@ proptest! {
 
     #[test]
     #[serial]
-    fn execute_enables_ai(value in Just(())) {
-        let _ = value; // suppress unused param
+    #[cfg(feature = "camera")]
+    fn execute_enables_ai(_ in Just(())) {
         let dir = tempdir().unwrap();
         std::env::set_var("BONGO_STATE_PATH", dir.path().join("state.json"));
         let socket = dir.path().join("sock");
         std::env::set_var("BONGO_SOCKET", &socket);
 
         let listener = UnixListener::bind(&socket).unwrap();
-        let handle = std::thread::spawn(move || {
-            let (mut stream, _) = listener.accept().unwrap();
-            serde_json::from_reader::<_, ControlMessage>(&mut stream).unwrap()
-        });
+        let handle = fake_daemon(listener, Response::Ok);
 
-        let cli = Cli { command: Commands::Mode { mode: ModeSubcommand::Ai } };
+        let cli = Cli { command: Commands::Mode { mode: ModeSubcommand::Ai { model: None, face_model: None, pose_model: None, dark_threshold: None } }, log: LogOptions::default() };
         execute(cli);
 
         let received = handle.join().unwrap();
@@ -105,18 +128,186 @@ proptest! {
     }
 }
 
+#[test]
+#[serial]
+#[cfg(not(feature = "camera"))]
+fn execute_ai_requires_camera_feature() {
+    let dir = tempdir().unwrap();
+    std::env::set_var("BONGO_STATE_PATH", dir.path().join("state.json"));
+    std::env::set_var("BONGO_SOCKET", dir.path().join("sock"));
+
+    let cli = Cli {
+        command: Commands::Mode {
+            mode: ModeSubcommand::Ai {
+                model: None,
+                face_model: None,
+                pose_model: None,
+                dark_threshold: None,
+            },
+        },
+        log: LogOptions::default(),
+    };
+    execute(cli);
+
+    assert!(!load_config().ai_mode);
+}
+
+#[test]
+#[serial]
+fn rejected_fps_is_not_saved() {
+    let dir = tempdir().unwrap();
+    std::env::set_var("BONGO_STATE_PATH", dir.path().join("state.json"));
+    let socket = dir.path().join("sock");
+    std::env::set_var("BONGO_SOCKET", &socket);
+
+    let listener = UnixListener::bind(&socket).unwrap();
+    let handle = fake_daemon(
+        listener,
+        Response::Error(IpcError::new(ErrorKind::BadRequest, "no thanks")),
+    );
+    let cli = Cli {
+        command: Commands::Mode {
+            mode: ModeSubcommand::Fps { fps: 12.0 },
+        },
+        log: LogOptions::default(),
+    };
+
+    assert_eq!(execute(cli), ExitCode::FAILURE);
+    assert!(matches!(handle.join().unwrap(), ControlMessage::SetFps(_)));
+    assert_ne!(load_config().fps, 12.0);
+}
+
+#[test]
+fn parse_daemon_match_rules() {
+    let args = [
+        "bongo-modulator",
+        "daemon",
+        "--exe",
+        "/usr/bin/hyprlock",
+        "--cmdline",
+        "--immediate",
+        "--uid",
+        "1000",
+        "--cgroup",
+        "hyprlock.service",
+    ];
+    let cli = Cli::parse_from(args);
+    match cli.command {
+        Commands::Daemon { rules, .. } => {
+            assert_eq!(
+                rules.exe.as_deref(),
+                Some(std::path::Path::new("/usr/bin/hyprlock"))
+            );
+            assert_eq!(rules.cmdline.as_deref(), Some("--immediate"));
+            assert_eq!(rules.uid, Some(1000));
+            assert!(!rules.any_uid);
+            assert_eq!(rules.cgroup.as_deref(), Some("hyprlock.service"));
+        }
+        _ => panic!("unexpected subcommand"),
+    }
+}
+
+#[test]
+fn parse_daemon_targets() {
+    let args = [
+        "bongo-modulator",
+        "daemon",
+        "--target",
+        "signal:SIGUSR2",
+        "--target",
+        "2:file:/tmp/bongo",
+    ];
+    let cli = Cli::parse_from(args);
+    match cli.command {
+        Commands::Daemon { targets, .. } => {
+            assert_eq!(targets.len(), 2);
+            assert_eq!(targets[1].divisor, 2);
+        }
+        _ => panic!("unexpected subcommand"),
+    }
+    let bad = ["bongo-modulator", "daemon", "--target", "carrier-pigeon:x"];
+    assert!(Cli::try_parse_from(bad).is_err());
+}
+
+#[test]
+fn uid_and_any_uid_conflict() {
+    let args = ["bongo-modulator", "daemon", "--uid", "0", "--any-uid"];
+    assert!(Cli::try_parse_from(args).is_err());
+}
+
 #[test]
 fn parse_ai_mode() {
     let args = ["bongo-modulator", "mode", "ai"];
-    let cli = Cli::parse_from(&args);
+    let cli = Cli::parse_from(args);
     match cli.command {
         Commands::Mode {
-            mode: ModeSubcommand::Ai,
+            mode:
+                ModeSubcommand::Ai {
+                    model: None,
+                    face_model: None,
+                    pose_model: None,
+                    dark_threshold: None,
+                },
         } => {}
         _ => panic!("unexpected subcommand"),
     }
 }
 
+#[test]
+fn parse_ai_mode_with_model() {
+    let args = [
+        "bongo-modulator",
+        "mode",
+        "ai",
+        "--model",
+        "org/repo:model.onnx",
+    ];
+    let cli = Cli::parse_from(args);
+    match cli.command {
+        Commands::Mode {
+            mode: ModeSubcommand::Ai { model, .. },
+        } => assert_eq!(model.as_deref(), Some("org/repo:model.onnx")),
+        _ => panic!("unexpected subcommand"),
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "camera")]
+fn rejected_model_leaves_config_untouched() {
+    let dir = tempdir().unwrap();
+    std::env::set_var("BONGO_STATE_PATH", dir.path().join("state.json"));
+    let socket = dir.path().join("sock");
+    std::env::set_var("BONGO_SOCKET", &socket);
+
+    let listener = UnixListener::bind(&socket).unwrap();
+    let handle = fake_daemon(
+        listener,
+        Response::Error(IpcError::new(ErrorKind::ModelRejected, "bad model")),
+    );
+
+    let cli = Cli {
+        command: Commands::Mode {
+            mode: ModeSubcommand::Ai {
+                model: Some("missing.onnx".into()),
+                face_model: None,
+                pose_model: None,
+                dark_threshold: None,
+            },
+        },
+        log: LogOptions::default(),
+    };
+    assert_eq!(execute(cli), ExitCode::FAILURE);
+
+    assert_eq!(
+        handle.join().unwrap(),
+        ControlMessage::SetModel("missing.onnx".into())
+    );
+    let cfg = load_config();
+    assert!(!cfg.ai_mode);
+    assert!(cfg.model.is_none());
+}
+
 #[test]
 #[serial]
 fn next_image_uses_daemon() {
@@ -126,18 +317,10 @@ fn next_image_uses_daemon() {
 
     let listener = UnixListener::bind(&socket).unwrap();
     let img_path = dir.path().join("img.png");
-    let return_path = img_path.clone();
-    let handle = std::thread::spawn(move || {
-        let (mut stream, _) = listener.accept().unwrap();
-        let msg: ControlMessage = serde_json::from_reader(&mut stream).unwrap();
-        assert_eq!(msg, ControlMessage::NextImage);
-        stream
-            .write_all(return_path.to_string_lossy().as_bytes())
-            .unwrap();
-    });
+    let handle = fake_daemon(listener, Response::Frame(img_path.clone()));
 
     let result = bongo_modulator::next_image_path();
-    handle.join().unwrap();
+    assert_eq!(handle.join().unwrap(), ControlMessage::NextImage);
     assert_eq!(result.unwrap(), img_path);
 }
 
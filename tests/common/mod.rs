@@ -0,0 +1,123 @@
+//! Helpers shared by the integration tests; each test uses only some.
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::{tempdir, TempDir};
+
+/// Waits up to 5s for `cond` to hold, failing the test otherwise.
+pub fn wait_for(mut cond: impl FnMut() -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !cond() {
+        assert!(Instant::now() < deadline, "condition not met in time");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Starts `sleep 30` under a name unique to this test run, returning it
+/// once the process is visible under that name.
+pub fn sleeper(tag: &str) -> (TempDir, String, Child) {
+    let dir = tempdir().unwrap();
+    let name = format!("bt{tag}{}", std::process::id() % 100_000);
+    let path = dir.path().join(&name);
+    let sleep = ["/bin/sleep", "/usr/bin/sleep"]
+        .into_iter()
+        .find(|p| std::path::Path::new(p).exists())
+        .unwrap();
+    std::fs::copy(sleep, &path).unwrap();
+    let child = Command::new(&path).arg("30").spawn().unwrap();
+    wait_for(|| {
+        std::fs::read_to_string(format!("/proc/{}/comm", child.id()))
+            .is_ok_and(|c| c.trim() == name)
+    });
+    (dir, name, child)
+}
+
+/// Waits for `child` to exit, killing it and failing the test after 15s.
+pub fn wait_exit(child: &mut Child) -> ExitStatus {
+    let deadline = Instant::now() + Duration::from_secs(15);
+    loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            return status;
+        }
+        if Instant::now() >= deadline {
+            child.kill().unwrap();
+            panic!("daemon did not exit");
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+/// A private bus, torn down when dropped.
+pub struct Bus {
+    daemon: Child,
+    pub address: String,
+    _dir: TempDir,
+}
+
+impl Drop for Bus {
+    fn drop(&mut self) {
+        let _ = self.daemon.kill();
+        let _ = self.daemon.wait();
+    }
+}
+
+fn dbus_daemon() -> Option<PathBuf> {
+    std::env::var_os("PATH").and_then(|paths| {
+        std::env::split_paths(&paths)
+            .map(|p| p.join("dbus-daemon"))
+            .find(|p| p.is_file())
+    })
+}
+
+/// Starts a private bus open to everyone. Without `dbus-daemon` the test
+/// fails, unless `BONGO_SKIP_DBUS_TESTS` is set to skip it explicitly.
+pub fn start_bus() -> Option<Bus> {
+    let Some(binary) = dbus_daemon() else {
+        if std::env::var_os("BONGO_SKIP_DBUS_TESTS").is_some() {
+            eprintln!("dbus-daemon not found; skipping as BONGO_SKIP_DBUS_TESTS asks");
+            return None;
+        }
+        panic!("dbus-daemon not found; install it or set BONGO_SKIP_DBUS_TESTS=1 to skip");
+    };
+    let dir = tempdir().unwrap();
+    let config = dir.path().join("bus.conf");
+    std::fs::write(
+        &config,
+        format!(
+            r#"<!DOCTYPE busconfig PUBLIC "-//freedesktop//DTD D-Bus Bus Configuration 1.0//EN"
+ "http://www.freedesktop.org/standards/dbus/1.0/busconfig.dtd">
+<busconfig>
+  <type>custom</type>
+  <listen>unix:path={}</listen>
+  <auth>EXTERNAL</auth>
+  <policy context="default">
+    <allow user="*"/>
+    <allow send_destination="*" eavesdrop="true"/>
+    <allow receive_sender="*"/>
+    <allow eavesdrop="true"/>
+    <allow own="*"/>
+  </policy>
+</busconfig>"#,
+            dir.path().join("bus").display()
+        ),
+    )
+    .unwrap();
+    let mut daemon = Command::new(binary)
+        .arg(format!("--config-file={}", config.display()))
+        .args(["--nofork", "--print-address"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut address = String::new();
+    BufReader::new(daemon.stdout.take().unwrap())
+        .read_line(&mut address)
+        .unwrap();
+    Some(Bus {
+        daemon,
+        address: address.trim().to_string(),
+        _dir: dir,
+    })
+}
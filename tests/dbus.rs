@@ -0,0 +1,163 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use bongo_modulator::config::Config;
+use bongo_modulator::dbus::{BUS_NAME, INTERFACE, OBJECT_PATH};
+use bongo_modulator::ipc::{Client, ControlMessage, Response};
+use common::{start_bus, Bus};
+use std::collections::HashMap;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::{tempdir, TempDir};
+use zbus::blocking::{connection, proxy, Connection, MessageIterator, Proxy};
+use zbus::zvariant::OwnedValue;
+use zbus::CacheProperties;
+use zbus::{message::Type, MatchRule};
+
+/// The modulator daemon serving on `bus`, with a file target so nothing
+/// else gets signalled.
+struct Daemon {
+    dir: TempDir,
+    child: Child,
+}
+
+impl Daemon {
+    fn spawn(bus: &Bus) -> Self {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("frames")).unwrap();
+        std::fs::write(dir.path().join("frames/001.png"), b"").unwrap();
+        let child = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+            .args(["daemon", "--dbus", "--target"])
+            .arg(format!("file:{}", dir.path().join("out").display()))
+            .env("DBUS_SESSION_BUS_ADDRESS", &bus.address)
+            .env("BONGO_SOCKET", dir.path().join("bongo.sock"))
+            .env("BONGO_STATE_PATH", dir.path().join("state.json"))
+            .env("BONGO_IMAGE_DIR", dir.path().join("frames"))
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+        Self { dir, child }
+    }
+}
+
+impl Drop for Daemon {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Waits for the daemon to own its name and returns a proxy for it, reading
+/// properties afresh each time.
+fn modulator(conn: &Connection) -> Proxy<'static> {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        let proxy: Proxy = proxy::Builder::new(conn)
+            .destination(BUS_NAME)
+            .unwrap()
+            .path(OBJECT_PATH)
+            .unwrap()
+            .interface(INTERFACE)
+            .unwrap()
+            .cache_properties(CacheProperties::No)
+            .build()
+            .unwrap();
+        if proxy.get_property::<f64>("Fps").is_ok() {
+            return proxy;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "daemon never appeared on the bus"
+        );
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+type Changed = (String, HashMap<String, OwnedValue>, Vec<String>);
+
+#[test]
+fn controls_the_daemon_over_dbus() {
+    let Some(bus) = start_bus() else { return };
+    let daemon = Daemon::spawn(&bus);
+    let conn = connection::Builder::address(bus.address.as_str())
+        .unwrap()
+        .build()
+        .unwrap();
+    let proxy = modulator(&conn);
+    let rule = MatchRule::builder()
+        .msg_type(Type::Signal)
+        .path(OBJECT_PATH)
+        .unwrap()
+        .member("PropertiesChanged")
+        .unwrap()
+        .build();
+    let mut changes = MessageIterator::for_match_rule(rule, &conn, None).unwrap();
+
+    proxy.call_method("SetFps", &(12.0f64,)).unwrap();
+    assert_eq!(proxy.get_property::<f64>("Fps").unwrap(), 12.0);
+    assert_eq!(proxy.get_property::<String>("Mode").unwrap(), "manual");
+    assert!(!proxy.get_property::<bool>("AiEnabled").unwrap());
+    assert!(proxy
+        .get_property::<String>("FrameDir")
+        .unwrap()
+        .ends_with("frames"));
+    // FrameDir is not announced, and says so.
+    let xml: String = conn
+        .call_method(
+            Some(BUS_NAME),
+            OBJECT_PATH,
+            Some("org.freedesktop.DBus.Introspectable"),
+            "Introspect",
+            &(),
+        )
+        .unwrap()
+        .body()
+        .deserialize()
+        .unwrap();
+    let frame_dir = &xml[xml.find(r#"name="FrameDir""#).unwrap()..];
+    let frame_dir = &frame_dir[..frame_dir.find("</property>").unwrap()];
+    assert!(
+        frame_dir
+            .contains(r#"name="org.freedesktop.DBus.Property.EmitsChangedSignal" value="false""#),
+        "{frame_dir}"
+    );
+    let (iface, changed, _): Changed = changes
+        .next()
+        .unwrap()
+        .unwrap()
+        .body()
+        .deserialize()
+        .unwrap();
+    assert_eq!(iface, INTERFACE);
+    assert_eq!(f64::try_from(&changed["Fps"]).unwrap(), 12.0);
+
+    let frame: String = proxy.call("NextImage", &()).unwrap();
+    assert!(frame.ends_with("001.png"));
+
+    let err = proxy.call_method("SetFps", &(f64::NAN,)).unwrap_err();
+    assert!(
+        matches!(&err, zbus::Error::MethodError(name, _, _) if name.as_str() == "org.freedesktop.DBus.Error.InvalidArgs"),
+        "{err}"
+    );
+    #[cfg(not(feature = "camera"))]
+    assert!(proxy.call_method("EnableAi", &()).is_err());
+
+    // Both front ends act on the same daemon state.
+    let cfg = Config {
+        fps: 3.0,
+        ..Config::default()
+    };
+    std::fs::write(
+        daemon.dir.path().join("state.json"),
+        serde_json::to_vec(&cfg).unwrap(),
+    )
+    .unwrap();
+    proxy.call_method("Reload", &()).unwrap();
+    std::env::set_var("BONGO_SOCKET", daemon.dir.path().join("bongo.sock"));
+    match Client::connect().unwrap().request(ControlMessage::Status) {
+        Ok(Response::Status(status)) => assert_eq!(status.fps, 3.0),
+        other => panic!("unexpected answer {other:?}"),
+    }
+}
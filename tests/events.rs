@@ -0,0 +1,53 @@
+use bongo_modulator::events::{Event, EventBus, EventKind};
+use bongo_modulator::status::Mode;
+use std::time::{Duration, Instant};
+
+#[test]
+fn subscribers_get_only_what_they_asked_for() {
+    let bus = EventBus::default();
+    let fps = bus.subscribe(vec![EventKind::Fps], 8);
+    let all = bus.subscribe(Vec::new(), 8);
+
+    bus.publish(Event::Mode(Mode::Ai));
+    bus.publish(Event::Fps(3.0));
+
+    assert_eq!(fps.try_iter().collect::<Vec<_>>(), [Event::Fps(3.0)]);
+    assert_eq!(
+        all.try_iter().collect::<Vec<_>>(),
+        [Event::Mode(Mode::Ai), Event::Fps(3.0)]
+    );
+}
+
+#[test]
+fn slow_subscriber_loses_events_without_blocking() {
+    let bus = EventBus::default();
+    let slow = bus.subscribe(Vec::new(), 2);
+
+    let started = Instant::now();
+    for i in 0..100 {
+        bus.publish(Event::Fps(i as f32));
+    }
+    assert!(started.elapsed() < Duration::from_secs(1));
+
+    assert_eq!(
+        slow.try_iter().collect::<Vec<_>>(),
+        [Event::Fps(0.0), Event::Fps(1.0)]
+    );
+    // Once there is room again, it learns what it missed.
+    bus.publish(Event::Fps(100.0));
+    assert_eq!(
+        slow.try_iter().collect::<Vec<_>>(),
+        [Event::Lagged(98), Event::Fps(100.0)]
+    );
+}
+
+#[test]
+fn departed_subscribers_are_forgotten() {
+    let bus = EventBus::default();
+    let kept = bus.subscribe(Vec::new(), 1);
+    drop(bus.subscribe(Vec::new(), 1));
+
+    bus.publish(Event::Fps(5.0));
+    assert_eq!(bus.subscribers(), 1);
+    assert_eq!(kept.recv().unwrap(), Event::Fps(5.0));
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "ai")]
+
+use bongo_modulator::face::{decode_face_outputs, decode_faces, facing_count, FaceLayout};
+use candle_core::{Device, Tensor};
+use std::collections::HashMap;
+
+/// Builds a `[1, C, N]` output tensor from per-candidate rows.
+fn output(candidates: &[Vec<f32>]) -> Tensor {
+    let channels = candidates[0].len();
+    let mut data = Vec::with_capacity(channels * candidates.len());
+    for c in 0..channels {
+        data.extend(candidates.iter().map(|row| row[c]));
+    }
+    Tensor::from_vec(data, (1, channels, candidates.len()), &Device::Cpu).unwrap()
+}
+
+/// A face candidate with landmarks; `nose_x` moves the nose between the eyes.
+fn face(cx: f32, score: f32, nose_x: f32) -> Vec<f32> {
+    let mut row = vec![cx, 100.0, 40.0, 40.0, score];
+    let landmarks = [
+        (cx - 10.0, 95.0),
+        (cx + 10.0, 95.0),
+        (nose_x, 105.0),
+        (cx - 8.0, 112.0),
+        (cx + 8.0, 112.0),
+    ];
+    for (x, y) in landmarks {
+        row.extend([x, y, 1.0]);
+    }
+    row
+}
+
+#[test]
+fn frontal_face_scores_high() {
+    let faces = decode_faces(&output(&[face(100.0, 0.9, 100.0)]), 0.5).unwrap();
+    assert_eq!(faces.len(), 1);
+    assert!((faces[0].frontal - 1.0).abs() < 1e-6);
+    assert_eq!(faces[0].bbox, [80.0, 80.0, 120.0, 120.0]);
+}
+
+#[test]
+fn profile_face_is_not_facing() {
+    let faces = decode_faces(&output(&[face(100.0, 0.9, 110.0)]), 0.5).unwrap();
+    assert_eq!(faces.len(), 1);
+    assert!(faces[0].frontal < 0.1);
+    assert_eq!(facing_count(&faces, 0.5), 0);
+}
+
+#[test]
+fn low_confidence_faces_are_dropped() {
+    let faces = decode_faces(&output(&[face(100.0, 0.2, 100.0)]), 0.5).unwrap();
+    assert!(faces.is_empty());
+}
+
+#[test]
+fn overlapping_faces_are_merged() {
+    let candidates = [
+        face(100.0, 0.8, 100.0),
+        face(102.0, 0.9, 102.0),
+        face(300.0, 0.7, 300.0),
+    ];
+    let faces = decode_faces(&output(&candidates), 0.5).unwrap();
+    assert_eq!(faces.len(), 2);
+    assert!((faces[0].score - 0.9).abs() < 1e-6);
+    assert_eq!(facing_count(&faces, 0.5), 2);
+}
+
+#[test]
+fn faces_without_landmarks_count_as_frontal() {
+    let candidates = [vec![50.0, 50.0, 20.0, 20.0, 0.9]];
+    let faces = decode_faces(&output(&candidates), 0.5).unwrap();
+    assert_eq!(facing_count(&faces, 0.5), 1);
+}
+
+#[test]
+fn unknown_layout_is_an_error() {
+    let candidates = [vec![0.0; 7]];
+    assert!(decode_faces(&output(&candidates), 0.5).is_err());
+}
+
+#[test]
+fn layouts_are_told_apart_by_output_names() {
+    assert_eq!(FaceLayout::detect(["output0"]), FaceLayout::Yolo);
+    assert_eq!(
+        FaceLayout::detect(["scores", "boxes"]),
+        FaceLayout::UltraFace
+    );
+    let yunet = ["cls_8", "obj_8", "bbox_8", "kps_8", "cls_16", "obj_16"];
+    assert_eq!(FaceLayout::detect(yunet), FaceLayout::YuNet);
+}
+
+#[test]
+fn ultraface_boxes_scale_to_the_input() {
+    let scores = Tensor::from_vec(vec![0.1f32, 0.9, 0.8, 0.2], (1, 2, 2), &Device::Cpu).unwrap();
+    let boxes = Tensor::from_vec(
+        vec![0.25f32, 0.25, 0.5, 0.5, 0.0, 0.0, 1.0, 1.0],
+        (1, 2, 4),
+        &Device::Cpu,
+    )
+    .unwrap();
+    let outputs = HashMap::from([("scores".to_string(), scores), ("boxes".to_string(), boxes)]);
+    let faces = decode_face_outputs(FaceLayout::UltraFace, &outputs, (320, 240), 0.5).unwrap();
+    assert_eq!(faces.len(), 1);
+    assert_eq!(faces[0].bbox, [80.0, 60.0, 160.0, 120.0]);
+    assert_eq!(facing_count(&faces, 0.5), 1);
+}
+
+/// YuNet outputs for a 32x32 input with one face in cell (1, 2) of the
+/// stride-8 grid; `nose_dx` moves the nose off the eyes' midpoint.
+fn yunet(nose_dx: f32) -> HashMap<String, Tensor> {
+    let mut outputs = HashMap::new();
+    for stride in [8, 16, 32] {
+        let cells = (32 / stride) * (32 / stride);
+        let mut cls = vec![0.0f32; cells];
+        let obj = vec![1.0f32; cells];
+        let mut bbox = vec![0.0f32; cells * 4];
+        let mut kps = vec![0.0f32; cells * 10];
+        if stride == 8 {
+            let cell = 2 * 4 + 1;
+            cls[cell] = 0.81;
+            bbox[cell * 4..cell * 4 + 4].copy_from_slice(&[0.5, 0.5, 0.0, 0.0]);
+            let landmarks = [0.25, 0.4, 0.75, 0.4, 0.5 + nose_dx, 0.6, 0.3, 0.8, 0.7, 0.8];
+            kps[cell * 10..cell * 10 + 10].copy_from_slice(&landmarks);
+        }
+        let tensor = |data: Vec<f32>, width: usize| {
+            Tensor::from_vec(data, (1, cells, width), &Device::Cpu).unwrap()
+        };
+        outputs.insert(format!("cls_{stride}"), tensor(cls, 1));
+        outputs.insert(format!("obj_{stride}"), tensor(obj, 1));
+        outputs.insert(format!("bbox_{stride}"), tensor(bbox, 4));
+        outputs.insert(format!("kps_{stride}"), tensor(kps, 10));
+    }
+    outputs
+}
+
+#[test]
+fn yunet_cells_decode_to_input_pixels() {
+    let faces = decode_face_outputs(FaceLayout::YuNet, &yunet(0.0), (32, 32), 0.5).unwrap();
+    assert_eq!(faces.len(), 1);
+    assert!((faces[0].score - 0.9).abs() < 1e-6);
+    assert_eq!(faces[0].bbox, [8.0, 16.0, 16.0, 24.0]);
+    assert!((faces[0].frontal - 1.0).abs() < 1e-6);
+
+    let turned = decode_face_outputs(FaceLayout::YuNet, &yunet(0.25), (32, 32), 0.5).unwrap();
+    assert_eq!(facing_count(&turned, 0.5), 0);
+}
+
+#[test]
+fn missing_yunet_outputs_are_an_error() {
+    let mut outputs = yunet(0.0);
+    outputs.remove("kps_16");
+    assert!(decode_face_outputs(FaceLayout::YuNet, &outputs, (32, 32), 0.5).is_err());
+}
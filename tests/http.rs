@@ -0,0 +1,399 @@
+#![cfg(feature = "http")]
+
+use bongo_modulator::events::{Event, EventBus};
+use bongo_modulator::http::{serve, token, HttpServer};
+use bongo_modulator::ipc::{ControlMessage, ErrorKind, IpcError, Response};
+use bongo_modulator::listen::Bind;
+use bongo_modulator::server::Limits;
+use bongo_modulator::status::{
+    AiStatus, FetchStatus, FrameStatus, Mode, Status, TickStatus, WorkerState,
+};
+use serial_test::serial;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tempfile::TempDir;
+
+const TOKEN: &str = "s3cret";
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\nnot really";
+
+/// What the fake daemon behind the API remembers.
+struct Fake {
+    fps: f32,
+    mode: Mode,
+    frame: Option<PathBuf>,
+}
+
+fn status(fake: &Fake) -> Status {
+    Status {
+        version: "test".into(),
+        pid: 1,
+        uptime_secs: 0.0,
+        fps: fake.fps,
+        mode: fake.mode,
+        locked: false,
+        ai: AiStatus {
+            state: WorkerState::Unavailable,
+            errors: 0,
+            last_error: None,
+            last_detection: None,
+        },
+        frames: FrameStatus {
+            dir: PathBuf::from("/frames"),
+            count: usize::from(fake.frame.is_some()),
+            index: 0,
+            current: fake.frame.clone(),
+        },
+        targets: Vec::new(),
+        ticks: TickStatus::default(),
+        fetches: FetchStatus {
+            fetches: 0,
+            latency_ms: None,
+            effective_fps: None,
+        },
+        drift: Vec::new(),
+    }
+}
+
+struct Api {
+    server: HttpServer,
+    fake: Arc<Mutex<Fake>>,
+    events: Arc<EventBus>,
+    _dir: TempDir,
+}
+
+impl Api {
+    fn url(&self, path: &str) -> String {
+        format!("http://{}{path}", self.server.local_addr().unwrap())
+    }
+
+    fn authed(&self, method: &str, path: &str) -> ureq::Request {
+        ureq::request(method, &self.url(path)).set("Authorization", &format!("Bearer {TOKEN}"))
+    }
+}
+
+fn start() -> Api {
+    start_with(&Limits::default(), None)
+}
+
+fn start_with(limits: &Limits, origin: Option<&str>) -> Api {
+    let dir = TempDir::new().unwrap();
+    let frame = dir.path().join("0.png");
+    std::fs::write(&frame, PNG).unwrap();
+    let fake = Arc::new(Mutex::new(Fake {
+        fps: 5.0,
+        mode: Mode::Manual,
+        frame: Some(frame),
+    }));
+    let events = Arc::new(EventBus::default());
+    let state = fake.clone();
+    let handler = move |msg| {
+        let mut fake = state.lock().unwrap();
+        match msg {
+            ControlMessage::Status => Response::Status(Box::new(status(&fake))),
+            ControlMessage::SetFps(fps) if fps > 0.0 => {
+                fake.fps = fps;
+                fake.mode = Mode::Manual;
+                Response::Ok
+            }
+            ControlMessage::SetFps(_) => {
+                Response::Error(IpcError::new(ErrorKind::BadRequest, "fps must be positive"))
+            }
+            ControlMessage::EnableAi => {
+                fake.mode = Mode::Ai;
+                Response::Ok
+            }
+            other => Response::Error(IpcError::new(ErrorKind::Unsupported, format!("{other:?}"))),
+        }
+    };
+    let bind: Bind = "127.0.0.1:0".parse().unwrap();
+    let server = serve(
+        &bind,
+        TOKEN.into(),
+        origin.map(str::to_string),
+        handler,
+        events.clone(),
+        limits,
+    )
+    .unwrap();
+    Api {
+        server,
+        fake,
+        events,
+        _dir: dir,
+    }
+}
+
+#[test]
+fn requests_need_the_token() {
+    let api = start();
+
+    let err = ureq::get(&api.url("/status")).call().unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(401, _)), "{err}");
+    let err = ureq::get(&api.url("/status"))
+        .set("Authorization", "Bearer wrong")
+        .call()
+        .unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(401, _)), "{err}");
+
+    let status: Status = ureq::get(&api.url(&format!("/status?token={TOKEN}")))
+        .call()
+        .unwrap()
+        .into_json()
+        .unwrap();
+    assert_eq!(status.fps, 5.0);
+}
+
+#[test]
+fn empty_tokens_never_match() {
+    let bind: Bind = "127.0.0.1:0".parse().unwrap();
+    let server = serve(
+        &bind,
+        String::new(),
+        None,
+        |_| Response::Ok,
+        Arc::new(EventBus::default()),
+        &Limits::default(),
+    )
+    .unwrap();
+    let url = format!("http://{}/status", server.local_addr().unwrap());
+
+    let err = ureq::get(&format!("{url}?token=")).call().unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(401, _)), "{err}");
+    let err = ureq::get(&url)
+        .set("Authorization", "Bearer ")
+        .call()
+        .unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(401, _)), "{err}");
+}
+
+#[test]
+#[serial]
+fn blank_env_token_is_ignored() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("bongo-http.token");
+
+    std::env::set_var("BONGO_HTTP_TOKEN", " ");
+    let stored = token(&path).unwrap();
+    assert_eq!(stored.len(), 32);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), stored);
+
+    std::env::set_var("BONGO_HTTP_TOKEN", "from-env");
+    assert_eq!(token(&path).unwrap(), "from-env");
+    std::env::remove_var("BONGO_HTTP_TOKEN");
+    assert_eq!(token(&path).unwrap(), stored);
+}
+
+#[test]
+fn preflights_are_answered_for_the_configured_origin() {
+    const ORIGIN: &str = "http://localhost:3000";
+    let api = start_with(&Limits::default(), Some(ORIGIN));
+
+    let preflight = ureq::request("OPTIONS", &api.url("/fps"))
+        .set("Origin", ORIGIN)
+        .set("Access-Control-Request-Method", "PUT")
+        .set(
+            "Access-Control-Request-Headers",
+            "authorization, content-type",
+        )
+        .call()
+        .unwrap();
+    assert_eq!(preflight.status(), 204);
+    assert_eq!(
+        preflight.header("Access-Control-Allow-Origin"),
+        Some(ORIGIN)
+    );
+    assert!(preflight
+        .header("Access-Control-Allow-Headers")
+        .is_some_and(|h| h.contains("Authorization")));
+    assert!(preflight
+        .header("Access-Control-Allow-Methods")
+        .is_some_and(|m| m.contains("PUT")));
+
+    let status = api
+        .authed("GET", "/status")
+        .set("Origin", ORIGIN)
+        .call()
+        .unwrap();
+    assert_eq!(status.header("Access-Control-Allow-Origin"), Some(ORIGIN));
+
+    let err = ureq::request("OPTIONS", &api.url("/fps"))
+        .set("Origin", "http://evil.example")
+        .call()
+        .unwrap_err();
+    let ureq::Error::Status(403, response) = err else {
+        panic!("{err}");
+    };
+    assert_eq!(response.header("Access-Control-Allow-Origin"), None);
+    let status = api
+        .authed("GET", "/status")
+        .set("Origin", "http://evil.example")
+        .call()
+        .unwrap();
+    assert_eq!(status.header("Access-Control-Allow-Origin"), None);
+}
+
+#[test]
+fn cross_origin_requests_are_refused_without_an_origin() {
+    let api = start();
+    let err = ureq::request("OPTIONS", &api.url("/status"))
+        .set("Origin", "http://localhost:3000")
+        .call()
+        .unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(403, _)), "{err}");
+}
+
+#[test]
+fn endpoints_mirror_the_control_messages() {
+    let api = start();
+
+    let resp = api.authed("PUT", "/fps").send_json(12.5).unwrap();
+    assert_eq!(resp.status(), 204);
+    assert_eq!(api.fake.lock().unwrap().fps, 12.5);
+
+    let resp = api
+        .authed("POST", "/mode")
+        .send_json(serde_json::json!({"mode": "ai"}));
+    assert_eq!(resp.unwrap().status(), 204);
+    assert_eq!(api.fake.lock().unwrap().mode, Mode::Ai);
+
+    // Back to manual at the rate it had.
+    api.authed("POST", "/mode")
+        .send_json(serde_json::json!({"mode": "manual"}))
+        .unwrap();
+    let status: Status = api
+        .authed("GET", "/status")
+        .call()
+        .unwrap()
+        .into_json()
+        .unwrap();
+    assert_eq!((status.mode, status.fps), (Mode::Manual, 12.5));
+
+    // Daemon errors keep their kind and map onto HTTP statuses.
+    match api.authed("PUT", "/fps").send_json(-1.0) {
+        Err(ureq::Error::Status(400, resp)) => {
+            let err: IpcError = resp.into_json().unwrap();
+            assert_eq!(err.kind, ErrorKind::BadRequest);
+        }
+        other => panic!("unexpected answer {other:?}"),
+    }
+    let err = api.authed("POST", "/reload").call().unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(501, _)), "{err}");
+    let err = api.authed("PUT", "/fps").send_string("fast").unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(400, _)), "{err}");
+    let err = api.authed("DELETE", "/fps").call().unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(405, _)), "{err}");
+    let err = api.authed("GET", "/nope").call().unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(404, _)), "{err}");
+}
+
+#[test]
+fn frame_endpoint_serves_the_image() {
+    let api = start();
+
+    let resp = api.authed("GET", "/frame").call().unwrap();
+    assert_eq!(resp.content_type(), "image/png");
+    let mut body = Vec::new();
+    resp.into_reader().read_to_end(&mut body).unwrap();
+    assert_eq!(body, PNG);
+
+    api.fake.lock().unwrap().frame = None;
+    let err = api.authed("GET", "/frame").call().unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(404, _)), "{err}");
+}
+
+#[test]
+fn websocket_streams_events() {
+    let api = start();
+    let url = format!(
+        "ws://{}/events?token={TOKEN}&only=fps",
+        api.server.local_addr().unwrap()
+    );
+    let (mut ws, _) = tungstenite::connect(url).unwrap();
+    if let tungstenite::stream::MaybeTlsStream::Plain(stream) = ws.get_ref() {
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+    }
+
+    // The subscription exists once the upgrade is answered.
+    api.events.publish(Event::Mode(Mode::Ai));
+    api.events.publish(Event::Fps(7.0));
+    let event: Event = match ws.read().unwrap() {
+        tungstenite::Message::Text(text) => serde_json::from_str(&text).unwrap(),
+        other => panic!("unexpected message {other:?}"),
+    };
+    assert_eq!(event, Event::Fps(7.0));
+
+    let url = format!("ws://{}/events", api.server.local_addr().unwrap());
+    assert!(tungstenite::connect(url).is_err());
+}
+
+#[test]
+fn handlers_and_subscribers_are_capped() {
+    let api = start_with(
+        &Limits {
+            max_connections: 1,
+            max_subscribers: 1,
+            ..Limits::default()
+        },
+        None,
+    );
+    let addr = api.server.local_addr().unwrap();
+    let url = format!("ws://{addr}/events?token={TOKEN}");
+
+    // A subscriber leaves the request slot free, but takes the only
+    // subscriber's one.
+    let (_ws, _) = tungstenite::connect(&url).unwrap();
+    assert_eq!(api.authed("GET", "/status").call().unwrap().status(), 200);
+    match tungstenite::connect(&url) {
+        Err(tungstenite::Error::Http(resp)) => assert_eq!(resp.status(), 503),
+        other => panic!("unexpected answer {other:?}"),
+    }
+
+    // A request whose body never comes holds the only handler; tiny_http
+    // reads short bodies itself, so announce a long one.
+    let mut stalled = TcpStream::connect(addr).unwrap();
+    write!(
+        stalled,
+        "PUT /fps HTTP/1.1\r\nHost: x\r\nAuthorization: Bearer {TOKEN}\r\nContent-Length: 2000\r\n\r\n"
+    )
+    .unwrap();
+    std::thread::sleep(Duration::from_millis(200));
+    let err = api.authed("GET", "/status").call().unwrap_err();
+    assert!(matches!(err, ureq::Error::Status(503, _)), "{err}");
+
+    write!(stalled, "{:<2000}", 12.0).unwrap();
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while api.authed("GET", "/status").call().is_err() {
+        assert!(std::time::Instant::now() < deadline, "slot never freed");
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn unix_socket_is_private_from_the_start() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("http.sock");
+    let bind = Bind::Unix(path.clone());
+    let handler = |_| Response::Ok;
+    let _server = serve(
+        &bind,
+        TOKEN.into(),
+        None,
+        handler,
+        Arc::new(EventBus::default()),
+        &Limits::default(),
+    )
+    .unwrap();
+
+    let meta = std::fs::symlink_metadata(&path).unwrap();
+    assert!(meta.file_type().is_socket());
+    assert_eq!(meta.permissions().mode() & 0o777, 0o600);
+    // Only the socket is left behind, not the directory it was bound in.
+    let entries: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+    assert_eq!(entries.len(), 1);
+}
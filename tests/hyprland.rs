@@ -0,0 +1,201 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use bongo_modulator::hyprland::{socket_dir, Event, Hyprland};
+use bongo_modulator::process::ProcessMatcher;
+use bongo_modulator::target::{SignalTarget, Target, Tick};
+use common::wait_for;
+use serial_test::serial;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixListener;
+use std::process::Command;
+use std::sync::mpsc;
+use tempfile::{tempdir, TempDir};
+
+const TICK: Tick = Tick { fps: 10.0 };
+
+/// A fake Hyprland instance directory: `.socket.sock` answers `j/monitors`
+/// and `.socket2.sock` streams the lines handed to `events`.
+struct FakeHyprland {
+    dir: TempDir,
+    events: mpsc::Sender<Option<String>>,
+}
+
+impl FakeHyprland {
+    fn start(monitors: &'static str) -> Self {
+        let dir = tempdir().unwrap();
+        let requests = UnixListener::bind(dir.path().join(".socket.sock")).unwrap();
+        std::thread::spawn(move || {
+            for stream in requests.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = [0u8; 64];
+                let n = stream.read(&mut request).unwrap();
+                assert_eq!(&request[..n], b"j/monitors");
+                stream.write_all(monitors.as_bytes()).unwrap();
+            }
+        });
+        let listener = UnixListener::bind(dir.path().join(".socket2.sock")).unwrap();
+        let (events, rx) = mpsc::channel::<Option<String>>();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            // `None` hangs up, like a compositor exiting.
+            while let Ok(Some(line)) = rx.recv() {
+                stream.write_all(format!("{line}\n").as_bytes()).unwrap();
+            }
+        });
+        Self { dir, events }
+    }
+
+    fn send(&self, line: &str) {
+        self.events.send(Some(line.to_string())).unwrap();
+    }
+
+    fn hang_up(&self) {
+        self.events.send(None).unwrap();
+    }
+}
+
+#[test]
+fn parses_event_lines() {
+    assert_eq!(Event::parse("activewindow>>,"), Some(Event::FocusCleared));
+    assert_eq!(
+        Event::parse("activewindow>>kitty,~"),
+        Some(Event::Other("activewindow".into()))
+    );
+    assert_eq!(
+        Event::parse("monitoradded>>HDMI-A-1"),
+        Some(Event::MonitorAdded("HDMI-A-1".into()))
+    );
+    assert_eq!(
+        Event::parse("monitorremoved>>DP-2"),
+        Some(Event::MonitorRemoved("DP-2".into()))
+    );
+    assert_eq!(
+        Event::parse("monitoraddedv2>>2,HDMI-A-1,Some Display"),
+        Some(Event::Other("monitoraddedv2".into()))
+    );
+    assert_eq!(Event::parse("garbage"), None);
+}
+
+#[test]
+fn only_focus_loss_counts_from_replayed_events() {
+    let fake = FakeHyprland::start("[]");
+    let hyprland = Hyprland::connect(fake.dir.path()).unwrap();
+
+    // Recorded around hyprlock starting: the lock screen takes focus.
+    for line in [
+        "workspace>>2",
+        "workspacev2>>2,2",
+        "activewindow>>kitty,~",
+        "activewindowv2>>55d0c8a2e9f0",
+        "monitoradded>>HDMI-A-1",
+        "activewindow>>,",
+        "activewindowv2>>",
+        "activewindow>>,",
+    ] {
+        fake.send(line);
+    }
+    wait_for(|| hyprland.generation() == 2);
+    assert!(hyprland.is_connected());
+
+    fake.hang_up();
+    wait_for(|| !hyprland.is_connected());
+    assert_eq!(hyprland.generation(), 2);
+}
+
+#[test]
+fn tracks_monitors_from_replayed_events() {
+    let fake = FakeHyprland::start(r#"[{"id": 0, "name": "eDP-1"}, {"id": 1, "name": "DP-1"}]"#);
+    let hyprland = Hyprland::connect(fake.dir.path()).unwrap();
+    assert_eq!(hyprland.monitors(), vec!["eDP-1", "DP-1"]);
+
+    // Recorded while docking: an external display replaces DP-1.
+    for line in [
+        "monitoraddedv2>>2,HDMI-A-1,Some Display",
+        "monitoradded>>HDMI-A-1",
+        "monitorremovedv2>>1,DP-1,Other Display",
+        "monitorremoved>>DP-1",
+        "monitoradded>>HDMI-A-1",
+        // Marks the end of the replay; monitor events don't count.
+        "activewindow>>,",
+    ] {
+        fake.send(line);
+    }
+    wait_for(|| hyprland.generation() == 1);
+    assert_eq!(hyprland.monitors(), vec!["eDP-1", "HDMI-A-1"]);
+}
+
+#[test]
+fn unanswered_monitor_query_starts_empty() {
+    let fake = FakeHyprland::start("not json");
+    let hyprland = Hyprland::connect(fake.dir.path()).unwrap();
+    assert!(hyprland.monitors().is_empty());
+    fake.send("monitoradded>>eDP-1");
+    wait_for(|| hyprland.monitors() == vec!["eDP-1"]);
+}
+
+#[test]
+fn missing_socket_is_an_error() {
+    let dir = tempdir().unwrap();
+    assert!(Hyprland::connect(dir.path()).is_err());
+}
+
+#[test]
+#[serial]
+fn socket_dir_follows_instance_signature() {
+    let runtime = tempdir().unwrap();
+    let instance = runtime.path().join("hypr").join("abc_123");
+    std::fs::create_dir_all(&instance).unwrap();
+    std::env::set_var("XDG_RUNTIME_DIR", runtime.path());
+
+    std::env::set_var("HYPRLAND_INSTANCE_SIGNATURE", "abc_123");
+    assert_eq!(socket_dir(), Some(instance));
+    std::env::set_var("HYPRLAND_INSTANCE_SIGNATURE", "gone");
+    assert_eq!(socket_dir(), None);
+    std::env::remove_var("HYPRLAND_INSTANCE_SIGNATURE");
+    assert_eq!(socket_dir(), None);
+}
+
+#[test]
+fn signal_target_rescans_on_events() {
+    let fake = FakeHyprland::start("[]");
+    let hyprland = Hyprland::connect(fake.dir.path()).unwrap();
+
+    let bin = tempdir().unwrap();
+    let name = format!("bth{}", std::process::id() % 100_000);
+    let path = bin.path().join(&name);
+    let sleep = ["/bin/sleep", "/usr/bin/sleep"]
+        .into_iter()
+        .find(|p| std::path::Path::new(p).exists())
+        .unwrap();
+    std::fs::copy(sleep, &path).unwrap();
+
+    let mut target = SignalTarget::new(libc::SIGTERM, ProcessMatcher::by_name(&name));
+    target.hyprland = Some(hyprland.clone());
+    target.fire(&TICK).unwrap();
+    assert!(target.pids().is_empty());
+
+    let mut child = Command::new(&path).arg("30").spawn().unwrap();
+    wait_for(|| {
+        std::fs::read_to_string(format!("/proc/{}/comm", child.id()))
+            .is_ok_and(|c| c.trim() == name)
+    });
+    // No event yet, so the process list is not rescanned.
+    target.fire(&TICK).unwrap();
+    assert!(target.pids().is_empty());
+    // Nor on events unrelated to a lock screen.
+    fake.send("activewindow>>kitty,~");
+    target.fire(&TICK).unwrap();
+    assert!(target.pids().is_empty());
+
+    fake.send("activewindow>>,");
+    wait_for(|| hyprland.generation() == 1);
+    target.fire(&TICK).unwrap();
+    assert_eq!(target.pids(), vec![child.id()]);
+    let status = child.wait().unwrap();
+    assert_eq!(
+        std::os::unix::process::ExitStatusExt::signal(&status),
+        Some(libc::SIGTERM)
+    );
+}
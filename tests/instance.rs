@@ -0,0 +1,93 @@
+mod common;
+
+use bongo_modulator::instance::{pid_path, InstanceLock, LockError};
+use common::wait_exit;
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::tempdir;
+
+#[test]
+fn pid_file_sits_next_to_the_socket() {
+    assert_eq!(
+        pid_path(Path::new("/run/user/1000/bongo.sock")),
+        PathBuf::from("/run/user/1000/bongo.pid")
+    );
+}
+
+#[test]
+fn second_lock_is_refused_with_the_holders_pid() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("bongo.pid");
+    let lock = InstanceLock::acquire(&path).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        std::process::id().to_string()
+    );
+
+    match InstanceLock::acquire(&path) {
+        Err(LockError::Held(pid)) => assert_eq!(pid, Some(std::process::id())),
+        Err(e) => panic!("unexpected error: {e}"),
+        Ok(_) => panic!("lock acquired twice"),
+    }
+
+    drop(lock);
+    assert!(!path.exists());
+    InstanceLock::acquire(&path).unwrap();
+}
+
+#[test]
+fn leftover_pid_file_does_not_block() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("bongo.pid");
+    std::fs::write(&path, "999999").unwrap();
+    let _lock = InstanceLock::acquire(&path).unwrap();
+}
+
+fn spawn(dir: &Path, extra: &[&str]) -> Child {
+    Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("daemon")
+        .arg("--target")
+        .arg(format!("file:{}", dir.join("out").display()))
+        .args(extra)
+        .env("BONGO_SOCKET", dir.join("bongo.sock"))
+        .env("BONGO_STATE_PATH", dir.join("state.json"))
+        .env("BONGO_IMAGE_DIR", dir)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap()
+}
+
+/// Waits until the daemon with `pid` owns the pid file and the socket.
+fn wait_running(dir: &Path, pid: u32) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while std::fs::read_to_string(dir.join("bongo.pid")).ok() != Some(pid.to_string())
+        || UnixStream::connect(dir.join("bongo.sock")).is_err()
+    {
+        assert!(Instant::now() < deadline, "daemon {pid} never came up");
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn second_daemon_refuses_unless_replacing() {
+    let dir = tempdir().unwrap();
+    let mut first = spawn(dir.path(), &[]);
+    wait_running(dir.path(), first.id());
+
+    let mut second = spawn(dir.path(), &[]);
+    assert_eq!(wait_exit(&mut second).code(), Some(1));
+    assert!(first.try_wait().unwrap().is_none(), "first daemon died");
+    assert!(UnixStream::connect(dir.path().join("bongo.sock")).is_ok());
+
+    let mut third = spawn(dir.path(), &["--replace"]);
+    assert!(wait_exit(&mut first).success());
+    wait_running(dir.path(), third.id());
+
+    unsafe { libc::kill(third.id() as i32, libc::SIGTERM) };
+    assert!(wait_exit(&mut third).success());
+    assert!(!dir.path().join("bongo.pid").exists());
+    assert!(!dir.path().join("bongo.sock").exists());
+}
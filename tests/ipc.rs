@@ -1,4 +1,4 @@
-use bongo_modulator::ipc::socket_path;
+use bongo_modulator::ipc::{fallback_dir, socket_path};
 use tempfile::tempdir;
 
 #[test]
@@ -22,5 +22,9 @@ fn socket_uses_runtime_dir() {
 fn socket_falls_back_to_tempdir() {
     std::env::remove_var("BONGO_SOCKET");
     std::env::remove_var("XDG_RUNTIME_DIR");
-    assert_eq!(socket_path(), std::env::temp_dir().join("bongo.sock"));
+    let path = socket_path();
+    assert_eq!(path, fallback_dir().join("bongo.sock"));
+    // A directory of our own, not the world-writable temporary directory.
+    assert_ne!(path.parent(), Some(std::env::temp_dir().as_path()));
+    assert!(path.starts_with(std::env::temp_dir()));
 }
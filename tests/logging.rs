@@ -0,0 +1,135 @@
+use bongo_modulator::logfile::{rotated, RotatingFile, KEPT_LOGS};
+use bongo_modulator::telemetry::{LogFormat, LogOptions};
+use bongo_modulator::Cli;
+use clap::Parser;
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::tempdir;
+
+#[test]
+fn log_flags_are_accepted_after_the_subcommand() {
+    let cli = Cli::parse_from([
+        "bongo-modulator",
+        "status",
+        "--log-level",
+        "debug",
+        "--log-format",
+        "json",
+        "--log-file",
+        "/tmp/bongo.log",
+        "--log-max-size",
+        "2",
+    ]);
+    assert_eq!(
+        cli.log,
+        LogOptions {
+            log_level: Some("debug".into()),
+            log_format: LogFormat::Json,
+            log_file: Some("/tmp/bongo.log".into()),
+            log_max_size: Some(2),
+        }
+    );
+    assert!(Cli::try_parse_from(["bongo-modulator", "status", "--log-level", "loud=="]).is_err());
+}
+
+#[test]
+fn rotation_keeps_a_bounded_number_of_files() {
+    let tmp = tempdir().unwrap();
+    let path = tmp.path().join("logs/bongo.log");
+    let file = RotatingFile::open(&path, 10).unwrap();
+    for n in 0..KEPT_LOGS + 3 {
+        (&file)
+            .write_all(format!("line {n:04}\n").as_bytes())
+            .unwrap();
+    }
+
+    let newest = KEPT_LOGS + 2;
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        format!("line {newest:04}\n")
+    );
+    for n in 1..=KEPT_LOGS {
+        assert_eq!(
+            std::fs::read_to_string(rotated(&path, n)).unwrap(),
+            format!("line {:04}\n", newest - n)
+        );
+    }
+    assert!(!rotated(&path, KEPT_LOGS + 1).exists());
+}
+
+#[test]
+fn next_image_stays_silent_without_a_daemon() {
+    let tmp = tempdir().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("next-image")
+        .env("BONGO_SOCKET", tmp.path().join("missing.sock"))
+        .env("BONGO_STATE_PATH", tmp.path().join("state.json"))
+        .env_remove("RUST_LOG")
+        .env_remove("JOURNAL_STREAM")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn log_file_captures_client_errors() {
+    let tmp = tempdir().unwrap();
+    let log = tmp.path().join("client.log");
+    let output = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("next-image")
+        .arg("--log-level")
+        .arg("error")
+        .arg("--log-file")
+        .arg(&log)
+        .env("BONGO_SOCKET", tmp.path().join("missing.sock"))
+        .env("BONGO_STATE_PATH", tmp.path().join("state.json"))
+        .env_remove("JOURNAL_STREAM")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output.stderr.is_empty());
+    let text = std::fs::read_to_string(&log).unwrap();
+    assert!(text.contains("ERROR"), "{text}");
+}
+
+#[test]
+fn daemon_writes_json_lines() {
+    let tmp = tempdir().unwrap();
+    std::fs::create_dir(tmp.path().join("frames")).unwrap();
+    let socket = tmp.path().join("bongo.sock");
+    let log = tmp.path().join("daemon.log");
+    let mut daemon = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("daemon")
+        .arg("--target")
+        .arg(format!("file:{}", tmp.path().join("out").display()))
+        .arg("--log-format")
+        .arg("json")
+        .arg("--log-file")
+        .arg(&log)
+        .env("BONGO_SOCKET", &socket)
+        .env("BONGO_STATE_PATH", tmp.path().join("state.json"))
+        .env("BONGO_IMAGE_DIR", tmp.path().join("frames"))
+        .env_remove("RUST_LOG")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while UnixStream::connect(&socket).is_err() {
+        assert!(Instant::now() < deadline, "daemon never listened");
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    assert_eq!(unsafe { libc::kill(daemon.id() as i32, libc::SIGTERM) }, 0);
+    assert!(daemon.wait().unwrap().success());
+
+    let text = std::fs::read_to_string(&log).unwrap();
+    assert!(!text.is_empty());
+    for line in text.lines() {
+        let event: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert!(event["level"].is_string(), "{line}");
+    }
+}
@@ -0,0 +1,258 @@
+mod common;
+
+use bongo_modulator::listen::Bind;
+use bongo_modulator::metrics::{serve, Metrics, Stage};
+use bongo_modulator::server::Limits;
+use bongo_modulator::status::{
+    AiStatus, FetchStatus, FrameStatus, Mode, Status, TargetStatus, TickStatus, WorkerState,
+};
+use std::io::{Read, Write};
+use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::tempdir;
+
+fn status() -> Status {
+    Status {
+        version: "test".into(),
+        pid: 1,
+        uptime_secs: 2.5,
+        fps: 7.5,
+        mode: Mode::Ai,
+        locked: true,
+        ai: AiStatus {
+            state: WorkerState::Running,
+            errors: 1,
+            last_error: None,
+            last_detection: None,
+        },
+        frames: FrameStatus {
+            dir: PathBuf::from("/frames"),
+            count: 0,
+            index: 0,
+            current: None,
+        },
+        targets: vec![TargetStatus {
+            target: "file:/tmp/\"odd\"".into(),
+            divisor: 1,
+            pids: Vec::new(),
+            fired: 12,
+            failed: 3,
+        }],
+        ticks: TickStatus::default(),
+        fetches: FetchStatus {
+            fetches: 0,
+            latency_ms: None,
+            effective_fps: None,
+        },
+        drift: Vec::new(),
+    }
+}
+
+/// The value of the sample written exactly as `series`.
+fn sample(text: &str, series: &str) -> f64 {
+    text.lines()
+        .find_map(|line| line.strip_prefix(series)?.strip_prefix(' '))
+        .unwrap_or_else(|| panic!("no {series} in\n{text}"))
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn only_loopback_addresses_are_accepted() {
+    assert!("127.0.0.1:8080".parse::<Bind>().is_ok());
+    assert!("[::1]:8080".parse::<Bind>().is_ok());
+    assert_eq!(
+        "unix:/run/bongo.metrics".parse::<Bind>(),
+        Ok(Bind::Unix("/run/bongo.metrics".into()))
+    );
+    assert!("0.0.0.0:8080".parse::<Bind>().is_err());
+}
+
+#[test]
+fn renders_counters_and_status() {
+    let metrics = Metrics::default();
+    metrics.next_image(Duration::from_micros(50));
+    metrics.next_image(Duration::from_millis(3));
+    metrics.inference(Stage::Eval, Duration::from_millis(40));
+    metrics.worker_restarted();
+    metrics.set_camera_open(true);
+
+    let text = metrics.render(&status());
+    assert_eq!(sample(&text, "bongo_fps"), 7.5);
+    assert_eq!(sample(&text, "bongo_ai_mode"), 1.0);
+    assert_eq!(
+        sample(&text, r#"bongo_signals_total{target="file:/tmp/\"odd\""}"#),
+        12.0
+    );
+    assert_eq!(
+        sample(
+            &text,
+            r#"bongo_signal_failures_total{target="file:/tmp/\"odd\""}"#
+        ),
+        3.0
+    );
+    // Buckets are cumulative.
+    assert_eq!(
+        sample(&text, r#"bongo_next_image_seconds_bucket{le="0.0001"}"#),
+        1.0
+    );
+    assert_eq!(
+        sample(&text, r#"bongo_next_image_seconds_bucket{le="0.001"}"#),
+        1.0
+    );
+    assert_eq!(
+        sample(&text, r#"bongo_next_image_seconds_bucket{le="0.005"}"#),
+        2.0
+    );
+    assert_eq!(
+        sample(&text, r#"bongo_next_image_seconds_bucket{le="+Inf"}"#),
+        2.0
+    );
+    assert_eq!(sample(&text, "bongo_next_image_seconds_count"), 2.0);
+    assert_eq!(
+        sample(
+            &text,
+            r#"bongo_inference_seconds_bucket{stage="eval",le="0.05"}"#
+        ),
+        1.0
+    );
+    assert_eq!(
+        sample(&text, r#"bongo_inference_seconds_count{stage="capture"}"#),
+        0.0
+    );
+    assert_eq!(sample(&text, "bongo_ai_worker_restarts_total"), 1.0);
+    assert_eq!(sample(&text, "bongo_ai_errors_total"), 1.0);
+    assert_eq!(sample(&text, "bongo_camera_open"), 1.0);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn signal_counters_skip_dead_processes_and_count_failures() {
+    use bongo_modulator::process::ProcessMatcher;
+    use bongo_modulator::target::{ScheduledTarget, SignalTarget, Tick};
+
+    let (_dir, name, mut child) = common::sleeper("m");
+    let tick = Tick { fps: 10.0 };
+    // Not a valid signal number, so delivery fails while the process lives.
+    let target = SignalTarget::new(1000, ProcessMatcher::by_name(&name));
+    let mut scheduled = ScheduledTarget::new(Box::new(target), 1);
+    scheduled.on_tick(0, &tick);
+    child.kill().unwrap();
+    child.wait().unwrap();
+    // Nobody left to signal: neither sent nor failed.
+    scheduled.on_tick(1, &tick);
+
+    let mut status = status();
+    status.targets = vec![TargetStatus {
+        target: scheduled.target.describe(),
+        divisor: scheduled.divisor,
+        pids: scheduled.target.pids(),
+        fired: scheduled.fired,
+        failed: scheduled.failed,
+    }];
+    let text = Metrics::default().render(&status);
+    let label = format!(r#"{{target="{}"}}"#, scheduled.target.describe());
+    assert_eq!(sample(&text, &format!("bongo_signals_total{label}")), 0.0);
+    assert_eq!(
+        sample(&text, &format!("bongo_signal_failures_total{label}")),
+        1.0
+    );
+}
+
+fn scrape(socket: &Path, path: &str) -> String {
+    let mut stream = UnixStream::connect(socket).unwrap();
+    write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+    let mut response = Vec::new();
+    // A refused scrape is closed with its request unread, which may reset
+    // the connection once the answer is in.
+    let _ = stream.read_to_end(&mut response);
+    String::from_utf8(response).unwrap()
+}
+
+#[test]
+fn daemon_serves_metrics() {
+    let tmp = tempdir().unwrap();
+    let frames = tmp.path().join("frames");
+    std::fs::create_dir(&frames).unwrap();
+    std::fs::write(frames.join("a.png"), b"").unwrap();
+    let metrics = tmp.path().join("metrics.sock");
+    let envs = [
+        ("BONGO_SOCKET", tmp.path().join("bongo.sock")),
+        ("BONGO_STATE_PATH", tmp.path().join("state.json")),
+        ("BONGO_IMAGE_DIR", frames),
+    ];
+    let mut daemon = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("daemon")
+        .arg("--target")
+        .arg(format!("file:{}", tmp.path().join("out").display()))
+        .arg("--metrics")
+        .arg(format!("unix:{}", metrics.display()))
+        .envs(envs.clone())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while UnixStream::connect(&metrics).is_err() {
+        assert!(Instant::now() < deadline, "metrics never listened");
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    let fetched = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("next-image")
+        .envs(envs)
+        .output()
+        .unwrap();
+    assert!(fetched.status.success());
+
+    let response = scrape(&metrics, "/metrics");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+    assert!(response.contains("Content-Type: text/plain; version=0.0.4"));
+    assert_eq!(sample(&response, "bongo_next_image_seconds_count"), 1.0);
+    assert!(response.contains("# TYPE bongo_signals_total counter"));
+    assert!(scrape(&metrics, "/other").starts_with("HTTP/1.1 404 "));
+
+    let _ = daemon.kill();
+    let _ = daemon.wait();
+}
+
+#[test]
+fn unix_socket_is_private_from_the_start() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("metrics.sock");
+    let _server = serve(&Bind::Unix(path.clone()), String::new, &Limits::default()).unwrap();
+
+    let meta = std::fs::symlink_metadata(&path).unwrap();
+    assert!(meta.file_type().is_socket());
+    assert_eq!(meta.permissions().mode() & 0o777, 0o600);
+    let entries: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+    assert_eq!(entries.len(), 1);
+}
+
+#[test]
+fn slow_scrapers_neither_block_others_nor_pile_up() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("metrics.sock");
+    let _server = serve(
+        &Bind::Unix(path.clone()),
+        || "bongo_fps 5\n".to_string(),
+        &Limits {
+            max_connections: 2,
+            ..Limits::default()
+        },
+    )
+    .unwrap();
+
+    // Connects but never sends its request.
+    let _stalled = UnixStream::connect(&path).unwrap();
+    let started = Instant::now();
+    let response = scrape(&path, "/metrics");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+    assert!(started.elapsed() < Duration::from_secs(2));
+
+    let _stalled_too = UnixStream::connect(&path).unwrap();
+    common::wait_for(|| scrape(&path, "/metrics").starts_with("HTTP/1.1 503 "));
+}
@@ -0,0 +1,214 @@
+#![cfg(feature = "otel")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::os::unix::net::UnixStream;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tempfile::tempdir;
+
+/// The path an export was sent to and its body.
+type Export = (String, Vec<u8>);
+
+/// Stands in for an OTLP collector, keeping every export it receives.
+#[derive(Clone, Default)]
+struct Collector {
+    exports: Arc<Mutex<Vec<Export>>>,
+}
+
+impl Collector {
+    fn start() -> (Self, String) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let collector = Self::default();
+        let exports = collector.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let exports = exports.clone();
+                std::thread::spawn(move || exports.serve(stream));
+            }
+        });
+        (collector, endpoint)
+    }
+
+    fn serve(&self, stream: TcpStream) {
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut stream = stream;
+        loop {
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+                return;
+            }
+            let path = request_line
+                .split_whitespace()
+                .nth(1)
+                .unwrap_or_default()
+                .to_string();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            self.exports.lock().unwrap().push((path, body));
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+        }
+    }
+
+    /// Whether some export went to `path` and mentions `needle`.
+    fn saw(&self, path: &str, needle: &str) -> bool {
+        self.exports.lock().unwrap().iter().any(|(p, body)| {
+            p == path && body.windows(needle.len()).any(|w| w == needle.as_bytes())
+        })
+    }
+}
+
+#[test]
+fn daemon_exports_request_spans() {
+    let (collector, endpoint) = Collector::start();
+    let tmp = tempdir().unwrap();
+    std::fs::create_dir(tmp.path().join("frames")).unwrap();
+    let socket = tmp.path().join("bongo.sock");
+    let envs = [
+        ("BONGO_SOCKET", socket.clone()),
+        ("BONGO_STATE_PATH", tmp.path().join("state.json")),
+        ("BONGO_IMAGE_DIR", tmp.path().join("frames")),
+    ];
+    let mut daemon = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("daemon")
+        .arg("--target")
+        .arg(format!("file:{}", tmp.path().join("out").display()))
+        .envs(envs.clone())
+        .env("OTEL_EXPORTER_OTLP_ENDPOINT", &endpoint)
+        .env_remove("OTEL_SERVICE_NAME")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while UnixStream::connect(&socket).is_err() {
+        assert!(Instant::now() < deadline, "daemon never listened");
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    let status = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("status")
+        .envs(envs)
+        .env_remove("OTEL_EXPORTER_OTLP_ENDPOINT")
+        .output()
+        .unwrap();
+    assert!(status.status.success());
+
+    // Exiting flushes the spans still batched.
+    assert_eq!(unsafe { libc::kill(daemon.id() as i32, libc::SIGTERM) }, 0);
+    assert!(daemon.wait().unwrap().success());
+    assert!(collector.saw("/v1/traces", "ipc_request"));
+    assert!(collector.saw("/v1/traces", "bongo-modulator"));
+}
+
+#[cfg(feature = "ai")]
+#[test]
+fn worker_exports_pipeline_spans() {
+    use bongo_modulator::ai::{spawn_worker, Models, Worker};
+    use bongo_modulator::capture::ReplaySource;
+    use bongo_modulator::config::Config;
+    use bongo_modulator::events::{EventBus, EventKind};
+    use bongo_modulator::metrics::Metrics;
+    use bongo_modulator::policy::FpsPolicy;
+    use bongo_modulator::scheduler::Rate;
+    use bongo_modulator::session::LockState;
+    use bongo_modulator::status::{AiHealth, WorkerState};
+    use bongo_modulator::telemetry::tracer_provider;
+    use candle_onnx::onnx;
+    use image::{Rgb, RgbImage};
+    use opentelemetry::trace::TracerProvider as _;
+    use prost::Message;
+    use std::sync::atomic::AtomicBool;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let (collector, endpoint) = Collector::start();
+    std::env::set_var("OTEL_EXPORTER_OTLP_ENDPOINT", endpoint);
+    let provider = tracer_provider().unwrap();
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+    tracing::subscriber::set_global_default(subscriber).unwrap();
+
+    let tmp = tempdir().unwrap();
+    let frames = tmp.path().join("frames");
+    std::fs::create_dir(&frames).unwrap();
+    RgbImage::from_pixel(32, 32, Rgb([128; 3]))
+        .save(frames.join("0.png"))
+        .unwrap();
+    let value_info = |name: &str| onnx::ValueInfoProto {
+        name: name.to_string(),
+        ..Default::default()
+    };
+    let model = onnx::ModelProto {
+        graph: Some(onnx::GraphProto {
+            node: vec![onnx::NodeProto {
+                input: vec!["images".to_string()],
+                output: vec!["output0".to_string()],
+                op_type: "Identity".to_string(),
+                ..Default::default()
+            }],
+            input: vec![value_info("images")],
+            output: vec![value_info("output0")],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let path = tmp.path().join("model.onnx");
+    std::fs::write(&path, model.encode_to_vec()).unwrap();
+    let models = Models::default();
+    models.objects.load(path.to_str().unwrap()).unwrap();
+
+    let events = Arc::new(EventBus::default());
+    let detections = events.subscribe(vec![EventKind::Detection], 4);
+    spawn_worker(
+        ReplaySource::new(&frames),
+        Worker {
+            fps: Arc::new(Rate::new(5.0)),
+            enabled: Arc::new(AtomicBool::new(true)),
+            session: Arc::new(LockState::new(true)),
+            models,
+            policy: Arc::new(Mutex::new(FpsPolicy::default())),
+            health: Arc::new(AiHealth::new(WorkerState::Starting)),
+            events,
+            metrics: Arc::new(Metrics::default()),
+            cfg: Config::default(),
+        },
+    );
+    detections.recv_timeout(Duration::from_secs(30)).unwrap();
+
+    // A pass's spans close once it is done with its FPS update.
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !collector.saw("/v1/traces", "frame") {
+        assert!(Instant::now() < deadline, "no frame span exported");
+        std::thread::sleep(Duration::from_millis(200));
+        provider.force_flush().unwrap();
+    }
+    for span in [
+        "capture",
+        "decode",
+        "infer",
+        "preprocess",
+        "simple_eval",
+        "postprocess",
+        "fps_update",
+    ] {
+        assert!(collector.saw("/v1/traces", span), "no {span} span exported");
+    }
+}
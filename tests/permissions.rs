@@ -0,0 +1,72 @@
+use bongo_modulator::ipc::fallback_dir;
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::tempdir;
+
+/// Starts the daemon without `BONGO_SOCKET` or `XDG_RUNTIME_DIR`, so it
+/// falls back to a directory under `tmp`.
+fn spawn_in(tmp: &Path) -> Child {
+    std::fs::create_dir_all(tmp.join("frames")).unwrap();
+    Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("daemon")
+        .arg("--target")
+        .arg(format!("file:{}", tmp.join("out").display()))
+        .env_remove("BONGO_SOCKET")
+        .env_remove("XDG_RUNTIME_DIR")
+        .env("TMPDIR", tmp)
+        .env("BONGO_STATE_PATH", tmp.join("state.json"))
+        .env("BONGO_IMAGE_DIR", tmp.join("frames"))
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap()
+}
+
+fn mode(path: &Path) -> u32 {
+    std::fs::metadata(path).unwrap().permissions().mode() & 0o777
+}
+
+/// Where the daemon puts its socket under `tmp`.
+fn private_dir(tmp: &Path) -> std::path::PathBuf {
+    tmp.join(fallback_dir().file_name().unwrap())
+}
+
+#[test]
+fn fallback_socket_is_private() {
+    let tmp = tempdir().unwrap();
+    let mut daemon = spawn_in(tmp.path());
+    let socket = private_dir(tmp.path()).join("bongo.sock");
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while UnixStream::connect(&socket).is_err() {
+        assert!(Instant::now() < deadline, "daemon never listened");
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    assert_eq!(mode(&private_dir(tmp.path())), 0o700);
+    assert_eq!(mode(&socket), 0o600);
+    let _ = daemon.kill();
+    let _ = daemon.wait();
+}
+
+#[test]
+fn planted_fallback_directory_is_refused() {
+    let tmp = tempdir().unwrap();
+    let dir = private_dir(tmp.path());
+    std::fs::DirBuilder::new().mode(0o777).create(&dir).unwrap();
+    std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o777)).unwrap();
+
+    let mut daemon = spawn_in(tmp.path());
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let status = loop {
+        if let Some(status) = daemon.try_wait().unwrap() {
+            break status;
+        }
+        assert!(Instant::now() < deadline, "daemon did not refuse to start");
+        std::thread::sleep(Duration::from_millis(20));
+    };
+    assert!(!status.success());
+    assert!(!dir.join("bongo.sock").exists());
+}
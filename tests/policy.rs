@@ -0,0 +1,93 @@
+use bongo_modulator::policy::{FpsPolicy, Signals, SLEEPY_FPS};
+
+fn compute_fps(signals: &Signals, ratio: f32) -> f32 {
+    FpsPolicy::default().fps(signals, ratio)
+}
+
+#[test]
+fn facing_faces_override_object_count() {
+    let crowd = Signals {
+        objects: 3,
+        facing: Some(0),
+        drumming: None,
+        brightness: None,
+    };
+    let nobody = Signals::default();
+    assert_eq!(compute_fps(&crowd, 0.5), compute_fps(&nobody, 0.5));
+
+    let watched = Signals {
+        objects: 0,
+        facing: Some(1),
+        drumming: None,
+        brightness: None,
+    };
+    assert!(compute_fps(&watched, 0.5) > compute_fps(&nobody, 0.5));
+}
+
+#[test]
+fn fps_stays_in_range() {
+    let busy = Signals {
+        objects: 100,
+        facing: None,
+        drumming: None,
+        brightness: None,
+    };
+    assert_eq!(compute_fps(&busy, 1.0), 30.0);
+}
+
+#[test]
+fn drumming_drives_fps() {
+    let idle = Signals {
+        objects: 5,
+        facing: Some(1),
+        drumming: Some(0.0),
+        brightness: None,
+    };
+    let busy = Signals {
+        drumming: Some(1.0),
+        ..idle.clone()
+    };
+    assert_eq!(compute_fps(&idle, 1.0), 5.0);
+    assert_eq!(compute_fps(&busy, 0.0), 30.0);
+}
+
+#[test]
+fn pose_without_anyone_in_view_defers_to_faces() {
+    // With the pose and face backends on, an empty frame reports no
+    // drumming, so the faces decide as if pose were off.
+    let empty = Signals {
+        objects: 2,
+        facing: Some(0),
+        drumming: None,
+        brightness: None,
+    };
+    assert_eq!(
+        compute_fps(&empty, 1.0),
+        compute_fps(&Signals::default(), 1.0)
+    );
+    let watched = Signals {
+        facing: Some(1),
+        ..empty.clone()
+    };
+    assert!(compute_fps(&watched, 1.0) > compute_fps(&empty, 1.0));
+}
+
+#[test]
+fn dark_room_caps_fps() {
+    let policy = FpsPolicy {
+        dark_threshold: Some(0.2),
+    };
+    let dark = Signals {
+        drumming: Some(1.0),
+        brightness: Some(0.05),
+        ..Signals::default()
+    };
+    assert_eq!(policy.fps(&dark, 1.0), SLEEPY_FPS);
+
+    let lit = Signals {
+        brightness: Some(0.6),
+        ..dark.clone()
+    };
+    assert_eq!(policy.fps(&lit, 1.0), 30.0);
+    assert_eq!(FpsPolicy::default().fps(&dark, 1.0), 30.0);
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "ai")]
+
+use bongo_modulator::pose::{decode_poses, Arms, DrummingTracker};
+use candle_core::{Device, Tensor};
+
+/// A candidate's score plus its left and right wrist positions.
+type Candidate = (f32, (f32, f32), (f32, f32));
+
+/// Builds a `[1, 56, N]` pose output with elbows fixed below the wrists.
+fn output(candidates: &[Candidate]) -> Tensor {
+    let rows: Vec<Vec<f32>> = candidates
+        .iter()
+        .map(|&(score, left, right)| {
+            let mut row = vec![320.0, 320.0, 200.0, 400.0, score];
+            for k in 0..17 {
+                let (x, y, conf) = match k {
+                    7 => (left.0, left.1 + 50.0, 0.9),
+                    8 => (right.0, right.1 + 50.0, 0.9),
+                    9 => (left.0, left.1, 0.9),
+                    10 => (right.0, right.1, 0.9),
+                    _ => (0.0, 0.0, 0.1),
+                };
+                row.extend([x, y, conf]);
+            }
+            row
+        })
+        .collect();
+    let mut data = Vec::new();
+    for c in 0..56 {
+        data.extend(rows.iter().map(|row| row[c]));
+    }
+    Tensor::from_vec(data, (1, 56, rows.len()), &Device::Cpu).unwrap()
+}
+
+#[test]
+fn decodes_wrists_and_elbows_of_best_pose() {
+    let poses = decode_poses(
+        &output(&[
+            (0.6, (100.0, 200.0), (300.0, 200.0)),
+            (0.9, (110.0, 210.0), (310.0, 210.0)),
+            (0.2, (0.0, 0.0), (0.0, 0.0)),
+        ]),
+        0.5,
+    )
+    .unwrap();
+    assert_eq!(poses.len(), 2);
+    let arms = poses[0].arms(0.5);
+    assert_eq!(arms.wrists, [Some((110.0, 210.0)), Some((310.0, 210.0))]);
+    assert_eq!(arms.elbows, [Some((110.0, 260.0)), Some((310.0, 260.0))]);
+}
+
+#[test]
+fn low_confidence_keypoints_are_missing() {
+    let poses = decode_poses(&output(&[(0.9, (1.0, 1.0), (2.0, 2.0))]), 0.5).unwrap();
+    assert_eq!(poses[0].arms(0.95), Arms::default());
+}
+
+#[test]
+fn wrong_channel_count_is_an_error() {
+    let tensor = Tensor::zeros((1, 20, 3), candle_core::DType::F32, &Device::Cpu).unwrap();
+    assert!(decode_poses(&tensor, 0.5).is_err());
+}
+
+fn arms(wrist_dx: f32) -> Arms {
+    Arms {
+        elbows: [Some((100.0, 300.0)), Some((200.0, 300.0))],
+        wrists: [
+            Some((100.0 + wrist_dx, 200.0)),
+            Some((200.0 + wrist_dx, 200.0)),
+        ],
+    }
+}
+
+#[test]
+fn still_hands_do_not_drum() {
+    let mut tracker = DrummingTracker::default();
+    for _ in 0..5 {
+        tracker.update(Some(arms(0.0)));
+    }
+    assert_eq!(tracker.intensity(), 0.0);
+}
+
+#[test]
+fn moving_hands_raise_intensity() {
+    let mut tracker = DrummingTracker::default();
+    let mut last = 0.0;
+    for i in 0..6 {
+        let dx = if i % 2 == 0 { 0.0 } else { 40.0 };
+        last = tracker.update(Some(arms(dx))).unwrap();
+    }
+    assert!(last > 0.5, "intensity {last}");
+    assert!(last <= 1.0);
+}
+
+#[test]
+fn moving_whole_arm_is_not_drumming() {
+    let mut tracker = DrummingTracker::default();
+    let shifted = |dx: f32| Arms {
+        elbows: [Some((100.0 + dx, 300.0)), Some((200.0 + dx, 300.0))],
+        wrists: [Some((100.0 + dx, 200.0)), Some((200.0 + dx, 200.0))],
+    };
+    tracker.update(Some(shifted(0.0)));
+    assert_eq!(tracker.update(Some(shifted(80.0))), Some(0.0));
+}
+
+#[test]
+fn intensity_decays_without_hands() {
+    let mut tracker = DrummingTracker::default();
+    tracker.update(Some(arms(0.0)));
+    let busy = tracker.update(Some(arms(40.0))).unwrap();
+    assert_eq!(tracker.update(None), None);
+    assert!(tracker.intensity() < busy);
+}
+
+#[test]
+fn nobody_in_view_gives_no_intensity() {
+    let mut tracker = DrummingTracker::default();
+    assert_eq!(tracker.update(None), None);
+    // A lone wrist is no forearm to measure.
+    let wrist_only = Arms {
+        wrists: [Some((100.0, 200.0)), None],
+        ..Arms::default()
+    };
+    assert_eq!(tracker.update(Some(wrist_only)), None);
+    assert_eq!(tracker.update(Some(arms(0.0))), Some(0.0));
+}
@@ -0,0 +1,234 @@
+#![cfg(target_os = "linux")]
+
+use bongo_modulator::process::{
+    find_processes, MatchRules, ProcessMatcher, ProcessTracker, TrackedProcess,
+};
+use std::os::unix::process::ExitStatusExt;
+use std::path::PathBuf;
+use std::process::{Child, Command};
+use std::time::{Duration, Instant};
+use tempfile::{tempdir, TempDir};
+
+/// Copies `sleep` under a unique name so the tests only see their own child.
+fn sleeper(tag: &str) -> (TempDir, PathBuf, String) {
+    let dir = tempdir().unwrap();
+    let name = format!("bt{tag}{}", std::process::id() % 100_000);
+    let sleep = ["/bin/sleep", "/usr/bin/sleep"]
+        .into_iter()
+        .find(|p| std::path::Path::new(p).exists())
+        .unwrap();
+    let path = dir.path().join(&name);
+    std::fs::copy(sleep, &path).unwrap();
+    (dir, path, name)
+}
+
+fn spawn(path: &PathBuf) -> Child {
+    let child = Command::new(path).arg("30").spawn().unwrap();
+    // Give exec a moment so the process carries its new name and argv; the
+    // kernel renames the task before it installs the new command line.
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let execed = || {
+        let comm = std::fs::read_to_string(format!("/proc/{}/comm", child.id()));
+        let cmdline = std::fs::read(format!("/proc/{}/cmdline", child.id()));
+        match (comm, cmdline) {
+            (Ok(comm), Ok(cmdline)) => {
+                comm.trim() == path.file_name().unwrap().to_str().unwrap()
+                    && cmdline.ends_with(b"30\0")
+            }
+            _ => true,
+        }
+    };
+    while !execed() {
+        assert!(Instant::now() < deadline);
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    child
+}
+
+#[test]
+fn discovers_spawned_process() {
+    let (_dir, path, name) = sleeper("d");
+    let mut child = spawn(&path);
+
+    assert_eq!(
+        find_processes(&ProcessMatcher::by_name(&name)),
+        vec![child.id()]
+    );
+    let mut tracker = ProcessTracker::new(ProcessMatcher::by_name(&name));
+    assert_eq!(tracker.discover(), 1);
+    assert_eq!(tracker.pids(), vec![child.id()]);
+    // Already tracked processes are not added twice.
+    assert_eq!(tracker.discover(), 0);
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn signal_reaches_target() {
+    let (_dir, path, name) = sleeper("s");
+    let mut child = spawn(&path);
+
+    let mut tracker = ProcessTracker::new(ProcessMatcher::by_name(&name));
+    tracker.wait_for_process(Duration::from_millis(10));
+    assert_eq!(tracker.signal_all(libc::SIGUSR2).sent, 1);
+
+    let status = child.wait().unwrap();
+    assert_eq!(status.signal(), Some(libc::SIGUSR2));
+}
+
+#[test]
+fn exited_target_is_dropped() {
+    let (_dir, path, name) = sleeper("x");
+    let mut child = spawn(&path);
+
+    let mut tracker = ProcessTracker::new(ProcessMatcher::by_name(&name));
+    tracker.discover();
+    let target = TrackedProcess::open(child.id()).unwrap();
+    assert!(target.is_alive());
+
+    child.kill().unwrap();
+    // Not reaped yet: the zombie must already count as gone.
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while target.is_alive() {
+        assert!(Instant::now() < deadline, "exit never observed");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(tracker.signal_all(libc::SIGUSR2).sent, 0);
+    assert!(tracker.is_empty());
+    child.wait().unwrap();
+}
+
+fn matches(name: &str, rules: MatchRules) -> Vec<u32> {
+    find_processes(&ProcessMatcher::new(name, &rules).unwrap())
+}
+
+#[test]
+fn uid_rule_defaults_to_own_user() {
+    let (_dir, path, name) = sleeper("u");
+    let mut child = spawn(&path);
+    let own = unsafe { libc::getuid() };
+
+    assert_eq!(matches(&name, MatchRules::default()), vec![child.id()]);
+    let other = MatchRules {
+        uid: Some(own + 1),
+        ..Default::default()
+    };
+    assert!(matches(&name, other).is_empty());
+    let any = MatchRules {
+        any_uid: true,
+        ..Default::default()
+    };
+    assert_eq!(matches(&name, any), vec![child.id()]);
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn exe_rule_tells_copies_apart() {
+    let (_dir, path, name) = sleeper("e");
+    let (_other_dir, other_path, _) = sleeper("e");
+    let mut child = spawn(&path);
+
+    let exact = MatchRules {
+        exe: Some(path.canonicalize().unwrap()),
+        ..Default::default()
+    };
+    assert_eq!(matches(&name, exact), vec![child.id()]);
+    let wrapper = MatchRules {
+        exe: Some(other_path),
+        ..Default::default()
+    };
+    assert!(matches(&name, wrapper).is_empty());
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn cmdline_rule_uses_regex() {
+    let (_dir, path, name) = sleeper("c");
+    let mut child = spawn(&path);
+
+    let hit = MatchRules {
+        cmdline: Some(r"\s30$".into()),
+        ..Default::default()
+    };
+    assert_eq!(matches(&name, hit), vec![child.id()]);
+    let miss = MatchRules {
+        cmdline: Some("--immediate".into()),
+        ..Default::default()
+    };
+    assert!(matches(&name, miss).is_empty());
+    assert!(ProcessMatcher::new(
+        &name,
+        &MatchRules {
+            cmdline: Some("(".into()),
+            ..Default::default()
+        }
+    )
+    .is_err());
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn cgroup_rule_matches_own_cgroup() {
+    let (_dir, path, name) = sleeper("g");
+    let mut child = spawn(&path);
+    let own = std::fs::read_to_string("/proc/self/cgroup").unwrap();
+    let own = own.lines().next().unwrap().splitn(3, ':').nth(2).unwrap();
+
+    let same = MatchRules {
+        cgroup: Some(own.to_string()),
+        ..Default::default()
+    };
+    assert_eq!(matches(&name, same), vec![child.id()]);
+    let other = MatchRules {
+        cgroup: Some("no-such-unit.service".into()),
+        ..Default::default()
+    };
+    assert!(matches(&name, other).is_empty());
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn cli_rules_override_config() {
+    let config = MatchRules {
+        exe: Some("/usr/bin/hyprlock".into()),
+        uid: Some(1000),
+        ..Default::default()
+    };
+    let cli = MatchRules {
+        uid: Some(1001),
+        cgroup: Some("lock.service".into()),
+        ..Default::default()
+    };
+    let merged = config.merge(cli);
+    assert_eq!(
+        merged.exe.as_deref(),
+        Some(std::path::Path::new("/usr/bin/hyprlock"))
+    );
+    assert_eq!(merged.uid, Some(1001));
+    assert_eq!(merged.cgroup.as_deref(), Some("lock.service"));
+}
+
+#[test]
+fn cli_uid_overrides_config_any_uid() {
+    let config = MatchRules {
+        any_uid: true,
+        ..Default::default()
+    };
+    let cli = MatchRules {
+        uid: Some(1001),
+        ..Default::default()
+    };
+    let merged = config.clone().merge(cli);
+    assert_eq!(merged.uid, Some(1001));
+    assert!(!merged.any_uid);
+    assert!(config.merge(MatchRules::default()).any_uid);
+}
@@ -0,0 +1,329 @@
+use bongo_modulator::events::{Event, EventKind};
+use bongo_modulator::ipc::{
+    encode_response, send_command, Client, ClientError, ControlMessage, ErrorKind, RequestEnvelope,
+    Response, ResponseEnvelope, REPLY_TIMEOUT,
+};
+use bongo_modulator::status::{Mode, Status};
+use serial_test::serial;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::{tempdir, TempDir};
+
+struct Daemon {
+    dir: TempDir,
+    child: Child,
+}
+
+impl Daemon {
+    /// Starts the daemon with a file target, so nothing else gets signalled.
+    fn spawn() -> Self {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("frames")).unwrap();
+        let child = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+            .arg("daemon")
+            .arg("--target")
+            .arg(format!("file:{}", dir.path().join("out").display()))
+            .env("BONGO_SOCKET", dir.path().join("bongo.sock"))
+            .env("BONGO_STATE_PATH", dir.path().join("state.json"))
+            .env("BONGO_IMAGE_DIR", dir.path().join("frames"))
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+        let daemon = Self { dir, child };
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while UnixStream::connect(daemon.socket()).is_err() {
+            assert!(Instant::now() < deadline, "daemon never listened");
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        daemon
+    }
+
+    fn socket(&self) -> PathBuf {
+        self.dir.path().join("bongo.sock")
+    }
+
+    /// Sends raw request lines and reads one response line per request.
+    fn exchange(&self, lines: &[&str]) -> Vec<ResponseEnvelope> {
+        let mut stream = UnixStream::connect(self.socket()).unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        lines
+            .iter()
+            .map(|line| {
+                writeln!(stream, "{line}").unwrap();
+                let mut buf = String::new();
+                reader.read_line(&mut buf).unwrap();
+                serde_json::from_str(&buf).unwrap()
+            })
+            .collect()
+    }
+
+    /// Talks to the daemon the way clients before the envelope did.
+    fn legacy(&self, msg: &str) -> String {
+        let mut stream = UnixStream::connect(self.socket()).unwrap();
+        stream.write_all(msg.as_bytes()).unwrap();
+        stream.shutdown(Shutdown::Write).unwrap();
+        let mut reply = String::new();
+        stream.read_to_string(&mut reply).unwrap();
+        reply
+    }
+}
+
+impl Drop for Daemon {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+#[serial]
+fn versioned_session_reports_typed_errors() {
+    let daemon = Daemon::spawn();
+    std::fs::write(daemon.dir.path().join("frames/001.png"), b"").unwrap();
+    std::env::set_var("BONGO_SOCKET", daemon.socket());
+
+    let mut client = Client::connect().unwrap();
+    assert_eq!(client.daemon_version, env!("CARGO_PKG_VERSION"));
+    match client.request(ControlMessage::NextImage) {
+        Ok(Response::Frame(path)) => assert!(path.ends_with("001.png")),
+        other => panic!("unexpected answer {other:?}"),
+    }
+    match client.request(ControlMessage::SetFps(f32::NAN)) {
+        Err(ClientError::Daemon(e)) => assert_eq!(e.kind, ErrorKind::BadRequest),
+        other => panic!("unexpected answer {other:?}"),
+    }
+    assert_eq!(
+        client.request(ControlMessage::SetFps(12.0)).unwrap(),
+        Response::Ok
+    );
+}
+
+#[test]
+fn mismatched_versions_are_refused() {
+    let daemon = Daemon::spawn();
+    let answers = daemon.exchange(&[
+        r#"{"version":99,"id":1,"message":"Hello"}"#,
+        r#"{"version":99,"id":2,"message":{"Teleport":"moon"}}"#,
+        r#"{"version":1,"id":3,"message":{"Teleport":"moon"}}"#,
+        r#"{"version":1,"id":4,"message":"NextImage"}"#,
+        r#"{"version":1,"id":5,"message":{"SetFps":8.0}}"#,
+    ]);
+
+    assert_eq!(answers[0].id, 1);
+    assert!(matches!(
+        answers[0].response,
+        Response::Hello { protocol: 1, .. }
+    ));
+    let kinds: Vec<_> = answers[1..]
+        .iter()
+        .map(|a| match &a.response {
+            Response::Error(e) => Some(e.kind),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            Some(ErrorKind::VersionMismatch),
+            Some(ErrorKind::BadRequest),
+            Some(ErrorKind::NoFrames),
+            None
+        ]
+    );
+    let ids: Vec<_> = answers.iter().map(|a| a.id).collect();
+    assert_eq!(ids, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn legacy_clients_still_work() {
+    let daemon = Daemon::spawn();
+    std::fs::write(daemon.dir.path().join("frames/001.png"), b"").unwrap();
+
+    assert!(daemon.legacy(r#""NextImage""#).ends_with("001.png"));
+    assert_eq!(daemon.legacy(r#"{"SetFps":9.0}"#), "");
+    let swap: Result<(), String> =
+        serde_json::from_str(&daemon.legacy(r#"{"SetModel":"missing.onnx"}"#)).unwrap();
+    assert!(swap.is_err());
+}
+
+#[test]
+fn hung_client_does_not_freeze_next_image() {
+    let daemon = Daemon::spawn();
+    std::fs::write(daemon.dir.path().join("frames/001.png"), b"").unwrap();
+    let mut hung = UnixStream::connect(daemon.socket()).unwrap();
+    hung.write_all(br#"{"version":1,"id":1,"#).unwrap();
+
+    let started = Instant::now();
+    let output = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("next-image")
+        .env("BONGO_SOCKET", daemon.socket())
+        .stderr(Stdio::null())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .ends_with("001.png"));
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+#[serial]
+fn one_shot_commands_skip_the_handshake() {
+    let dir = tempdir().unwrap();
+    let listener = UnixListener::bind(dir.path().join("bongo.sock")).unwrap();
+    std::env::set_var("BONGO_SOCKET", dir.path().join("bongo.sock"));
+    let daemon = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(&stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let request: RequestEnvelope = serde_json::from_str(&line).unwrap();
+        (&stream)
+            .write_all(&encode_response(
+                request.id,
+                Response::Frame("/frames/001.png".into()),
+            ))
+            .unwrap();
+        request.message
+    });
+
+    assert_eq!(
+        send_command(ControlMessage::NextImage).unwrap(),
+        Response::Frame("/frames/001.png".into())
+    );
+    assert_eq!(daemon.join().unwrap(), ControlMessage::NextImage);
+}
+
+#[test]
+#[serial]
+fn silent_daemon_times_out() {
+    let dir = tempdir().unwrap();
+    let listener = UnixListener::bind(dir.path().join("bongo.sock")).unwrap();
+    std::env::set_var("BONGO_SOCKET", dir.path().join("bongo.sock"));
+
+    let started = Instant::now();
+    match send_command(ControlMessage::NextImage) {
+        Err(ClientError::Protocol(msg)) => assert!(msg.contains("did not answer"), "{msg}"),
+        other => panic!("unexpected answer {other:?}"),
+    }
+    assert!(started.elapsed() < REPLY_TIMEOUT * 2);
+    drop(listener);
+}
+
+#[test]
+fn status_reports_daemon_state() {
+    let daemon = Daemon::spawn();
+    std::fs::write(daemon.dir.path().join("frames/001.png"), b"").unwrap();
+    std::fs::write(daemon.dir.path().join("frames/002.png"), b"").unwrap();
+    daemon.exchange(&[
+        r#"{"version":1,"id":1,"message":{"SetFps":12.0}}"#,
+        r#"{"version":1,"id":2,"message":"NextImage"}"#,
+    ]);
+    // Let the loop tick at the new rate.
+    std::thread::sleep(Duration::from_millis(300));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .args(["status", "--json"])
+        .env("BONGO_SOCKET", daemon.socket())
+        .env("BONGO_STATE_PATH", daemon.dir.path().join("state.json"))
+        .stderr(Stdio::null())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let status: Status = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(status.pid, daemon.child.id());
+    assert_eq!(status.fps, 12.0);
+    assert_eq!(status.mode, Mode::Manual);
+    assert_eq!(status.frames.count, 2);
+    assert_eq!(status.frames.index, 1);
+    assert_eq!(status.fetches.fetches, 1);
+    assert_eq!(status.targets.len(), 1);
+    assert!(status.targets[0].fired > 0);
+    assert!(status.ticks.ticks > 0);
+    // The daemon never saved the new rate, so state.json still has the old one.
+    assert!(status
+        .drift
+        .iter()
+        .any(|d| d.setting == "fps" && d.daemon == "12"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .arg("status")
+        .env("BONGO_SOCKET", daemon.socket())
+        .env("BONGO_STATE_PATH", daemon.dir.path().join("state.json"))
+        .stderr(Stdio::null())
+        .output()
+        .unwrap();
+    let text = String::from_utf8_lossy(&output.stdout);
+    assert!(text.contains("mode      manual at 12.0 fps"), "{text}");
+    assert!(text.contains("drift     fps"), "{text}");
+
+    // Saving the running rate clears the drift without a reload.
+    std::fs::write(
+        daemon.dir.path().join("state.json"),
+        r#"{"fps":12.0,"ai_mode":false}"#,
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .args(["status", "--json"])
+        .env("BONGO_SOCKET", daemon.socket())
+        .env("BONGO_STATE_PATH", daemon.dir.path().join("state.json"))
+        .stderr(Stdio::null())
+        .output()
+        .unwrap();
+    let status: Status = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(status.drift.is_empty(), "{:?}", status.drift);
+}
+
+#[test]
+#[serial]
+fn subscribers_see_events_as_they_happen() {
+    let daemon = Daemon::spawn();
+    std::fs::write(daemon.dir.path().join("frames/001.png"), b"").unwrap();
+    std::env::set_var("BONGO_SOCKET", daemon.socket());
+
+    let mut events = Client::connect()
+        .unwrap()
+        .subscribe(vec![EventKind::Fps, EventKind::Frame])
+        .unwrap();
+    daemon.exchange(&[
+        r#"{"version":1,"id":1,"message":{"SetFps":7.0}}"#,
+        r#"{"version":1,"id":2,"message":"NextImage"}"#,
+        r#"{"version":1,"id":3,"message":{"SetDarkThreshold":0.3}}"#,
+    ]);
+    assert_eq!(events.next().unwrap().unwrap(), Event::Fps(7.0));
+    match events.next().unwrap().unwrap() {
+        Event::Frame { path, index: 0 } => assert!(path.ends_with("001.png")),
+        other => panic!("unexpected event {other:?}"),
+    }
+
+    // `watch` prints the same stream; poke the daemon until it has joined.
+    let mut watch = Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+        .args(["watch", "--json", "--only", "fps"])
+        .env("BONGO_SOCKET", daemon.socket())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let stdout = watch.stdout.take().unwrap();
+    let reader = std::thread::spawn(move || {
+        let mut line = String::new();
+        BufReader::new(stdout).read_line(&mut line).unwrap();
+        line
+    });
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !reader.is_finished() {
+        assert!(Instant::now() < deadline, "watch printed nothing");
+        daemon.exchange(&[r#"{"version":1,"id":1,"message":{"SetFps":9.0}}"#]);
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    let _ = watch.kill();
+    let _ = watch.wait();
+    let event: Event = serde_json::from_str(&reader.join().unwrap()).unwrap();
+    assert_eq!(event, Event::Fps(9.0));
+}
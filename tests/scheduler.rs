@@ -0,0 +1,164 @@
+use bongo_modulator::scheduler::{Clock, Rate, Scheduler, TickStats};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A clock that only moves when waited on or advanced by hand.
+#[derive(Clone)]
+struct ManualClock {
+    now: Rc<Cell<Instant>>,
+}
+
+impl ManualClock {
+    fn new() -> Self {
+        Self {
+            now: Rc::new(Cell::new(Instant::now())),
+        }
+    }
+
+    fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+
+    fn wait_until(&self, rate: &Rate, seen: u64, deadline: Instant) {
+        if rate.generation() == seen && deadline > self.now.get() {
+            self.now.set(deadline);
+        }
+    }
+}
+
+const MS: Duration = Duration::from_millis(1);
+
+#[test]
+fn ticks_keep_absolute_deadlines() {
+    let clock = ManualClock::new();
+    let start = clock.now();
+    let rate = Rate::new(10.0);
+    let mut scheduler = Scheduler::new(clock.clone());
+
+    for i in 0..10u32 {
+        scheduler.wait(&rate);
+        assert_eq!(clock.now() - start, 100 * MS * i);
+        // Work done during the tick does not push later ticks back.
+        clock.advance(30 * MS);
+    }
+    let stats = scheduler.stats();
+    assert_eq!(stats.ticks, 10);
+    assert_eq!(stats.missed, 0);
+    assert_eq!(stats.max_jitter, Duration::ZERO);
+}
+
+#[test]
+fn late_ticks_are_coalesced_and_counted() {
+    let clock = ManualClock::new();
+    let start = clock.now();
+    let rate = Rate::new(10.0);
+    let mut scheduler = Scheduler::new(clock.clone());
+
+    scheduler.wait(&rate);
+    // Stall through the deadlines at 100, 200 and 300 ms.
+    clock.advance(350 * MS);
+    scheduler.wait(&rate);
+    assert_eq!(clock.now() - start, 350 * MS);
+    // The schedule keeps its phase instead of restarting from the stall.
+    scheduler.wait(&rate);
+    assert_eq!(clock.now() - start, 400 * MS);
+
+    let stats = scheduler.stats();
+    assert_eq!(stats.ticks, 3);
+    assert_eq!(stats.missed, 2);
+    assert_eq!(stats.last_jitter, Duration::ZERO);
+    assert_eq!(stats.max_jitter, 250 * MS);
+    assert_eq!(stats.mean_jitter(), 250 * MS / 3);
+}
+
+#[test]
+fn long_stall_keeps_the_phase() {
+    let clock = ManualClock::new();
+    let start = clock.now();
+    let rate = Rate::new(1000.0);
+    let mut scheduler = Scheduler::new(clock.clone());
+
+    scheduler.wait(&rate);
+    // More missed deadlines than fit in a u32, as after a long suspend.
+    let missed = u64::from(u32::MAX) + 1;
+    clock.advance(MS.mul_f64(missed as f64 + 1.5));
+    scheduler.wait(&rate);
+    assert_eq!(scheduler.stats().missed, missed);
+    scheduler.wait(&rate);
+    let next = MS.mul_f64(missed as f64 + 2.0);
+    let error = (clock.now() - start).abs_diff(next);
+    assert!(error < Duration::from_micros(1), "off by {error:?}");
+}
+
+#[test]
+fn rate_change_reschedules_from_last_tick() {
+    let clock = ManualClock::new();
+    let start = clock.now();
+    let rate = Rate::new(1.0);
+    let mut scheduler = Scheduler::new(clock.clone());
+
+    scheduler.wait(&rate);
+    clock.advance(20 * MS);
+    rate.set(10.0);
+    scheduler.wait(&rate);
+    assert_eq!(clock.now() - start, 100 * MS);
+
+    // Slowing down past the current time fires on the new period too.
+    clock.advance(50 * MS);
+    rate.set(2.0);
+    scheduler.wait(&rate);
+    assert_eq!(clock.now() - start, 600 * MS);
+    assert_eq!(scheduler.stats().missed, 0);
+}
+
+#[test]
+fn rate_change_wakes_a_waiting_scheduler() {
+    let rate = Arc::new(Rate::new(0.5));
+    let seen = rate.generation();
+    let setter = rate.clone();
+    let handle = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        setter.set(30.0);
+    });
+    let started = Instant::now();
+    assert!(rate.wait_changed(seen, Duration::from_secs(10)));
+    assert!(started.elapsed() < Duration::from_secs(5));
+    handle.join().unwrap();
+
+    // Setting the same rate again is not a change.
+    let seen = rate.generation();
+    rate.set(30.0);
+    assert!(!rate.wait_changed(seen, Duration::ZERO));
+}
+
+#[test]
+fn stats_start_empty() {
+    let scheduler = Scheduler::new(ManualClock::new());
+    assert_eq!(scheduler.stats(), TickStats::default());
+    assert_eq!(scheduler.stats().mean_jitter(), Duration::ZERO);
+}
+
+#[test]
+fn closing_the_rate_stops_the_scheduler() {
+    let rate = Arc::new(Rate::new(0.5));
+    let closer = rate.clone();
+    let mut scheduler = Scheduler::new(bongo_modulator::scheduler::SystemClock);
+    assert!(scheduler.wait(&rate).is_some());
+    let handle = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        closer.close();
+    });
+    let started = Instant::now();
+    // The next tick is two seconds out; closing ends the wait right away.
+    assert!(scheduler.wait(&rate).is_none());
+    assert!(started.elapsed() < Duration::from_secs(1));
+    handle.join().unwrap();
+}
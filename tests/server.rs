@@ -0,0 +1,207 @@
+use bongo_modulator::ipc::{ErrorKind, Response, ResponseEnvelope};
+use bongo_modulator::peer::{peer_of, AllowList};
+use bongo_modulator::server::{serve, Connection, Limits, ReadError};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tempfile::{tempdir, TempDir};
+
+const LIMITS: Limits = Limits {
+    read_timeout: Duration::from_millis(300),
+    write_timeout: Duration::from_millis(300),
+    max_message: 64,
+    max_connections: 2,
+    max_subscribers: 1,
+};
+
+/// Echoes every message back as a line, and `too large` for oversized ones.
+/// `subscribe` turns the connection into a subscriber held until hang-up.
+fn echo(mut conn: Connection) {
+    let mut out = conn.writer().unwrap();
+    loop {
+        match conn.read_message() {
+            Ok(Some(msg)) if msg.trim_ascii() == b"subscribe" => {
+                if conn.subscribe().is_err() {
+                    out.write_all(b"busy\n").unwrap();
+                    continue;
+                }
+                out.write_all(b"subscribed\n").unwrap();
+                while !conn.hung_up() {
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+                return;
+            }
+            Ok(Some(msg)) => {
+                out.write_all(b"echo ").unwrap();
+                out.write_all(msg.trim_ascii()).unwrap();
+                out.write_all(b"\n").unwrap();
+            }
+            Ok(None) | Err(ReadError::Io(_)) => return,
+            Err(ReadError::TooLarge(_)) => {
+                let _ = out.write_all(b"too large\n");
+                return;
+            }
+        }
+    }
+}
+
+struct Server {
+    _dir: TempDir,
+    path: PathBuf,
+}
+
+impl Server {
+    fn start() -> Self {
+        Self::with_limits(LIMITS)
+    }
+
+    fn with_limits(limits: Limits) -> Self {
+        Self::with(limits, AllowList::same_user())
+    }
+
+    fn with(limits: Limits, allow: AllowList) -> Self {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("sock");
+        let listener = UnixListener::bind(&path).unwrap();
+        let stop = Arc::new(AtomicBool::new(false));
+        std::thread::spawn(move || serve(listener, limits, allow, stop, echo));
+        Self { _dir: dir, path }
+    }
+
+    fn connect(&self) -> (UnixStream, BufReader<UnixStream>) {
+        let stream = UnixStream::connect(&self.path).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let reader = BufReader::new(stream.try_clone().unwrap());
+        (stream, reader)
+    }
+
+    fn ask(&self, msg: &str) -> String {
+        let (mut stream, mut reader) = self.connect();
+        writeln!(stream, "{msg}").unwrap();
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        line
+    }
+}
+
+#[test]
+fn hung_client_does_not_block_others() {
+    let server = Server::start();
+    // Half a request, never finished.
+    let (mut hung, _) = server.connect();
+    hung.write_all(b"{\"version\":1,").unwrap();
+
+    let started = Instant::now();
+    assert_eq!(server.ask("hello"), "echo hello\n");
+    assert!(started.elapsed() < LIMITS.read_timeout);
+}
+
+#[test]
+fn stalled_client_is_dropped() {
+    let server = Server::start();
+    let (_stream, mut reader) = server.connect();
+
+    let started = Instant::now();
+    let mut rest = String::new();
+    // EOF once the server gives up on us.
+    reader.read_to_string(&mut rest).unwrap();
+    assert!(started.elapsed() >= LIMITS.read_timeout);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn trickling_client_is_dropped_at_the_deadline() {
+    let server = Server::start();
+    let (mut stream, mut reader) = server.connect();
+
+    let started = Instant::now();
+    let trickle = std::thread::spawn(move || {
+        // A byte well within each read timeout, never finishing the line.
+        while stream.write_all(b"x").is_ok() && started.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(LIMITS.read_timeout / 4);
+        }
+    });
+    let mut rest = String::new();
+    reader.read_to_string(&mut rest).unwrap();
+    assert!(started.elapsed() < LIMITS.read_timeout * 3);
+    trickle.join().unwrap();
+}
+
+#[test]
+fn subscribers_do_not_take_connection_slots() {
+    let server = Server::start();
+    let subscribe = || {
+        let (mut stream, mut reader) = server.connect();
+        writeln!(stream, "subscribe").unwrap();
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        (stream, line)
+    };
+    let (_first, reply) = subscribe();
+    assert_eq!(reply, "subscribed\n");
+    let (_second, reply) = subscribe();
+    assert_eq!(reply, "busy\n");
+
+    // One subscriber and the refused one's connection leave a slot free.
+    assert_eq!(server.ask("hello"), "echo hello\n");
+}
+
+#[test]
+fn connections_beyond_the_cap_are_refused() {
+    let server = Server::start();
+    let _first = server.connect();
+    let _second = server.connect();
+
+    let reply: ResponseEnvelope = serde_json::from_str(&server.ask("hello")).unwrap();
+    assert_eq!(reply.id, 0);
+    assert!(matches!(reply.response, Response::Error(e) if e.kind == ErrorKind::Busy));
+
+    // Slots free up as the idle clients time out.
+    std::thread::sleep(LIMITS.read_timeout * 2);
+    assert_eq!(server.ask("hello"), "echo hello\n");
+}
+
+#[test]
+fn oversized_messages_are_rejected() {
+    // Earlier connections may not have released their slots yet.
+    let server = Server::with_limits(Limits {
+        max_connections: 8,
+        ..LIMITS
+    });
+    assert_eq!(
+        server.ask(&"x".repeat(64)),
+        format!("echo {}\n", "x".repeat(64))
+    );
+    assert_eq!(server.ask(&"x".repeat(1000)), "too large\n");
+
+    // A message ended by EOF instead of a newline, as legacy clients send.
+    let (mut stream, mut reader) = server.connect();
+    stream.write_all(b"legacy").unwrap();
+    stream.shutdown(std::net::Shutdown::Write).unwrap();
+    let mut reply = String::new();
+    reader.read_to_string(&mut reply).unwrap();
+    assert_eq!(reply, "echo legacy\n");
+}
+
+#[test]
+fn users_off_the_allow_list_are_refused() {
+    let (ours, _) = UnixStream::pair().unwrap();
+    let me = peer_of(&ours).unwrap();
+    assert_eq!(
+        me.pid,
+        Some(std::process::id()).filter(|_| cfg!(target_os = "linux"))
+    );
+
+    let server = Server::with(LIMITS, AllowList::uids([me.uid + 1]));
+    let reply: ResponseEnvelope = serde_json::from_str(&server.ask("hello")).unwrap();
+    assert_eq!(reply.id, 0);
+    assert!(matches!(reply.response, Response::Error(e) if e.kind == ErrorKind::PermissionDenied));
+
+    let server = Server::with(LIMITS, AllowList::uids([me.uid + 1]).with([me.uid]));
+    assert_eq!(server.ask("hello"), "echo hello\n");
+}
@@ -0,0 +1,154 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use bongo_modulator::session::{watch_logind, LockState};
+use common::{start_bus, Bus};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use zbus::blocking::{connection, Connection};
+use zbus::zvariant::{OwnedObjectPath, Value};
+
+const SESSION: &str = "/org/freedesktop/login1/session/_31";
+const WAIT: Duration = Duration::from_secs(5);
+
+struct Manager;
+
+#[zbus::interface(name = "org.freedesktop.login1.Manager")]
+impl Manager {
+    // Any id resolves, so an `XDG_SESSION_ID` in the test environment works.
+    fn get_session(&self, _id: &str) -> OwnedObjectPath {
+        OwnedObjectPath::try_from(SESSION).unwrap()
+    }
+}
+
+struct Session {
+    locked: Arc<AtomicBool>,
+}
+
+#[zbus::interface(name = "org.freedesktop.login1.Session")]
+impl Session {
+    #[zbus(property)]
+    fn locked_hint(&self) -> bool {
+        self.locked.load(Ordering::Relaxed)
+    }
+}
+
+/// Mock logind serving one session, whose hint the test controls.
+struct Logind {
+    conn: Connection,
+    locked: Arc<AtomicBool>,
+}
+
+impl Logind {
+    fn start(bus: &Bus, locked: bool) -> Self {
+        let locked = Arc::new(AtomicBool::new(locked));
+        let conn = connection::Builder::address(bus.address.as_str())
+            .unwrap()
+            .name("org.freedesktop.login1")
+            .unwrap()
+            .serve_at("/org/freedesktop/login1", Manager)
+            .unwrap()
+            .serve_at(
+                SESSION,
+                Session {
+                    locked: locked.clone(),
+                },
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        Self { conn, locked }
+    }
+
+    fn emit(&self, member: &str) {
+        self.conn
+            .emit_signal(
+                None::<&str>,
+                SESSION,
+                "org.freedesktop.login1.Session",
+                member,
+                &(),
+            )
+            .unwrap();
+    }
+
+    fn set_hint(&self, locked: bool) {
+        self.locked.store(locked, Ordering::Relaxed);
+        let changed = HashMap::from([("LockedHint", Value::from(locked))]);
+        self.conn
+            .emit_signal(
+                None::<&str>,
+                SESSION,
+                "org.freedesktop.DBus.Properties",
+                "PropertiesChanged",
+                &(
+                    "org.freedesktop.login1.Session",
+                    changed,
+                    Vec::<&str>::new(),
+                ),
+            )
+            .unwrap();
+    }
+}
+
+fn client(bus: &Bus) -> Connection {
+    connection::Builder::address(bus.address.as_str())
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn follows_lock_and_unlock_signals() {
+    let Some(bus) = start_bus() else { return };
+    let logind = Logind::start(&bus, false);
+    let state = Arc::new(LockState::new(true));
+
+    watch_logind(client(&bus), state.clone()).unwrap();
+    assert!(!state.is_locked());
+
+    logind.emit("Lock");
+    assert!(state.wait_for(true, WAIT));
+    logind.emit("Unlock");
+    assert!(state.wait_for(false, WAIT));
+}
+
+#[test]
+fn follows_locked_hint() {
+    let Some(bus) = start_bus() else { return };
+    let logind = Logind::start(&bus, true);
+    let state = Arc::new(LockState::new(false));
+
+    watch_logind(client(&bus), state.clone()).unwrap();
+    assert!(state.is_locked());
+
+    logind.set_hint(false);
+    assert!(state.wait_for(false, WAIT));
+    logind.set_hint(true);
+    assert!(state.wait_for(true, WAIT));
+}
+
+#[test]
+fn fails_without_logind() {
+    let Some(bus) = start_bus() else { return };
+    let state = Arc::new(LockState::new(true));
+    assert!(watch_logind(client(&bus), state.clone()).is_err());
+    assert!(state.is_locked());
+}
+
+#[test]
+fn wait_locked_returns_once_locked() {
+    let state = Arc::new(LockState::new(false));
+    let setter = state.clone();
+    let handle = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        setter.set(true);
+    });
+    state.wait_locked();
+    assert!(state.is_locked());
+    assert!(!state.set(true));
+    handle.join().unwrap();
+}
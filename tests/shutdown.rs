@@ -0,0 +1,118 @@
+mod common;
+
+use common::wait_exit;
+use std::io::{Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::{tempdir, TempDir};
+
+struct Env {
+    dir: TempDir,
+}
+
+impl Env {
+    fn new() -> Self {
+        Self {
+            dir: tempdir().unwrap(),
+        }
+    }
+
+    fn socket(&self) -> PathBuf {
+        self.dir.path().join("bongo.sock")
+    }
+
+    fn state(&self) -> PathBuf {
+        self.dir.path().join("state.json")
+    }
+
+    /// Starts the daemon with a file target, so nothing else gets signalled.
+    fn spawn(&self) -> Child {
+        Command::new(env!("CARGO_BIN_EXE_bongo-modulator"))
+            .arg("daemon")
+            .arg("--target")
+            .arg(format!("file:{}", self.dir.path().join("out").display()))
+            .env("BONGO_SOCKET", self.socket())
+            .env("BONGO_STATE_PATH", self.state())
+            .env("BONGO_IMAGE_DIR", self.dir.path())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap()
+    }
+
+    fn send(&self, msg: &str) -> String {
+        let mut stream = UnixStream::connect(self.socket()).unwrap();
+        stream.write_all(msg.as_bytes()).unwrap();
+        stream.shutdown(Shutdown::Write).unwrap();
+        let mut reply = String::new();
+        stream.read_to_string(&mut reply).unwrap();
+        reply
+    }
+}
+
+fn wait_for_socket(path: &Path) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while UnixStream::connect(path).is_err() {
+        assert!(Instant::now() < deadline, "daemon never listened");
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+fn kill(child: &Child, signal: i32) {
+    assert_eq!(unsafe { libc::kill(child.id() as i32, signal) }, 0);
+}
+
+#[test]
+fn signals_stop_the_daemon_cleanly() {
+    for signal in [libc::SIGTERM, libc::SIGINT] {
+        let env = Env::new();
+        let mut daemon = env.spawn();
+        wait_for_socket(&env.socket());
+
+        // The daemon closes a legacy connection only once the message has
+        // been applied, so the rate change is in place before the signal.
+        env.send(r#"{"SetFps":12.0}"#);
+        kill(&daemon, signal);
+
+        assert!(wait_exit(&mut daemon).success());
+        assert!(!env.socket().exists(), "socket left behind");
+        let state: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(env.state()).unwrap()).unwrap();
+        assert_eq!(state["fps"], 12.0);
+    }
+}
+
+#[test]
+fn stale_socket_is_replaced() {
+    let env = Env::new();
+    drop(UnixListener::bind(env.socket()).unwrap());
+    assert!(env.socket().exists());
+
+    let mut daemon = env.spawn();
+    wait_for_socket(&env.socket());
+    kill(&daemon, libc::SIGTERM);
+    assert!(wait_exit(&mut daemon).success());
+}
+
+#[test]
+fn live_socket_is_left_alone() {
+    let env = Env::new();
+    let _other = UnixListener::bind(env.socket()).unwrap();
+
+    let mut daemon = env.spawn();
+    assert_eq!(wait_exit(&mut daemon).code(), Some(1));
+    assert!(env.socket().exists());
+}
+
+#[test]
+fn unrelated_file_is_not_deleted() {
+    let env = Env::new();
+    std::fs::write(env.socket(), "precious").unwrap();
+
+    let mut daemon = env.spawn();
+    assert_eq!(wait_exit(&mut daemon).code(), Some(1));
+    assert_eq!(std::fs::read_to_string(env.socket()).unwrap(), "precious");
+}
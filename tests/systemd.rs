@@ -0,0 +1,197 @@
+mod common;
+
+use bongo_modulator::systemd::{service_unit, socket_unit};
+use common::wait_exit;
+use std::io::{Read, Write};
+use std::net::Shutdown;
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::{UnixDatagram, UnixListener, UnixStream};
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::time::Duration;
+use tempfile::{tempdir, TempDir};
+
+const EXE: &str = env!("CARGO_BIN_EXE_bongo-modulator");
+
+struct Env {
+    dir: TempDir,
+}
+
+impl Env {
+    fn new() -> Self {
+        Self {
+            dir: tempdir().unwrap(),
+        }
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.dir.path().join(name)
+    }
+
+    /// The daemon with a file target, so nothing else gets signalled.
+    fn daemon(&self, program: &str) -> Command {
+        let mut cmd = Command::new(program);
+        cmd.env("BONGO_SOCKET", self.path("bongo.sock"))
+            .env("BONGO_STATE_PATH", self.path("state.json"))
+            .env("BONGO_IMAGE_DIR", self.dir.path())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        cmd
+    }
+
+    fn notify_socket(&self) -> UnixDatagram {
+        let sock = UnixDatagram::bind(self.path("notify")).unwrap();
+        sock.set_read_timeout(Some(Duration::from_secs(10)))
+            .unwrap();
+        sock
+    }
+
+    fn target(&self) -> String {
+        format!("file:{}", self.path("out").display())
+    }
+}
+
+/// Reads notifications until one contains `line`.
+fn expect(sock: &UnixDatagram, line: &str) -> String {
+    let mut buf = [0; 4096];
+    loop {
+        let n = sock.recv(&mut buf).expect("no notification");
+        let msg = String::from_utf8_lossy(&buf[..n]).into_owned();
+        if msg.lines().any(|l| l == line) {
+            return msg;
+        }
+    }
+}
+
+fn send(path: &Path, msg: &str) -> String {
+    let mut stream = UnixStream::connect(path).unwrap();
+    stream.write_all(msg.as_bytes()).unwrap();
+    stream.shutdown(Shutdown::Write).unwrap();
+    let mut reply = String::new();
+    stream.read_to_string(&mut reply).unwrap();
+    reply
+}
+
+fn terminate(child: &mut Child) -> ExitStatus {
+    assert_eq!(unsafe { libc::kill(child.id() as i32, libc::SIGTERM) }, 0);
+    wait_exit(child)
+}
+
+#[test]
+fn notifies_readiness_and_shutdown() {
+    let env = Env::new();
+    let notify = env.notify_socket();
+    let mut daemon = env
+        .daemon(EXE)
+        .args(["daemon", "--target", &env.target()])
+        .env("NOTIFY_SOCKET", env.path("notify"))
+        .spawn()
+        .unwrap();
+
+    let ready = expect(&notify, "READY=1");
+    assert!(ready.lines().any(|l| l == "STATUS=animating"));
+    // Ready means commands are accepted.
+    UnixStream::connect(env.path("bongo.sock")).unwrap();
+
+    assert!(terminate(&mut daemon).success());
+    expect(&notify, "STOPPING=1");
+}
+
+#[test]
+fn pings_the_watchdog() {
+    let env = Env::new();
+    let notify = env.notify_socket();
+    let mut daemon = env
+        .daemon(EXE)
+        .args(["daemon", "--target", &env.target()])
+        .env("NOTIFY_SOCKET", env.path("notify"))
+        .env("WATCHDOG_USEC", "200000")
+        .spawn()
+        .unwrap();
+
+    expect(&notify, "READY=1");
+    expect(&notify, "WATCHDOG=1");
+    expect(&notify, "WATCHDOG=1");
+    assert!(terminate(&mut daemon).success());
+}
+
+#[test]
+fn serves_the_activated_socket() {
+    let env = Env::new();
+    let activated = env.path("activated.sock");
+    let listener = UnixListener::bind(&activated).unwrap();
+    let fd = listener.as_raw_fd();
+    let mut cmd = env.daemon("sh");
+    // `exec` keeps the shell's pid, which is what LISTEN_PID has to name.
+    cmd.args([
+        "-c",
+        r#"LISTEN_PID=$$ LISTEN_FDS=1 exec "$0" daemon --target "$1""#,
+        EXE,
+        &env.target(),
+    ]);
+    unsafe {
+        cmd.pre_exec(move || {
+            // dup2 onto itself would keep the close-on-exec flag.
+            let passed = if fd == 3 {
+                libc::fcntl(fd, libc::F_SETFD, 0)
+            } else {
+                libc::dup2(fd, 3)
+            };
+            if passed < 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+    let mut daemon = cmd.spawn().unwrap();
+    drop(listener);
+
+    std::fs::write(env.path("001.png"), b"").unwrap();
+    let frame = send(&activated, r#""NextImage""#);
+    assert!(frame.ends_with("001.png"), "unexpected reply {frame:?}");
+    assert!(!env.path("bongo.sock").exists());
+
+    assert!(terminate(&mut daemon).success());
+    assert!(activated.exists(), "activated socket belongs to systemd");
+}
+
+#[test]
+fn install_service_writes_units() {
+    let env = Env::new();
+    let units = env.path("units");
+    let install = |force: bool| {
+        let mut cmd = Command::new(EXE);
+        cmd.args(["install-service", "--exe", "/opt/bongo"])
+            .arg("--dir")
+            .arg(&units)
+            .stderr(Stdio::null());
+        if force {
+            cmd.arg("--force");
+        }
+        cmd.output().unwrap()
+    };
+
+    let out = install(false);
+    assert!(out.status.success());
+    assert!(String::from_utf8_lossy(&out.stdout).contains("enable --now bongo-modulator.socket"));
+    let service = std::fs::read_to_string(units.join("bongo-modulator.service")).unwrap();
+    assert_eq!(service, service_unit(Path::new("/opt/bongo")));
+    assert!(service.contains("ExecStart=/opt/bongo daemon\n"));
+    assert!(service.contains("Type=notify\n"));
+    let socket = std::fs::read_to_string(units.join("bongo-modulator.socket")).unwrap();
+    assert_eq!(socket, socket_unit());
+    assert!(socket.contains("ListenStream=%t/bongo.sock\n"));
+
+    std::fs::write(units.join("bongo-modulator.socket"), "custom").unwrap();
+    assert!(!install(false).status.success());
+    assert_eq!(
+        std::fs::read_to_string(units.join("bongo-modulator.socket")).unwrap(),
+        "custom"
+    );
+    assert!(install(true).status.success());
+    assert_eq!(
+        std::fs::read_to_string(units.join("bongo-modulator.socket")).unwrap(),
+        socket_unit()
+    );
+}
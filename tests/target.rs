@@ -0,0 +1,264 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use bongo_modulator::process::{MatchRules, ProcessMatcher};
+use bongo_modulator::target::{
+    parse_signal, ExecTarget, FileTarget, Outcome, ScheduledTarget, SignalTarget, Target,
+    TargetKind, TargetSpec, Tick,
+};
+use common::{sleeper, wait_for};
+use serial_test::serial;
+use std::ffi::CString;
+use std::os::unix::fs::OpenOptionsExt;
+use std::time::Duration;
+use tempfile::tempdir;
+
+const TICK: Tick = Tick { fps: 12.5 };
+
+#[test]
+fn parses_target_specs() {
+    let spec: TargetSpec = "signal:SIGRTMIN+8@waybar".parse().unwrap();
+    assert_eq!(
+        spec,
+        TargetSpec {
+            kind: TargetKind::Signal {
+                signal: "SIGRTMIN+8".into(),
+                process: Some("waybar".into()),
+            },
+            divisor: 1,
+        }
+    );
+    let spec: TargetSpec = "3:exec:eww update bongo={frame}".parse().unwrap();
+    assert_eq!(spec.divisor, 3);
+    assert_eq!(
+        spec.kind,
+        TargetKind::Exec {
+            command: vec!["eww".into(), "update".into(), "bongo={frame}".into()],
+        }
+    );
+    let spec: TargetSpec = r#"exec:notify-send "bongo cat" '{frame}'"#.parse().unwrap();
+    assert_eq!(
+        spec.kind,
+        TargetKind::Exec {
+            command: vec!["notify-send".into(), "bongo cat".into(), "{frame}".into()],
+        }
+    );
+    assert!(r#"exec:notify-send "bongo"#.parse::<TargetSpec>().is_err());
+    let spec: TargetSpec = "file:/tmp/bongo".parse().unwrap();
+    assert!(
+        matches!(spec.kind, TargetKind::File { path, .. } if path == std::path::Path::new("/tmp/bongo"))
+    );
+
+    assert!("0:file:/tmp/x".parse::<TargetSpec>().is_err());
+    assert!("socket:/tmp/x".parse::<TargetSpec>().is_err());
+    assert!("signal:SIGNOPE".parse::<TargetSpec>().is_err());
+    assert!("signal".parse::<TargetSpec>().is_err());
+}
+
+#[test]
+fn parses_signals() {
+    assert_eq!(parse_signal("SIGUSR2"), Ok(libc::SIGUSR2));
+    assert_eq!(parse_signal("usr1"), Ok(libc::SIGUSR1));
+    assert_eq!(parse_signal("10"), Ok(10));
+    assert_eq!(parse_signal("SIGRTMIN+8"), Ok(libc::SIGRTMIN() + 8));
+    assert_eq!(parse_signal("RTMAX-1"), Ok(libc::SIGRTMAX() - 1));
+    assert!(parse_signal("RTMIN+x").is_err());
+}
+
+#[test]
+fn targets_deserialize_from_config() {
+    let json = r#"[
+        {"type": "signal", "signal": "SIGUSR2"},
+        {"type": "file", "path": "/tmp/bongo", "divisor": 2}
+    ]"#;
+    let specs: Vec<TargetSpec> = serde_json::from_str(json).unwrap();
+    assert_eq!(specs[0], TargetSpec::hyprlock());
+    assert_eq!(
+        specs[1],
+        TargetSpec {
+            kind: TargetKind::File {
+                path: "/tmp/bongo".into(),
+                template: "{frame}\n".into(),
+            },
+            divisor: 2,
+        }
+    );
+    let zero = TargetSpec {
+        divisor: 0,
+        ..TargetSpec::hyprlock()
+    };
+    assert!(zero
+        .build("hyprlock", &MatchRules::default(), None)
+        .is_err());
+}
+
+#[test]
+fn exec_target_expands_placeholders() {
+    let dir = tempdir().unwrap();
+    let out = dir.path().join("out");
+    let mut target = ExecTarget::new(vec![
+        "sh".into(),
+        "-c".into(),
+        format!("echo {{fps}} > {}", out.display()),
+    ]);
+    target.fire(&TICK).unwrap();
+    wait_for(|| std::fs::read_to_string(&out).is_ok_and(|s| s == "12.50\n"));
+}
+
+#[test]
+#[serial]
+fn exec_target_uses_one_frame_per_tick() {
+    let dir = tempdir().unwrap();
+    for name in ["a.png", "b.png"] {
+        std::fs::write(dir.path().join(name), b"").unwrap();
+    }
+    std::env::set_var("BONGO_IMAGE_DIR", dir.path());
+    let out = dir.path().join("out");
+    let mut target = ExecTarget::new(vec![
+        "sh".into(),
+        "-c".into(),
+        format!("echo \"$0 $1\" > {}", out.display()),
+        "{frame}".into(),
+        "{frame}".into(),
+    ]);
+    target.fire(&TICK).unwrap();
+    let a = dir.path().join("a.png");
+    let expected = format!("{} {}\n", a.display(), a.display());
+    wait_for(|| std::fs::read_to_string(&out).is_ok_and(|s| s == expected));
+}
+
+#[test]
+fn dropped_exec_target_kills_its_run() {
+    let dir = tempdir().unwrap();
+    let pid_file = dir.path().join("pid");
+    let mut target = ExecTarget::new(vec![
+        "sh".into(),
+        "-c".into(),
+        format!("echo $$ > {}; exec sleep 30", pid_file.display()),
+    ]);
+    assert_eq!(target.fire(&TICK).unwrap(), Outcome::Fired);
+    let mut pid = String::new();
+    wait_for(|| {
+        pid = std::fs::read_to_string(&pid_file).unwrap_or_default();
+        pid.ends_with('\n')
+    });
+    drop(target);
+    // Killed and reaped: not even a zombie is left.
+    assert!(!std::path::Path::new(&format!("/proc/{}", pid.trim())).exists());
+}
+
+#[test]
+fn exec_target_skips_while_busy() {
+    let dir = tempdir().unwrap();
+    let out = dir.path().join("runs");
+    let mut target = ExecTarget::new(vec![
+        "sh".into(),
+        "-c".into(),
+        format!("echo run >> {}; sleep 0.5", out.display()),
+    ]);
+    assert_eq!(target.fire(&TICK).unwrap(), Outcome::Fired);
+    assert_eq!(target.fire(&TICK).unwrap(), Outcome::Skipped);
+    wait_for(|| out.exists());
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(std::fs::read_to_string(&out).unwrap(), "run\n");
+}
+
+#[test]
+fn file_target_replaces_contents() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("state");
+    let mut target = FileTarget::new(path.clone(), "fps={fps}\n".into());
+    target.fire(&Tick { fps: 30.0 }).unwrap();
+    target.fire(&TICK).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "fps=12.50\n");
+}
+
+#[test]
+fn file_target_skips_fifo_without_reader() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("fifo");
+    let c = CString::new(path.to_str().unwrap()).unwrap();
+    assert_eq!(unsafe { libc::mkfifo(c.as_ptr(), 0o600) }, 0);
+
+    let mut target = FileTarget::new(path.clone(), "{fps}\n".into());
+    // Nobody is reading: the write is dropped instead of blocking.
+    target.fire(&TICK).unwrap();
+
+    let reader = std::fs::OpenOptions::new()
+        .read(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open(&path)
+        .unwrap();
+    target.fire(&TICK).unwrap();
+    let mut buf = String::new();
+    std::io::Read::read_to_string(&mut &reader, &mut buf).unwrap();
+    assert_eq!(buf, "12.50\n");
+}
+
+struct Counter(std::sync::Arc<std::sync::atomic::AtomicUsize>);
+
+impl Target for Counter {
+    fn describe(&self) -> String {
+        "counter".into()
+    }
+
+    fn fire(&mut self, _tick: &Tick) -> std::io::Result<Outcome> {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(Outcome::Fired)
+    }
+}
+
+#[test]
+fn divisor_thins_out_ticks() {
+    let fired = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let mut scheduled = ScheduledTarget::new(Box::new(Counter(fired.clone())), 3);
+    for count in 0..9 {
+        scheduled.on_tick(count, &TICK);
+    }
+    assert_eq!(fired.load(std::sync::atomic::Ordering::Relaxed), 3);
+    assert_eq!((scheduled.fired, scheduled.failed), (3, 0));
+}
+
+#[test]
+fn signal_target_reaches_process() {
+    let (_dir, name, mut child) = sleeper("t");
+    let mut target = SignalTarget::new(libc::SIGTERM, ProcessMatcher::by_name(&name));
+    assert_eq!(target.fire(&TICK).unwrap(), Outcome::Fired);
+    assert_eq!(target.pids(), vec![child.id()]);
+    let status = child.wait().unwrap();
+    assert_eq!(
+        std::os::unix::process::ExitStatusExt::signal(&status),
+        Some(libc::SIGTERM)
+    );
+}
+
+#[test]
+fn process_dying_before_the_signal_is_not_counted() {
+    let (_dir, name, mut child) = sleeper("d");
+    child.kill().unwrap();
+    // Unreaped, the process still shows up in the scan but is gone by the
+    // time the signal would go out.
+    wait_for(|| {
+        std::fs::read_to_string(format!("/proc/{}/stat", child.id()))
+            .is_ok_and(|s| s.contains(") Z "))
+    });
+    let target = SignalTarget::new(libc::SIGUSR2, ProcessMatcher::by_name(&name));
+    let mut scheduled = ScheduledTarget::new(Box::new(target), 1);
+    scheduled.on_tick(0, &TICK);
+    assert_eq!((scheduled.fired, scheduled.failed), (0, 0));
+    assert!(scheduled.target.pids().is_empty());
+    child.wait().unwrap();
+}
+
+#[test]
+fn undeliverable_signal_counts_as_failure() {
+    let (_dir, name, mut child) = sleeper("f");
+    // Not a valid signal number: the kernel refuses to send it.
+    let target = SignalTarget::new(1000, ProcessMatcher::by_name(&name));
+    let mut scheduled = ScheduledTarget::new(Box::new(target), 1);
+    scheduled.on_tick(0, &TICK);
+    assert_eq!((scheduled.fired, scheduled.failed), (0, 1));
+    child.kill().unwrap();
+    child.wait().unwrap();
+}